@@ -0,0 +1,75 @@
+//! Benchmarks for Solar <-> Lunar conversions.
+//!
+//! Run with `cargo bench` (or `task benchmark`).
+
+use std::fmt::Write;
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rs_klc::{DatePattern, LunarSolarConverter, SolarDate};
+
+fn bench_solar_to_lunar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("solar_to_lunar");
+
+    for (year, month, day) in [(1391, 2, 5), (2022, 7, 10), (2050, 12, 31)] {
+        let mut converter = LunarSolarConverter::new();
+        group.bench_function(format!("{:04}-{:02}-{:02}", year, month, day), |b| {
+            b.iter(|| converter.set_solar_date(black_box(year), black_box(month), black_box(day)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_lunar_to_solar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lunar_to_solar");
+
+    for (year, month, day, is_intercalation) in [
+        (1391, 1, 1, false),
+        (2023, 2, 15, true),
+        (2050, 11, 18, false),
+    ] {
+        let mut converter = LunarSolarConverter::new();
+        group.bench_function(format!("{:04}-{:02}-{:02}", year, month, day), |b| {
+            b.iter(|| {
+                converter.set_lunar_date(
+                    black_box(year),
+                    black_box(month),
+                    black_box(day),
+                    black_box(is_intercalation),
+                )
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_gapja(c: &mut Criterion) {
+    let mut converter = LunarSolarConverter::new();
+    converter.set_solar_date(2022, 7, 10);
+
+    c.bench_function("gapja_string", |b| b.iter(|| converter.get_gapja_string()));
+}
+
+fn bench_format(c: &mut Criterion) {
+    let pattern = DatePattern::new("음력 %lY년 %L%-lm월 %-ld일 (%y년)").unwrap();
+    let date = SolarDate::new(2023, 4, 5).unwrap();
+    let mut buffer = String::new();
+
+    c.bench_function("format_pattern", |b| {
+        b.iter(|| {
+            buffer.clear();
+            write!(buffer, "{}", pattern.format(black_box(date))).unwrap();
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_solar_to_lunar,
+    bench_lunar_to_solar,
+    bench_gapja,
+    bench_format
+);
+criterion_main!(benches);
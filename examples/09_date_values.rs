@@ -0,0 +1,39 @@
+//! Example 09: Immutable Date Values
+//!
+//! This example shows how to use the `SolarDate` and `LunarDate` value types
+//! instead of a mutable converter.
+
+use std::collections::BTreeMap;
+
+use rs_klc::{LunarDate, SolarDate};
+
+fn main() {
+    println!("=== 9. Immutable Date Values ===");
+
+    // 2022-07-10 (Solar) -> 2022-06-12 (Lunar)
+    if let Ok(solar) = SolarDate::new(2022, 7, 10) {
+        let lunar = solar.to_lunar();
+        println!("Solar {} -> Lunar {}", solar, lunar);
+        println!("Day of Week: {:?}", solar.day_of_week());
+    }
+
+    // Dates are `Copy` + `Ord`, so they can be used as map keys.
+    let mut birthdays = BTreeMap::new();
+    for (name, lunar) in [
+        ("Alice", LunarDate::new(2023, 2, 15, true)),
+        ("Bob", LunarDate::new(2023, 2, 15, false)),
+    ] {
+        if let Ok(lunar) = lunar {
+            birthdays.insert(lunar, name);
+        }
+    }
+
+    for (lunar, name) in &birthdays {
+        println!("{}: Lunar {} -> Solar {}", name, lunar, lunar.to_solar());
+    }
+
+    // Invalid dates are rejected on construction.
+    if let Err(err) = LunarDate::new(2022, 6, 12, true) {
+        println!("Lunar 2022-06-12 (Intercalary) rejected: {}", err);
+    }
+}
@@ -0,0 +1,30 @@
+//! Example 10: 24 Solar Terms
+//!
+//! This example lists the 24 solar terms (절기) of a year in KST and looks up
+//! the term in effect on a given day.
+
+use rs_klc::SolarDate;
+use rs_klc::solar_terms;
+
+fn main() {
+    println!("=== 10. 24 Solar Terms ===");
+
+    match solar_terms::solar_terms(2024) {
+        Ok(terms) => {
+            for time in &terms {
+                println!(
+                    "{} ({}, {})",
+                    time,
+                    time.term().hanja_name(),
+                    time.term().english_name()
+                );
+            }
+        }
+        Err(err) => println!("Error: {}", err),
+    }
+
+    // The term in effect on a day is the latest one on or before it.
+    if let Ok(solar) = SolarDate::new(2024, 2, 10) {
+        println!("{} falls in {}", solar, solar.solar_term().term());
+    }
+}
@@ -0,0 +1,143 @@
+//! Regenerates `KOREAN_LUNAR_DATA` from the astronomical computation and audits the
+//! embedded table against it.
+//!
+//! ```text
+//! klc-datagen emit [--from YEAR] [--to YEAR] [--utc-offset +HH:MM] [--name NAME]
+//! klc-datagen diff [--from YEAR] [--to YEAR] [--utc-offset +HH:MM]
+//! ```
+//!
+//! `emit` prints the computed years as Rust source. `diff` lists each year where the
+//! month lengths or the intercalary month of the embedded table disagree with the
+//! computation, and exits with status 1 if there is any. The years default to the
+//! range of the embedded table, the offset to Korean Standard Time (+09:00) and the
+//! name of the emitted table to `KOREAN_LUNAR_DATA`.
+
+use std::process::ExitCode;
+
+use rs_klc::klc::datagen::{self, TABLE_FIRST_YEAR, TABLE_LAST_YEAR};
+
+const USAGE: &str = "Usage: klc-datagen <emit|diff> [--from YEAR] [--to YEAR] \
+                     [--utc-offset +HH:MM] [--name NAME]";
+
+struct Options {
+    from: i32,
+    to: i32,
+    utc_offset_minutes: i32,
+    name: String,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some((command, flags)) = args.split_first() else {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+
+    let options = match parse_options(flags) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command.as_str() {
+        "emit" => {
+            print!(
+                "{}",
+                datagen::to_rust_source(
+                    &options.name,
+                    options.from,
+                    options.to,
+                    options.utc_offset_minutes
+                )
+            );
+            ExitCode::SUCCESS
+        }
+        "diff" => match datagen::diff(options.from, options.to, options.utc_offset_minutes) {
+            Ok(differences) => {
+                for difference in &differences {
+                    println!("{}", difference);
+                }
+                println!(
+                    "{} of {} years differ",
+                    differences.len(),
+                    options.to - options.from + 1
+                );
+
+                if differences.is_empty() {
+                    ExitCode::SUCCESS
+                } else {
+                    ExitCode::from(1)
+                }
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                ExitCode::FAILURE
+            }
+        },
+        _ => {
+            eprintln!("unknown command '{}'\n{}", command, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_options(flags: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        from: TABLE_FIRST_YEAR,
+        to: TABLE_LAST_YEAR,
+        utc_offset_minutes: 9 * 60,
+        name: "KOREAN_LUNAR_DATA".to_string(),
+    };
+
+    let mut flags = flags.iter();
+    while let Some(flag) = flags.next() {
+        let value = flags
+            .next()
+            .ok_or_else(|| format!("missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--from" => options.from = parse_year(value)?,
+            "--to" => options.to = parse_year(value)?,
+            "--utc-offset" => options.utc_offset_minutes = parse_utc_offset(value)?,
+            "--name" => options.name = value.clone(),
+            _ => return Err(format!("unknown option '{}'", flag)),
+        }
+    }
+
+    if options.from > options.to {
+        return Err(format!(
+            "--from {} is after --to {}",
+            options.from, options.to
+        ));
+    }
+
+    Ok(options)
+}
+
+fn parse_year(value: &str) -> Result<i32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid year '{}'", value))
+}
+
+/// Parses an offset such as `+09:00` or `-03:30` into minutes.
+fn parse_utc_offset(value: &str) -> Result<i32, String> {
+    let invalid = || format!("invalid UTC offset '{}', expected +HH:MM", value);
+
+    let (sign, rest) = match value.as_bytes().first() {
+        Some(b'+') => (1, &value[1..]),
+        Some(b'-') => (-1, &value[1..]),
+        _ => return Err(invalid()),
+    };
+    let (hours, minutes) = rest.split_once(':').ok_or_else(invalid)?;
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+
+    if hours > 14 || minutes > 59 {
+        return Err(invalid());
+    }
+
+    Ok(sign * (hours * 60 + minutes))
+}
@@ -0,0 +1,319 @@
+use super::{KOREAN_LUNAR_MAX_VALUE, KlcError, LunarDate, LunarSolarConverter, SolarDate};
+
+/// What to do when the anniversary day does not exist in a year, such as day 30 in a
+/// year where the month has 29 days.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissingDayPolicy {
+    /// Use the last day (29) of the month.
+    #[default]
+    LastDayOfMonth,
+    /// Use the day after the last day of the month (the first day of the next month).
+    NextDay,
+    /// Skip the year.
+    Skip,
+}
+
+/// What to do when an intercalary-month (윤달) anniversary falls in a year without
+/// that intercalary month.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissingLeapMonthPolicy {
+    /// Use the regular month with the same number.
+    #[default]
+    RegularMonth,
+    /// Skip the year.
+    Skip,
+}
+
+/// Which day the anniversary is observed on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Observance {
+    /// On the anniversary itself.
+    #[default]
+    OnTheDay,
+    /// On the day before, as for a 제사 held on the eve of the memorial day (기일).
+    Eve,
+}
+
+/// A recurring anniversary on a lunar month and day, such as a lunar birthday or a
+/// memorial day (기일).
+///
+/// # Example
+/// ```
+/// use rs_klc::{LunarAnniversary, MissingDayPolicy, SolarDate};
+///
+/// // Lunar 12/30; 12월 has only 29 days in lunar 2024 and 2025
+/// let anniversary = LunarAnniversary::new(12, 30, false)
+///     .unwrap()
+///     .with_missing_day_policy(MissingDayPolicy::LastDayOfMonth);
+/// let from = SolarDate::new(2024, 1, 1).unwrap();
+/// let dates: Vec<String> = anniversary
+///     .next_occurrences(from, 3)
+///     .iter()
+///     .map(|date| date.to_string())
+///     .collect();
+///
+/// assert_eq!(dates, ["2024-02-09", "2025-01-28", "2026-02-16"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunarAnniversary {
+    month: u32,
+    day: u32,
+    is_leap_month: bool,
+    missing_day_policy: MissingDayPolicy,
+    missing_leap_month_policy: MissingLeapMonthPolicy,
+    observance: Observance,
+}
+
+/// An iterator over the solar dates of a [`LunarAnniversary`], in chronological order.
+///
+/// Created by [`LunarAnniversary::occurrences`]. It ends at the end of the supported range.
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    anniversary: LunarAnniversary,
+    from: SolarDate,
+    lunar_year: i32,
+}
+
+impl LunarAnniversary {
+    /// Creates an anniversary on a lunar month and day, with the default policies.
+    ///
+    /// # Arguments
+    /// * `is_leap_month` - `true` if the anniversary is in an intercalary month (윤달).
+    ///
+    /// # Errors
+    /// Returns [`KlcError::InvalidMonth`] or [`KlcError::InvalidDay`] if the month is not
+    /// within 1-12 or the day within 1-30.
+    pub fn new(month: u32, day: u32, is_leap_month: bool) -> Result<Self, KlcError> {
+        if !(1..=12).contains(&month) {
+            return Err(KlcError::InvalidMonth(month));
+        }
+        if !(1..=30).contains(&day) {
+            return Err(KlcError::InvalidDay {
+                day,
+                days_in_month: 30,
+            });
+        }
+
+        Ok(LunarAnniversary {
+            month,
+            day,
+            is_leap_month,
+            missing_day_policy: MissingDayPolicy::default(),
+            missing_leap_month_policy: MissingLeapMonthPolicy::default(),
+            observance: Observance::default(),
+        })
+    }
+
+    /// Creates the anniversary of a lunar date.
+    pub fn from_lunar_date(date: LunarDate) -> Self {
+        LunarAnniversary::new(date.month(), date.day(), date.is_leap_month())
+            .expect("a validated LunarDate has a valid month and day")
+    }
+
+    /// Sets what to do when the day does not exist in a year.
+    pub fn with_missing_day_policy(mut self, policy: MissingDayPolicy) -> Self {
+        self.missing_day_policy = policy;
+        self
+    }
+
+    /// Sets what to do when the intercalary month does not exist in a year.
+    pub fn with_missing_leap_month_policy(mut self, policy: MissingLeapMonthPolicy) -> Self {
+        self.missing_leap_month_policy = policy;
+        self
+    }
+
+    /// Sets which day the anniversary is observed on.
+    pub fn with_observance(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    /// Returns the lunar month.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the lunar day.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns `true` if the anniversary is in an intercalary month (윤달).
+    pub fn is_leap_month(&self) -> bool {
+        self.is_leap_month
+    }
+
+    /// Returns the solar date on which the anniversary is observed in a lunar year,
+    /// or `None` if the year is skipped by a policy or is outside the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{LunarAnniversary, MissingLeapMonthPolicy};
+    ///
+    /// // 윤2월 15일: 2023 has 윤2월, 2024 does not
+    /// let anniversary = LunarAnniversary::new(2, 15, true).unwrap();
+    /// assert_eq!(anniversary.in_lunar_year(2023).unwrap().to_string(), "2023-04-05");
+    /// assert_eq!(anniversary.in_lunar_year(2024).unwrap().to_string(), "2024-03-24");
+    ///
+    /// let strict = anniversary.with_missing_leap_month_policy(MissingLeapMonthPolicy::Skip);
+    /// assert_eq!(strict.in_lunar_year(2024), None);
+    /// ```
+    pub fn in_lunar_year(&self, year: i32) -> Option<SolarDate> {
+        let leap_month = LunarSolarConverter::get_lunar_intercalary_month(year);
+        let is_leap_month = match (self.is_leap_month, self.missing_leap_month_policy) {
+            (false, _) => false,
+            (true, _) if leap_month == Some(self.month) => true,
+            (true, MissingLeapMonthPolicy::RegularMonth) => false,
+            (true, MissingLeapMonthPolicy::Skip) => return None,
+        };
+
+        let days_in_month = LunarSolarConverter::get_lunar_days(year, self.month, is_leap_month);
+        let (day, days_after) = match self.missing_day_policy {
+            _ if self.day <= days_in_month => (self.day, 0),
+            MissingDayPolicy::LastDayOfMonth => (days_in_month, 0),
+            MissingDayPolicy::NextDay => (days_in_month, 1),
+            MissingDayPolicy::Skip => return None,
+        };
+        let days_before = match self.observance {
+            Observance::OnTheDay => 0,
+            Observance::Eve => 1,
+        };
+
+        let solar = LunarDate::new(year, self.month, day, is_leap_month)
+            .ok()?
+            .to_solar();
+
+        SolarDate::from_julian_day_number(
+            solar.julian_day_number() as i64 + days_after - days_before,
+        )
+        .ok()
+    }
+
+    /// Returns an iterator over the observed solar dates on or after `from`.
+    pub fn occurrences(&self, from: SolarDate) -> Occurrences {
+        Occurrences {
+            anniversary: *self,
+            from,
+            // An anniversary late in the previous lunar year may still be ahead
+            lunar_year: from.to_lunar().year() - 1,
+        }
+    }
+
+    /// Returns the next `count` observed solar dates on or after `from`.
+    ///
+    /// Fewer dates are returned if the supported range ends first.
+    pub fn next_occurrences(&self, from: SolarDate, count: usize) -> Vec<SolarDate> {
+        self.occurrences(from).take(count).collect()
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = SolarDate;
+
+    fn next(&mut self) -> Option<SolarDate> {
+        while self.lunar_year <= (KOREAN_LUNAR_MAX_VALUE / 10000) as i32 {
+            let year = self.lunar_year;
+            self.lunar_year += 1;
+
+            match self.anniversary.in_lunar_year(year) {
+                Some(date) if date >= self.from => return Some(date),
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LunarAnniversary, MissingDayPolicy, MissingLeapMonthPolicy, Observance};
+    use crate::{KlcError, LunarDate, SolarDate};
+
+    fn dates(anniversary: LunarAnniversary, from: (u32, u32, u32), count: usize) -> Vec<String> {
+        let from = SolarDate::new(from.0, from.1, from.2).unwrap();
+
+        anniversary
+            .next_occurrences(from, count)
+            .iter()
+            .map(|date| date.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_missing_day_policies() {
+        let anniversary = LunarAnniversary::new(12, 30, false).unwrap();
+
+        assert_eq!(
+            dates(anniversary, (2024, 1, 1), 4),
+            ["2024-02-09", "2025-01-28", "2026-02-16", "2027-02-06"]
+        );
+        assert_eq!(
+            dates(
+                anniversary.with_missing_day_policy(MissingDayPolicy::NextDay),
+                (2024, 1, 1),
+                4
+            ),
+            ["2024-02-09", "2025-01-29", "2026-02-17", "2027-02-06"]
+        );
+        assert_eq!(
+            dates(
+                anniversary.with_missing_day_policy(MissingDayPolicy::Skip),
+                (2024, 1, 1),
+                2
+            ),
+            ["2024-02-09", "2027-02-06"]
+        );
+    }
+
+    #[test]
+    fn test_leap_month_policies() {
+        let anniversary =
+            LunarAnniversary::from_lunar_date(LunarDate::new(2023, 2, 15, true).unwrap());
+
+        assert_eq!(
+            dates(anniversary, (2023, 1, 1), 2),
+            ["2023-04-05", "2024-03-24"]
+        );
+
+        let strict = anniversary.with_missing_leap_month_policy(MissingLeapMonthPolicy::Skip);
+        let next = strict.next_occurrences(SolarDate::new(2023, 5, 1).unwrap(), 1);
+
+        assert_eq!(
+            next[0].to_lunar(),
+            LunarDate::new(2042, 2, 15, true).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_eve_observance() {
+        // 기일 on lunar 1/1 is observed on the eve, the last day of the previous year
+        let anniversary = LunarAnniversary::new(1, 1, false)
+            .unwrap()
+            .with_observance(Observance::Eve);
+
+        assert_eq!(
+            dates(anniversary, (2024, 2, 9), 2),
+            ["2024-02-09", "2025-01-28"]
+        );
+    }
+
+    #[test]
+    fn test_occurrences_end_with_supported_range() {
+        // The supported range ends on lunar 2200-11-25
+        let anniversary = LunarAnniversary::new(11, 28, false).unwrap();
+        let dates = anniversary.next_occurrences(SolarDate::new(2199, 6, 1).unwrap(), 5);
+
+        assert_eq!(dates.len(), 1);
+        assert_eq!(dates[0].to_lunar().to_string(), "2199-11-28");
+    }
+
+    #[test]
+    fn test_invalid_anniversary() {
+        assert_eq!(
+            LunarAnniversary::new(13, 1, false),
+            Err(KlcError::InvalidMonth(13))
+        );
+        assert!(LunarAnniversary::new(1, 31, false).is_err());
+    }
+}
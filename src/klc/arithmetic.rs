@@ -0,0 +1,384 @@
+use super::calendar::{Korean, LunisolarCalendar};
+use super::{
+    KOREAN_LUNAR_BASE_YEAR, KOREAN_LUNAR_MAX_VALUE, KOREAN_LUNAR_MIN_VALUE, KlcError, LunarDate,
+    LunarSolarConverter,
+};
+
+/// What to do when the day of a date moved by months or years does not exist in the
+/// target month, such as day 30 in a month of 29 days.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayOverflowPolicy {
+    /// Use the last day (29) of the month.
+    #[default]
+    Clamp,
+    /// Carry the extra days into the next month, so day 30 becomes day 1 of the next month.
+    NextMonth,
+    /// Return [`KlcError::InvalidDay`].
+    Error,
+}
+
+/// What to do when a date in an intercalary month (윤달) is moved by years to a year
+/// without that intercalary month.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeapMonthPolicy {
+    /// Use the regular month with the same number.
+    #[default]
+    RegularMonth,
+    /// Skip the missing intercalary month and use the month after the regular one.
+    Skip,
+    /// Return [`KlcError::InvalidLeapMonth`].
+    Error,
+}
+
+impl LunarDate {
+    /// Returns the date `days` days later (earlier if negative), or `None` if it is
+    /// outside the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::LunarDate;
+    ///
+    /// let date = LunarDate::new(2023, 2, 29, false).unwrap();
+    /// let next = date.checked_add_days(1).unwrap();
+    /// assert_eq!(next.to_string(), "2023-02-30");
+    /// assert_eq!(next.checked_add_days(1).unwrap().to_string(), "2023-02-01 Intercalation");
+    /// assert_eq!(date.checked_add_days(400_000), None);
+    /// ```
+    pub fn checked_add_days(&self, days: i64) -> Option<LunarDate> {
+        let abs_days = (self.abs_days() as i64).checked_add(days)?;
+
+        (1..=max_abs_days() as i64)
+            .contains(&abs_days)
+            .then(|| LunarDate::from_abs_days(abs_days as u32))
+    }
+
+    /// Returns the date `months` lunar months later (earlier if negative), walking the
+    /// actual sequence of months, so an intercalary month counts as a month of its own.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the result is outside the supported range,
+    /// or [`KlcError::InvalidDay`] if the day does not exist in the target month and the
+    /// policy is [`DayOverflowPolicy::Error`].
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{DayOverflowPolicy, LunarDate};
+    ///
+    /// // 2023: 2월 (30 days), 윤2월 (29 days), 3월
+    /// let date = LunarDate::new(2023, 2, 30, false).unwrap();
+    ///
+    /// let clamped = date.add_months(1, DayOverflowPolicy::Clamp).unwrap();
+    /// assert_eq!(clamped.to_string(), "2023-02-29 Intercalation");
+    ///
+    /// let rolled = date.add_months(1, DayOverflowPolicy::NextMonth).unwrap();
+    /// assert_eq!(rolled.to_string(), "2023-03-01");
+    ///
+    /// assert!(date.add_months(1, DayOverflowPolicy::Error).is_err());
+    /// ```
+    pub fn add_months(
+        &self,
+        months: i32,
+        overflow: DayOverflowPolicy,
+    ) -> Result<LunarDate, KlcError> {
+        let tables = Korean::tables();
+        let slot = tables.month_slot(self.year(), self.month(), self.is_leap_month());
+        let (year, month, is_leap_month) = walk_months(self.year(), slot as i64 + months as i64)?;
+
+        resolve_day(year, month, is_leap_month, self.day(), overflow)
+    }
+
+    /// Returns the date `years` lunar years later (earlier if negative), on the same
+    /// month and day.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the result is outside the supported range,
+    /// [`KlcError::InvalidDay`] if the day does not exist in the target month and the
+    /// policy is [`DayOverflowPolicy::Error`], or [`KlcError::InvalidLeapMonth`] if the
+    /// target year lacks the intercalary month and the policy is [`LeapMonthPolicy::Error`].
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{DayOverflowPolicy, LeapMonthPolicy, LunarDate};
+    ///
+    /// // 윤2월 15일 2023; 2024 has no 윤2월
+    /// let date = LunarDate::new(2023, 2, 15, true).unwrap();
+    ///
+    /// let regular = date
+    ///     .add_years(1, DayOverflowPolicy::Clamp, LeapMonthPolicy::RegularMonth)
+    ///     .unwrap();
+    /// assert_eq!(regular.to_string(), "2024-02-15");
+    ///
+    /// let skipped = date
+    ///     .add_years(1, DayOverflowPolicy::Clamp, LeapMonthPolicy::Skip)
+    ///     .unwrap();
+    /// assert_eq!(skipped.to_string(), "2024-03-15");
+    ///
+    /// let leap = date
+    ///     .add_years(19, DayOverflowPolicy::Clamp, LeapMonthPolicy::Error)
+    ///     .unwrap();
+    /// assert_eq!(leap.to_string(), "2042-02-15 Intercalation");
+    /// ```
+    pub fn add_years(
+        &self,
+        years: i32,
+        overflow: DayOverflowPolicy,
+        leap_month: LeapMonthPolicy,
+    ) -> Result<LunarDate, KlcError> {
+        let year = self.year().saturating_add(years);
+        check_year(year)?;
+
+        let intercalary_month = LunarSolarConverter::get_lunar_intercalary_month(year);
+        if !self.is_leap_month() || intercalary_month == Some(self.month()) {
+            return resolve_day(
+                year,
+                self.month(),
+                self.is_leap_month(),
+                self.day(),
+                overflow,
+            );
+        }
+
+        match leap_month {
+            LeapMonthPolicy::RegularMonth => {
+                resolve_day(year, self.month(), false, self.day(), overflow)
+            }
+            LeapMonthPolicy::Skip => {
+                let slot = Korean::tables().month_slot(year, self.month(), false);
+                let (year, month, is_leap_month) = walk_months(year, slot as i64 + 1)?;
+
+                resolve_day(year, month, is_leap_month, self.day(), overflow)
+            }
+            LeapMonthPolicy::Error => Err(KlcError::InvalidLeapMonth {
+                year,
+                month: self.month(),
+                leap_month: intercalary_month,
+            }),
+        }
+    }
+
+    /// Returns the number of days from this date to `other`, negative if `other` is
+    /// earlier.
+    pub fn days_between(&self, other: LunarDate) -> i64 {
+        other.abs_days() as i64 - self.abs_days() as i64
+    }
+
+    /// Returns the number of lunar months from the month of this date to the month of
+    /// `other`, counting intercalary months and ignoring the days, negative if `other`
+    /// is earlier.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::LunarDate;
+    ///
+    /// let from = LunarDate::new(2023, 1, 15, false).unwrap();
+    /// let to = LunarDate::new(2023, 3, 1, false).unwrap();
+    ///
+    /// // 1월 -> 2월 -> 윤2월 -> 3월
+    /// assert_eq!(from.months_between(to), 3);
+    /// assert_eq!(to.months_between(from), -3);
+    /// ```
+    pub fn months_between(&self, other: LunarDate) -> i32 {
+        let tables = Korean::tables();
+        let (first, last) = if self <= &other {
+            (self, &other)
+        } else {
+            (&other, self)
+        };
+
+        let mut months: usize = (first.year()..last.year())
+            .map(|year| tables.month_count(year))
+            .sum();
+        months += tables.month_slot(last.year(), last.month(), last.is_leap_month());
+        months -= tables.month_slot(first.year(), first.month(), first.is_leap_month());
+
+        if self <= &other {
+            months as i32
+        } else {
+            -(months as i32)
+        }
+    }
+}
+
+fn out_of_range() -> KlcError {
+    KlcError::OutOfRange {
+        min: KOREAN_LUNAR_MIN_VALUE,
+        max: KOREAN_LUNAR_MAX_VALUE,
+    }
+}
+
+fn check_year(year: i32) -> Result<(), KlcError> {
+    if (KOREAN_LUNAR_BASE_YEAR..=(KOREAN_LUNAR_MAX_VALUE / 10000) as i32).contains(&year) {
+        Ok(())
+    } else {
+        Err(out_of_range())
+    }
+}
+
+/// Returns the day count of the last supported lunar date.
+fn max_abs_days() -> u32 {
+    let max = KOREAN_LUNAR_MAX_VALUE;
+
+    Korean::tables().lunar_abs_days((max / 10000) as i32, max / 100 % 100, max % 100, false)
+}
+
+/// Returns the month at `slot` counted from the first month of `year`, which may run
+/// past the end of the year or before its start, as `(year, month, is_leap_month)`.
+fn walk_months(mut year: i32, mut slot: i64) -> Result<(i32, u32, bool), KlcError> {
+    let tables = Korean::tables();
+
+    while slot < 0 {
+        year -= 1;
+        check_year(year)?;
+        slot += tables.month_count(year) as i64;
+    }
+    while slot >= tables.month_count(year) as i64 {
+        slot -= tables.month_count(year) as i64;
+        year += 1;
+        check_year(year)?;
+    }
+
+    let (month, is_leap_month) = tables.month_by_slot(year, slot as usize);
+
+    Ok((year, month, is_leap_month))
+}
+
+/// Returns the date on a day of a month, applying the overflow policy if the month is
+/// shorter.
+fn resolve_day(
+    year: i32,
+    month: u32,
+    is_leap_month: bool,
+    day: u32,
+    overflow: DayOverflowPolicy,
+) -> Result<LunarDate, KlcError> {
+    let days_in_month = LunarSolarConverter::get_lunar_days(year, month, is_leap_month);
+    if day <= days_in_month {
+        return LunarDate::new(year, month, day, is_leap_month);
+    }
+
+    match overflow {
+        DayOverflowPolicy::Clamp => LunarDate::new(year, month, days_in_month, is_leap_month),
+        DayOverflowPolicy::NextMonth => LunarDate::new(year, month, days_in_month, is_leap_month)?
+            .checked_add_days((day - days_in_month) as i64)
+            .ok_or_else(out_of_range),
+        DayOverflowPolicy::Error => Err(KlcError::InvalidDay { day, days_in_month }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DayOverflowPolicy, LeapMonthPolicy};
+    use crate::{KlcError, LunarDate};
+
+    fn lunar(year: i32, month: u32, day: u32, is_leap_month: bool) -> LunarDate {
+        LunarDate::new(year, month, day, is_leap_month).unwrap()
+    }
+
+    #[test]
+    fn test_checked_add_days() {
+        // 11월 2023 has 29 days and 12월 30
+        let date = lunar(2023, 12, 30, false);
+
+        assert_eq!(date.checked_add_days(1), Some(lunar(2024, 1, 1, false)));
+        assert_eq!(date.checked_add_days(-30), Some(lunar(2023, 11, 29, false)));
+        assert_eq!(lunar(1391, 1, 1, false).checked_add_days(-1), None);
+        assert_eq!(
+            lunar(2200, 11, 24, false).checked_add_days(1),
+            Some(lunar(2200, 11, 25, false))
+        );
+        assert_eq!(lunar(2200, 11, 25, false).checked_add_days(1), None);
+        assert_eq!(date.checked_add_days(i64::MAX), None);
+        assert_eq!(date.checked_add_days(i64::MIN), None);
+    }
+
+    #[test]
+    fn test_add_months_across_years() {
+        let date = lunar(2022, 12, 15, false);
+
+        // 2023 has 13 months
+        assert_eq!(
+            date.add_months(13, DayOverflowPolicy::Clamp),
+            Ok(lunar(2023, 12, 15, false))
+        );
+        assert_eq!(
+            lunar(2023, 12, 15, false).add_months(-13, DayOverflowPolicy::Clamp),
+            Ok(date)
+        );
+        assert_eq!(
+            date.add_months(-3, DayOverflowPolicy::Clamp),
+            Ok(lunar(2022, 9, 15, false))
+        );
+        assert_eq!(date.add_months(0, DayOverflowPolicy::Clamp), Ok(date));
+    }
+
+    #[test]
+    fn test_add_months_out_of_range() {
+        assert!(matches!(
+            lunar(1391, 2, 1, false).add_months(-2, DayOverflowPolicy::Clamp),
+            Err(KlcError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            lunar(2200, 11, 1, false).add_months(1, DayOverflowPolicy::Clamp),
+            Err(KlcError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_add_years_day_overflow() {
+        // 2월 has 30 days in 2023 and 29 days in 2025
+        let date = lunar(2023, 2, 30, false);
+
+        assert_eq!(
+            date.add_years(2, DayOverflowPolicy::Clamp, LeapMonthPolicy::default()),
+            Ok(lunar(2025, 2, 29, false))
+        );
+        assert_eq!(
+            date.add_years(2, DayOverflowPolicy::NextMonth, LeapMonthPolicy::default()),
+            Ok(lunar(2025, 3, 1, false))
+        );
+        assert_eq!(
+            date.add_years(2, DayOverflowPolicy::Error, LeapMonthPolicy::default()),
+            Err(KlcError::InvalidDay {
+                day: 30,
+                days_in_month: 29
+            })
+        );
+    }
+
+    #[test]
+    fn test_add_years_missing_leap_month() {
+        let date = lunar(2023, 2, 15, true);
+
+        assert_eq!(
+            date.add_years(1, DayOverflowPolicy::Clamp, LeapMonthPolicy::Error),
+            Err(KlcError::InvalidLeapMonth {
+                year: 2024,
+                month: 2,
+                leap_month: None
+            })
+        );
+        assert_eq!(
+            date.add_years(-3, DayOverflowPolicy::Clamp, LeapMonthPolicy::Skip),
+            Ok(lunar(2020, 3, 15, false))
+        );
+        assert!(matches!(
+            date.add_years(200, DayOverflowPolicy::Clamp, LeapMonthPolicy::Skip),
+            Err(KlcError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_between() {
+        let from = lunar(2023, 1, 1, false);
+        let to = lunar(2024, 1, 1, false);
+
+        assert_eq!(from.days_between(to), 384);
+        assert_eq!(to.days_between(from), -384);
+        assert_eq!(from.months_between(to), 13);
+        assert_eq!(from.months_between(from), 0);
+        assert_eq!(
+            from.add_months(from.months_between(to), DayOverflowPolicy::Clamp),
+            Ok(to)
+        );
+    }
+}
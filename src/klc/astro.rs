@@ -0,0 +1,533 @@
+//! Astronomical helpers used to compute solar terms, new moons and solar time.
+//!
+//! The apparent longitude of the sun is evaluated from the truncated VSOP87 series
+//! for the Earth given in Jean Meeus, *Astronomical Algorithms* (2nd ed.), Appendix III,
+//! which is accurate to about one arc second (roughly half a minute of time) over the
+//! supported range. New moons use the periodic terms of Meeus, chapter 49, fitted to
+//! the ELP-2000/82 lunar theory, which are accurate to well under a minute.
+//! Julian Days are in Universal Time unless noted otherwise.
+
+/// Julian Day of the J2000.0 epoch.
+const J2000: f64 = 2451545.0;
+
+/// First Julian Day Number of the Gregorian calendar (1582-10-15).
+const GREGORIAN_REFORM_JDN: i64 = 2299161;
+
+/// Mean length of a synodic month in days.
+const SYNODIC_MONTH: f64 = 29.530588861;
+
+/// Periodic terms of the new moon `[coefficient, power of E, M, M', F, Ω]`, where the
+/// argument of the sine is the sum of the multiples of M, M', F and Ω.
+const NEW_MOON_TERMS: [[f64; 6]; 25] = [
+    [-0.40720, 0.0, 0.0, 1.0, 0.0, 0.0],
+    [0.17241, 1.0, 1.0, 0.0, 0.0, 0.0],
+    [0.01608, 0.0, 0.0, 2.0, 0.0, 0.0],
+    [0.01039, 0.0, 0.0, 0.0, 2.0, 0.0],
+    [0.00739, 1.0, -1.0, 1.0, 0.0, 0.0],
+    [-0.00514, 1.0, 1.0, 1.0, 0.0, 0.0],
+    [0.00208, 2.0, 2.0, 0.0, 0.0, 0.0],
+    [-0.00111, 0.0, 0.0, 1.0, -2.0, 0.0],
+    [-0.00057, 0.0, 0.0, 1.0, 2.0, 0.0],
+    [0.00056, 1.0, 1.0, 2.0, 0.0, 0.0],
+    [-0.00042, 0.0, 0.0, 3.0, 0.0, 0.0],
+    [0.00042, 1.0, 1.0, 0.0, 2.0, 0.0],
+    [0.00038, 1.0, 1.0, 0.0, -2.0, 0.0],
+    [-0.00024, 1.0, -1.0, 2.0, 0.0, 0.0],
+    [-0.00017, 0.0, 0.0, 0.0, 0.0, 1.0],
+    [-0.00007, 0.0, 2.0, 1.0, 0.0, 0.0],
+    [0.00004, 0.0, 0.0, 2.0, -2.0, 0.0],
+    [0.00004, 0.0, 3.0, 0.0, 0.0, 0.0],
+    [0.00003, 0.0, 1.0, 1.0, -2.0, 0.0],
+    [0.00003, 0.0, 0.0, 2.0, 2.0, 0.0],
+    [-0.00003, 0.0, 1.0, 1.0, 2.0, 0.0],
+    [0.00003, 0.0, -1.0, 1.0, 2.0, 0.0],
+    [-0.00002, 0.0, -1.0, 1.0, -2.0, 0.0],
+    [-0.00002, 0.0, 1.0, 3.0, 0.0, 0.0],
+    [0.00002, 0.0, 0.0, 4.0, 0.0, 0.0],
+];
+
+/// Additional planetary terms of the new moon `[coefficient, A at k = 0, A per lunation]`.
+const NEW_MOON_PLANETARY_TERMS: [[f64; 3]; 14] = [
+    [0.000325, 299.77, 0.107408],
+    [0.000165, 251.88, 0.016321],
+    [0.000164, 251.83, 26.651886],
+    [0.000126, 349.42, 36.412478],
+    [0.000110, 84.66, 18.206239],
+    [0.000062, 141.74, 53.303771],
+    [0.000060, 207.14, 2.453732],
+    [0.000056, 154.84, 7.306860],
+    [0.000047, 34.52, 27.261239],
+    [0.000042, 207.19, 0.121824],
+    [0.000040, 291.34, 1.844379],
+    [0.000037, 161.72, 24.198154],
+    [0.000035, 239.56, 25.513099],
+    [0.000023, 331.55, 3.592518],
+];
+
+/// Earth heliocentric longitude terms `[A, B, C]` for `A * cos(B + C * tau)`, in 1e-8 radians.
+const EARTH_L0: [[f64; 3]; 64] = [
+    [175347046.0, 0.0, 0.0],
+    [3341656.0, 4.6692568, 6283.0758500],
+    [34894.0, 4.62610, 12566.15170],
+    [3497.0, 2.7441, 5753.3849],
+    [3418.0, 2.8289, 3.5231],
+    [3136.0, 3.6277, 77713.7715],
+    [2676.0, 4.4181, 7860.4194],
+    [2343.0, 6.1352, 3930.2097],
+    [1324.0, 0.7425, 11506.7698],
+    [1273.0, 2.0371, 529.6910],
+    [1199.0, 1.1096, 1577.3435],
+    [990.0, 5.233, 5884.927],
+    [902.0, 2.045, 26.298],
+    [857.0, 3.508, 398.149],
+    [780.0, 1.179, 5223.694],
+    [753.0, 2.533, 5507.553],
+    [505.0, 4.583, 18849.228],
+    [492.0, 4.205, 775.523],
+    [357.0, 2.920, 0.067],
+    [317.0, 5.849, 11790.629],
+    [284.0, 1.899, 796.298],
+    [271.0, 0.315, 10977.079],
+    [243.0, 0.345, 5486.778],
+    [206.0, 4.806, 2544.314],
+    [205.0, 1.869, 5573.143],
+    [202.0, 2.458, 6069.777],
+    [156.0, 0.833, 213.299],
+    [132.0, 3.411, 2942.463],
+    [126.0, 1.083, 20.775],
+    [115.0, 0.645, 0.980],
+    [103.0, 0.636, 4694.003],
+    [102.0, 0.976, 15720.839],
+    [102.0, 4.267, 7.114],
+    [99.0, 6.21, 2146.17],
+    [98.0, 0.68, 155.42],
+    [86.0, 5.98, 161000.69],
+    [85.0, 1.30, 6275.96],
+    [85.0, 3.67, 71430.70],
+    [80.0, 1.81, 17260.15],
+    [79.0, 3.04, 12036.46],
+    [75.0, 1.76, 5088.63],
+    [74.0, 3.50, 3154.69],
+    [74.0, 4.68, 801.82],
+    [70.0, 0.83, 9437.76],
+    [62.0, 3.98, 8827.39],
+    [61.0, 1.82, 7084.90],
+    [57.0, 2.78, 6286.60],
+    [56.0, 4.39, 14143.50],
+    [56.0, 3.47, 6279.55],
+    [52.0, 0.19, 12139.55],
+    [52.0, 1.33, 1748.02],
+    [51.0, 0.28, 5856.48],
+    [49.0, 0.49, 1194.45],
+    [41.0, 5.37, 8429.24],
+    [41.0, 2.40, 19651.05],
+    [39.0, 6.17, 10447.39],
+    [37.0, 6.04, 10213.29],
+    [37.0, 2.57, 1059.38],
+    [36.0, 1.71, 2352.87],
+    [36.0, 1.78, 6812.77],
+    [33.0, 0.59, 17789.85],
+    [30.0, 0.44, 83996.85],
+    [30.0, 2.74, 1349.87],
+    [25.0, 3.16, 4690.48],
+];
+
+const EARTH_L1: [[f64; 3]; 34] = [
+    [628331966747.0, 0.0, 0.0],
+    [206059.0, 2.678235, 6283.075850],
+    [4303.0, 2.6351, 12566.1517],
+    [425.0, 1.590, 3.523],
+    [119.0, 5.796, 26.298],
+    [109.0, 2.966, 1577.344],
+    [93.0, 2.59, 18849.23],
+    [72.0, 1.14, 529.69],
+    [68.0, 1.87, 398.15],
+    [67.0, 4.41, 5507.55],
+    [59.0, 2.89, 5223.69],
+    [56.0, 2.17, 155.42],
+    [45.0, 0.40, 796.30],
+    [36.0, 0.47, 775.52],
+    [29.0, 2.65, 7.11],
+    [21.0, 5.34, 0.98],
+    [19.0, 1.85, 5486.78],
+    [19.0, 4.97, 213.30],
+    [17.0, 2.99, 6275.96],
+    [16.0, 0.03, 2544.31],
+    [16.0, 1.43, 2146.17],
+    [15.0, 1.21, 10977.08],
+    [12.0, 2.83, 1748.02],
+    [12.0, 3.26, 5088.63],
+    [12.0, 5.27, 1194.45],
+    [12.0, 2.08, 4694.00],
+    [11.0, 0.77, 553.57],
+    [10.0, 1.30, 6286.60],
+    [10.0, 4.24, 1349.87],
+    [9.0, 2.70, 242.73],
+    [9.0, 5.64, 951.72],
+    [8.0, 5.30, 2352.87],
+    [6.0, 2.65, 9437.76],
+    [6.0, 4.67, 4690.48],
+];
+
+const EARTH_L2: [[f64; 3]; 20] = [
+    [52919.0, 0.0, 0.0],
+    [8720.0, 1.0721, 6283.0758],
+    [309.0, 0.867, 12566.152],
+    [27.0, 0.05, 3.52],
+    [16.0, 5.19, 26.30],
+    [16.0, 3.68, 155.42],
+    [10.0, 0.76, 18849.23],
+    [9.0, 2.06, 77713.77],
+    [7.0, 0.83, 775.52],
+    [5.0, 4.66, 1577.34],
+    [4.0, 1.03, 7.11],
+    [4.0, 3.44, 5573.14],
+    [3.0, 5.14, 796.30],
+    [3.0, 6.05, 5507.55],
+    [3.0, 1.19, 242.73],
+    [3.0, 6.12, 529.69],
+    [3.0, 0.31, 398.15],
+    [3.0, 2.28, 553.57],
+    [2.0, 4.38, 5223.69],
+    [2.0, 3.75, 0.98],
+];
+
+const EARTH_L3: [[f64; 3]; 7] = [
+    [289.0, 5.844, 6283.076],
+    [35.0, 0.0, 0.0],
+    [17.0, 5.49, 12566.15],
+    [3.0, 5.20, 155.42],
+    [1.0, 4.72, 3.52],
+    [1.0, 5.30, 18849.23],
+    [1.0, 5.97, 242.73],
+];
+
+// The published coefficients round π to the precision of each series.
+#[allow(clippy::approx_constant)]
+const EARTH_L4: [[f64; 3]; 3] = [
+    [114.0, 3.142, 0.0],
+    [8.0, 4.13, 6283.08],
+    [1.0, 3.84, 12566.15],
+];
+
+#[allow(clippy::approx_constant)]
+const EARTH_L5: [[f64; 3]; 1] = [[1.0, 3.14, 0.0]];
+
+/// Leading Earth radius vector terms, in 1e-8 AU. Only used for the aberration.
+const EARTH_R0: [[f64; 3]; 3] = [
+    [100013989.0, 0.0, 0.0],
+    [1670700.0, 3.0984635, 6283.0758500],
+    [13956.0, 3.05525, 12566.15170],
+];
+
+const EARTH_R1: [[f64; 3]; 1] = [[103019.0, 1.107490, 6283.075850]];
+
+fn sum_series(terms: &[[f64; 3]], tau: f64) -> f64 {
+    terms.iter().map(|[a, b, c]| a * (b + c * tau).cos()).sum()
+}
+
+/// Normalizes an angle in degrees to `[0, 360)`.
+pub(crate) fn normalize_degrees(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.0)
+}
+
+/// Estimates ΔT = TT - UT in seconds using the polynomial expressions of
+/// Espenak and Meeus (NASA Five Millennium Canon).
+pub(crate) fn delta_t_seconds(year: f64) -> f64 {
+    if year < 1600.0 {
+        let u = (year - 1000.0) / 100.0;
+        1574.2 - 556.01 * u + 71.23472 * u.powi(2) + 0.319781 * u.powi(3)
+            - 0.8503463 * u.powi(4)
+            - 0.005050998 * u.powi(5)
+            + 0.0083572073 * u.powi(6)
+    } else if year < 1700.0 {
+        let t = year - 1600.0;
+        120.0 - 0.9808 * t - 0.01532 * t.powi(2) + t.powi(3) / 7129.0
+    } else if year < 1800.0 {
+        let t = year - 1700.0;
+        8.83 + 0.1603 * t - 0.0059285 * t.powi(2) + 0.00013336 * t.powi(3) - t.powi(4) / 1174000.0
+    } else if year < 1860.0 {
+        let t = year - 1800.0;
+        13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3)
+            - 0.00037436 * t.powi(4)
+            + 0.0000121272 * t.powi(5)
+            - 0.0000001699 * t.powi(6)
+            + 0.000000000875 * t.powi(7)
+    } else if year < 1900.0 {
+        let t = year - 1860.0;
+        7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3) - 0.0004473624 * t.powi(4)
+            + t.powi(5) / 233174.0
+    } else if year < 1920.0 {
+        let t = year - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if year < 1941.0 {
+        let t = year - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if year < 1961.0 {
+        let t = year - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if year < 1986.0 {
+        let t = year - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if year < 2005.0 {
+        let t = year - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if year < 2050.0 {
+        let t = year - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+    } else if year < 2150.0 {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u.powi(2) - 0.5628 * (2150.0 - year)
+    } else {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u.powi(2)
+    }
+}
+
+/// Converts a Julian Day in Universal Time to Julian Ephemeris Day (Terrestrial Time).
+pub(crate) fn ut_to_tt(jd_ut: f64) -> f64 {
+    let year = 2000.0 + (jd_ut - J2000) / 365.25;
+    jd_ut + delta_t_seconds(year) / 86400.0
+}
+
+/// Returns the apparent geocentric ecliptic longitude of the sun in degrees
+/// for a Julian Ephemeris Day.
+pub(crate) fn sun_apparent_longitude(jde: f64) -> f64 {
+    let tau = (jde - J2000) / 365250.0;
+
+    let l = (sum_series(&EARTH_L0, tau)
+        + sum_series(&EARTH_L1, tau) * tau
+        + sum_series(&EARTH_L2, tau) * tau.powi(2)
+        + sum_series(&EARTH_L3, tau) * tau.powi(3)
+        + sum_series(&EARTH_L4, tau) * tau.powi(4)
+        + sum_series(&EARTH_L5, tau) * tau.powi(5))
+        / 1e8;
+    let r = (sum_series(&EARTH_R0, tau) + sum_series(&EARTH_R1, tau) * tau) / 1e8;
+
+    // Geocentric longitude, converted to the FK5 system
+    let mut longitude = l.to_degrees() + 180.0 - 0.09033 / 3600.0;
+
+    // Nutation in longitude (low-precision expression)
+    let t = tau * 10.0;
+    let omega = (125.04452 - 1934.136261 * t).to_radians();
+    let sun_mean = (280.4665 + 36000.7698 * t).to_radians();
+    let moon_mean = (218.3165 + 481267.8813 * t).to_radians();
+    let nutation =
+        -17.20 * omega.sin() - 1.32 * (2.0 * sun_mean).sin() - 0.23 * (2.0 * moon_mean).sin()
+            + 0.21 * (2.0 * omega).sin();
+
+    // Aberration
+    let aberration = -20.4898 / r;
+
+    longitude += (nutation + aberration) / 3600.0;
+
+    normalize_degrees(longitude)
+}
+
+/// Finds the instant (Julian Day, UT) nearest to `jd_guess` at which the apparent
+/// longitude of the sun equals `target_degrees`.
+pub(crate) fn find_sun_longitude(target_degrees: f64, jd_guess: f64) -> f64 {
+    // Mean motion of the sun in days per degree
+    const DAYS_PER_DEGREE: f64 = 365.242189 / 360.0;
+
+    let mut jd = jd_guess;
+
+    for _ in 0..20 {
+        let longitude = sun_apparent_longitude(ut_to_tt(jd));
+        let delta = (target_degrees - longitude + 540.0).rem_euclid(360.0) - 180.0;
+        let step = delta * DAYS_PER_DEGREE;
+        jd += step;
+
+        if step.abs() < 1e-7 {
+            break;
+        }
+    }
+
+    jd
+}
+
+/// Returns the instant (Julian Ephemeris Day) of the new moon with lunation number `k`,
+/// counted from the new moon of 2000-01-06 (Meeus, chapter 49).
+pub(crate) fn new_moon_jde(k: i64) -> f64 {
+    let k = k as f64;
+    let t = k / 1236.85;
+
+    let mut jde = 2451550.09766 + SYNODIC_MONTH * k + 0.00015437 * t.powi(2)
+        - 0.000000150 * t.powi(3)
+        + 0.00000000073 * t.powi(4);
+
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t.powi(2);
+    let sun_anomaly =
+        (2.5534 + 29.10535670 * k - 0.0000014 * t.powi(2) - 0.00000011 * t.powi(3)).to_radians();
+    let moon_anomaly =
+        (201.5643 + 385.81693528 * k + 0.0107582 * t.powi(2) + 0.00001238 * t.powi(3)
+            - 0.000000058 * t.powi(4))
+        .to_radians();
+    let latitude = (160.7108 + 390.67050284 * k - 0.0016118 * t.powi(2) - 0.00000227 * t.powi(3)
+        + 0.000000011 * t.powi(4))
+    .to_radians();
+    let node =
+        (124.7746 - 1.56375588 * k + 0.0020672 * t.powi(2) + 0.00000215 * t.powi(3)).to_radians();
+
+    for [coefficient, e_power, m, m_prime, f, omega] in NEW_MOON_TERMS {
+        let argument = m * sun_anomaly + m_prime * moon_anomaly + f * latitude + omega * node;
+        jde += coefficient * e.powi(e_power as i32) * argument.sin();
+    }
+
+    for (index, [coefficient, base, rate]) in NEW_MOON_PLANETARY_TERMS.into_iter().enumerate() {
+        let mut argument = base + rate * k;
+        if index == 0 {
+            argument -= 0.009173 * t.powi(2);
+        }
+        jde += coefficient * argument.to_radians().sin();
+    }
+
+    jde
+}
+
+/// Returns the instant (Julian Day, UT) of the new moon with lunation number `k`.
+pub(crate) fn new_moon(k: i64) -> f64 {
+    let jde = new_moon_jde(k);
+    let year = 2000.0 + (jde - J2000) / 365.25;
+
+    jde - delta_t_seconds(year) / 86400.0
+}
+
+/// Returns the lunation number of the last new moon at or before `jd` (UT).
+pub(crate) fn lunation_before(jd: f64) -> i64 {
+    let mut k = ((jd - 2451550.09766) / SYNODIC_MONTH).floor() as i64;
+
+    while new_moon(k + 1) <= jd {
+        k += 1;
+    }
+    while new_moon(k) > jd {
+        k -= 1;
+    }
+
+    k
+}
+
+/// Returns the equation of time (apparent minus mean solar time) in minutes for a
+/// Julian Ephemeris Day, using Meeus's approximation (28.3).
+pub(crate) fn equation_of_time_minutes(jde: f64) -> f64 {
+    let t = (jde - J2000) / 36525.0;
+    let sun_mean = (280.46646 + 36000.76983 * t + 0.0003032 * t * t).to_radians();
+    let anomaly = (357.52911 + 35999.05029 * t - 0.0001537 * t * t).to_radians();
+    let eccentricity = 0.016708634 - 0.000042037 * t - 0.0000001267 * t * t;
+    let obliquity = (23.4392911 - 0.0130042 * t).to_radians();
+    let y = (obliquity / 2.0).tan().powi(2);
+
+    let e = y * (2.0 * sun_mean).sin() - 2.0 * eccentricity * anomaly.sin()
+        + 4.0 * eccentricity * y * anomaly.sin() * (2.0 * sun_mean).cos()
+        - 0.5 * y * y * (4.0 * sun_mean).sin()
+        - 1.25 * eccentricity * eccentricity * (2.0 * anomaly).sin();
+
+    // 1° of hour angle is 4 minutes of time
+    e.to_degrees() * 4.0
+}
+
+/// Converts a Julian Day Number to a (year, month, day) date, using the Julian
+/// calendar before 1582-10-15 and the Gregorian calendar afterwards.
+pub(crate) fn date_from_jdn(jdn: i64) -> (i32, u32, u32) {
+    let a = if jdn < GREGORIAN_REFORM_JDN {
+        jdn
+    } else {
+        let alpha = ((jdn as f64 - 1867216.25) / 36524.25).floor() as i64;
+        jdn + 1 + alpha - alpha.div_euclid(4)
+    };
+    let b = a + 1524;
+    let c = ((b as f64 - 122.1) / 365.25).floor() as i64;
+    let d = (365.25 * c as f64).floor() as i64;
+    let e = ((b - d) as f64 / 30.6001).floor() as i64;
+
+    let day = b - d - (30.6001 * e as f64).floor() as i64;
+    let month = if e < 14 { e - 1 } else { e - 13 };
+    let year = if month > 2 { c - 4716 } else { c - 4715 };
+
+    (year as i32, month as u32, day as u32)
+}
+
+/// Converts a (year, month, day) date to a Julian Day Number, using the Julian
+/// calendar before 1582-10-15 and the Gregorian calendar afterwards.
+pub(crate) const fn jdn_from_date(year: i32, month: u32, day: u32) -> i64 {
+    let (y, m) = if month <= 2 {
+        (year as i64 - 1, month as i64 + 12)
+    } else {
+        (year as i64, month as i64)
+    };
+    let julian =
+        (1461 * (y + 4716)).div_euclid(4) + (153 * (m + 1)).div_euclid(5) + day as i64 - 1524;
+
+    if julian < GREGORIAN_REFORM_JDN + 10 {
+        julian
+    } else {
+        let century = y.div_euclid(100);
+        julian + 2 - century + century.div_euclid(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        date_from_jdn, equation_of_time_minutes, find_sun_longitude, jdn_from_date,
+        lunation_before, new_moon, new_moon_jde, sun_apparent_longitude,
+    };
+
+    #[test]
+    fn test_sun_apparent_longitude() {
+        // Meeus, Example 25.b: 1992-10-13 0h TD, apparent longitude 199°54'21.818"
+        let longitude = sun_apparent_longitude(2448908.5);
+
+        assert!((longitude - 199.906061).abs() < 0.0005, "{}", longitude);
+    }
+
+    #[test]
+    fn test_equation_of_time() {
+        // Meeus, Example 28.b: 1992-10-13 0h TD, E = 13m42.7s
+        let minutes = equation_of_time_minutes(2448908.5);
+
+        assert!((minutes - 13.712).abs() < 0.01, "{}", minutes);
+    }
+
+    #[test]
+    fn test_find_march_equinox_2000() {
+        // 2000-03-20 07:35 UT
+        let jd = find_sun_longitude(0.0, 2451624.0);
+        let expected = 2451623.5 + (7.0 * 60.0 + 35.0) / 1440.0;
+
+        assert!((jd - expected).abs() < 2.0 / 1440.0, "{}", jd);
+    }
+
+    #[test]
+    fn test_new_moon() {
+        // Meeus, Example 49.a: the new moon of 1977-02-18, JDE 2443192.65118
+        let jde = new_moon_jde(-283);
+
+        assert!((jde - 2443192.65118).abs() < 1e-5, "{}", jde);
+
+        // 2024-01-11 11:57 UT
+        let jd = new_moon(297);
+        let expected = 2460320.5 + (11.0 * 60.0 + 57.0) / 1440.0;
+
+        assert!((jd - expected).abs() < 2.0 / 1440.0, "{}", jd);
+        assert_eq!(lunation_before(jd), 297);
+        assert_eq!(lunation_before(jd - 0.001), 296);
+    }
+
+    #[test]
+    fn test_jdn_round_trip() {
+        for (year, month, day, jdn) in [
+            (2022, 7, 10, 2459771),
+            (1582, 10, 4, 2299160),
+            (1582, 10, 15, 2299161),
+            (1391, 2, 5, 2229156),
+            (1390, 12, 22, 2229111),
+        ] {
+            assert_eq!(jdn_from_date(year, month, day), jdn);
+            assert_eq!(date_from_jdn(jdn), (year, month, day));
+        }
+    }
+}
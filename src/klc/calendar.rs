@@ -0,0 +1,303 @@
+//! Lunisolar calendar variants.
+//!
+//! The Korean, Chinese, Vietnamese and Japanese lunisolar calendars follow the same
+//! rules but date new moons and principal terms (중기) in different time zones, so they
+//! disagree on a month whenever a new moon falls near midnight. A [`LunisolarCalendar`]
+//! provides the lunar data of one variant, and
+//! [`LunarSolarConverter`](crate::LunarSolarConverter) is parameterized by it. This crate
+//! provides the [`Korean`] and [`Chinese`] calendars.
+//!
+//! # Example
+//! ```
+//! use rs_klc::LunarSolarConverter;
+//! use rs_klc::calendar::Chinese;
+//!
+//! let mut converter = LunarSolarConverter::<Chinese>::default();
+//! converter.set_solar_date(2024, 2, 10);
+//! assert_eq!(converter.get_lunar_iso_format(), "2024-01-01");
+//! ```
+
+use std::fmt;
+use std::hash::Hash;
+
+use super::{
+    KOREAN_LUNAR_BASE_YEAR, KOREAN_LUNAR_DATA, KlcError, LunarSolarConverter, SolarDate,
+    tables::{self, CalendarTables},
+};
+
+/// Lunar years 1901-2050 of the Chinese calendar, generated by
+/// `klc-datagen emit --from 1901 --to 2050 --utc-offset +08:00`.
+pub(super) const CHINESE_LUNAR_DATA: [u32; 150] = [
+    0x82c404ae, 0x82c60a57, 0x82fe554d, 0xc2c40d26, 0x82c60d95, 0x83014655, 0x82c4056a, 0xc2c609ad,
+    0x8300255d, 0x82c404ae, 0x83006a5b, 0xc2c40a4d, 0x82c40d25, 0x83015d29, 0x82c60b55, 0xc2c4056a,
+    0x83002ada, 0x82c6095b, 0x83017497, 0xc2c4049b, 0x82c40a4b, 0x83005b4b, 0x82c406a5, 0xc2c406d4,
+    0x83034ab5, 0x82c402b6, 0x82c60957, 0xc300252f, 0x82c40497, 0x82fe6656, 0x82c40d4a, 0xc2c60ea5,
+    0x830156a9, 0x82c605ad, 0x82c402b6, 0xc301386e, 0x82c4092e, 0x83017c8d, 0x82c40c95, 0xc2c40d4a,
+    0x83016d8a, 0x82c60b55, 0x82c4056a, 0xc3034a5b, 0x82c4025d, 0x82c4092d, 0x83002d2b, 0xc2c40a95,
+    0x83007b55, 0x82c406ca, 0x82c60b55, 0xc3015535, 0x82c404da, 0x82c60a5b, 0x83013457, 0xc2c4052b,
+    0x82fe8a9a, 0x82c60e95, 0x82c406aa, 0xc3006aea, 0x82c60ab5, 0x82c404b6, 0x83004aae, 0xc2c60a57,
+    0x82c20526, 0x83003f26, 0x82c60d95, 0xc30075b5, 0x82c4056a, 0x82c6096d, 0x830054dd, 0xc2c404ad,
+    0x82c40a4d, 0x83004d4d, 0x82c40d25, 0xc3008d55, 0x82c40b54, 0x82c60b6a, 0x8301695a, 0xc2c6095b,
+    0x82c4049b, 0x83004a97, 0x82c40a4b, 0xc300ab27, 0x82c406a5, 0x82c406d4, 0x83006af4, 0xc2c60ab6,
+    0x82c60957, 0x830054af, 0x82c40497, 0xc2c4064b, 0x82fe374a, 0x82c60ea5, 0x830086b5, 0xc2c405ac,
+    0x82c60ab6, 0x8300596d, 0x82c4092e, 0xc2c40c96, 0x83004d95, 0x82c40d4a, 0x82c60da5, 0xc3002755,
+    0x82c4056a, 0x83027abb, 0x82c4025d, 0xc2c4092d, 0x83005cab, 0x82c40a95, 0x82c40b4a, 0xc3004baa,
+    0x82c60ad5, 0x8300955d, 0x82c404ba, 0xc2c60a5b, 0x83016517, 0x82c4052b, 0x82c40a93, 0xc3004795,
+    0x82c406aa, 0x82c60ad5, 0x830025b5, 0xc2c404b6, 0x83006a6e, 0x82c40a4e, 0x82c40d26, 0xc3005ea6,
+    0x82c60d53, 0x82c405aa, 0x8300376a, 0xc2c6096d, 0x8300b4af, 0x82c404ad, 0x82c40a4d, 0xc3016d0b,
+    0x82c40d25, 0x82c40d52, 0x83005dd4, 0xc2c60b5a, 0x82c6056d, 0x8300255b, 0x82c4049b, 0xc3007a57,
+    0x82c40a4b, 0x82c40aa5, 0x83015b25, 0xc2c406d2, 0x82c60ada, 0x830134b6,
+];
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The lunar data of a lunisolar calendar.
+///
+/// Implemented by [`Korean`] and [`Chinese`]. This trait is sealed and cannot be
+/// implemented outside this crate, since each calendar's day tables are built into it.
+pub trait LunisolarCalendar:
+    fmt::Debug + Default + Clone + Copy + PartialEq + Eq + Hash + Send + Sync + 'static + sealed::Sealed
+{
+    /// The English name of the calendar.
+    const NAME: &'static str;
+
+    /// The first lunar year of [`LUNAR_DATA`](Self::LUNAR_DATA).
+    const BASE_YEAR: i32;
+
+    /// One bit-packed entry per lunar year from [`BASE_YEAR`](Self::BASE_YEAR), in the
+    /// layout of `KOREAN_LUNAR_DATA`. Later years are computed astronomically.
+    const LUNAR_DATA: &'static [u32];
+
+    /// The offset from UTC, in minutes, of the time in which new moons and principal
+    /// terms are dated.
+    const UTC_OFFSET_MINUTES: i32;
+
+    #[doc(hidden)]
+    fn tables() -> &'static CalendarTables;
+
+    /// Returns the intercalary month (윤달) of a lunar year, or `None` if the year has
+    /// none or is outside the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::calendar::{Chinese, Korean, LunisolarCalendar};
+    ///
+    /// assert_eq!(Korean::intercalary_month(2023), Some(2));
+    /// assert_eq!(Chinese::intercalary_month(2023), Some(2));
+    /// ```
+    fn intercalary_month(year: i32) -> Option<u32> {
+        Self::tables().intercalation_month(year)
+    }
+}
+
+/// The Korean calendar, reckoned in Korean Standard Time (UTC+9). This is the default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Korean;
+
+/// The Chinese calendar, reckoned in China Standard Time (UTC+8).
+///
+/// Its data starts in 1901. Before 1929 the official calendar was reckoned in Beijing
+/// local time, so a few of the earliest months may differ from historical almanacs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Chinese;
+
+impl sealed::Sealed for Korean {}
+
+impl LunisolarCalendar for Korean {
+    const NAME: &'static str = "Korean";
+    const BASE_YEAR: i32 = KOREAN_LUNAR_BASE_YEAR;
+    const LUNAR_DATA: &'static [u32] = &KOREAN_LUNAR_DATA;
+    const UTC_OFFSET_MINUTES: i32 = 9 * 60;
+
+    fn tables() -> &'static CalendarTables {
+        &tables::KOREAN_TABLES
+    }
+}
+
+impl sealed::Sealed for Chinese {}
+
+impl LunisolarCalendar for Chinese {
+    const NAME: &'static str = "Chinese";
+    const BASE_YEAR: i32 = 1901;
+    const LUNAR_DATA: &'static [u32] = &CHINESE_LUNAR_DATA;
+    const UTC_OFFSET_MINUTES: i32 = 8 * 60;
+
+    fn tables() -> &'static CalendarTables {
+        &tables::CHINESE_TABLES
+    }
+}
+
+/// A lunar date in a [`LunisolarCalendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarDate {
+    /// The lunar year.
+    pub year: i32,
+    /// The lunar month (1-12).
+    pub month: u32,
+    /// The lunar day (1-30).
+    pub day: u32,
+    /// `true` if the month is an intercalary month (윤달).
+    pub is_leap_month: bool,
+}
+
+impl fmt::Display for CalendarDate {
+    /// Formats the date like [`LunarDate`](crate::LunarDate).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)?;
+
+        if self.is_leap_month {
+            f.write_str(" Intercalation")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A solar date on which two calendars give different lunar dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarDifference {
+    /// The solar date.
+    pub date: SolarDate,
+    /// The lunar date in the first calendar.
+    pub first: CalendarDate,
+    /// The lunar date in the second calendar.
+    pub second: CalendarDate,
+}
+
+/// Returns the days of a solar year on which the calendars `A` and `B` give different
+/// lunar dates, in chronological order.
+///
+/// Days outside the range of either calendar are skipped.
+///
+/// # Errors
+/// Returns [`KlcError::OutOfRange`] if the year is outside the range of both calendars.
+///
+/// # Example
+/// ```
+/// use rs_klc::calendar::{self, Chinese, Korean};
+///
+/// // The new moon fell at 23:06 on 1997-02-07 in China, 00:06 on 1997-02-08 in Korea
+/// let differences = calendar::differences::<Korean, Chinese>(1997).unwrap();
+/// let first = differences[0];
+///
+/// assert_eq!(first.date.to_string(), "1997-02-07");
+/// assert_eq!(first.first.to_string(), "1996-12-30");
+/// assert_eq!(first.second.to_string(), "1997-01-01");
+/// ```
+pub fn differences<A: LunisolarCalendar, B: LunisolarCalendar>(
+    year: u32,
+) -> Result<Vec<CalendarDifference>, KlcError> {
+    let (_, _, first_min, max) = A::tables().range();
+    let (_, _, second_min, _) = B::tables().range();
+    let min = first_min.max(second_min);
+
+    if year < min / 10000 || year > max / 10000 {
+        return Err(KlcError::OutOfRange { min, max });
+    }
+
+    let mut first = LunarSolarConverter::<A>::default();
+    let mut second = LunarSolarConverter::<B>::default();
+    let mut differences = Vec::new();
+
+    let last_day = SolarDate::new(year, 12, 31)?.julian_day_number() as i64;
+    let mut jdn = SolarDate::new(year, 1, 1)?.julian_day_number() as i64;
+
+    while jdn <= last_day {
+        let date = SolarDate::from_julian_day_number(jdn)?;
+        jdn += 1;
+
+        if first
+            .try_set_solar_date(date.year(), date.month(), date.day())
+            .is_err()
+            || second
+                .try_set_solar_date(date.year(), date.month(), date.day())
+                .is_err()
+        {
+            continue;
+        }
+
+        let first_date = first.calendar_date();
+        let second_date = second.calendar_date();
+        if first_date != second_date {
+            differences.push(CalendarDifference {
+                date,
+                first: first_date,
+                second: second_date,
+            });
+        }
+    }
+
+    Ok(differences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chinese, Korean, LunisolarCalendar, differences};
+    use crate::{KlcError, LunarSolarConverter};
+
+    #[test]
+    fn test_chinese_new_year() {
+        let mut converter = LunarSolarConverter::<Chinese>::default();
+
+        for (year, month, day) in [
+            (1901, 2, 19),
+            (1950, 2, 17),
+            (2000, 2, 5),
+            (2001, 1, 24),
+            (2020, 1, 25),
+            (2033, 1, 31),
+            (2050, 1, 23),
+        ] {
+            assert!(converter.set_solar_date(year, month, day));
+            assert_eq!(
+                converter.get_lunar_iso_format(),
+                format!("{}-01-01", year),
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn test_chinese_range() {
+        let mut converter = LunarSolarConverter::<Chinese>::default();
+
+        assert_eq!(
+            converter.try_set_solar_date(1901, 2, 18),
+            Err(KlcError::OutOfRange {
+                min: 19010219,
+                max: 22001231
+            })
+        );
+        assert!(converter.set_lunar_date(1901, 1, 1, false));
+        assert_eq!(converter.get_solar_iso_format(), "1901-02-19");
+        assert!(converter.set_solar_date(2200, 12, 31));
+        assert_eq!(converter.get_lunar_iso_format(), "2200-11-25");
+    }
+
+    #[test]
+    fn test_intercalary_month() {
+        assert_eq!(Chinese::intercalary_month(2020), Some(4));
+        assert_eq!(Chinese::intercalary_month(1900), None);
+        assert_eq!(Korean::NAME, "Korean");
+    }
+
+    #[test]
+    fn test_differences() {
+        assert_eq!(differences::<Korean, Chinese>(2024), Ok(Vec::new()));
+
+        assert!(matches!(
+            differences::<Korean, Chinese>(1900),
+            Err(KlcError::OutOfRange { min: 19010219, .. })
+        ));
+
+        // 2012: 윤3월 in Korea, 윤4월 in China
+        let leap_months = differences::<Korean, Chinese>(2012).unwrap();
+        assert_eq!(leap_months.len(), 119);
+        assert_eq!(leap_months[0].date.to_string(), "2012-04-21");
+        assert_eq!(leap_months[0].first.to_string(), "2012-03-01 Intercalation");
+        assert_eq!(leap_months[0].second.to_string(), "2012-04-01");
+    }
+}
@@ -0,0 +1,153 @@
+//! Conversions with [`chrono::NaiveDate`], enabled by the `chrono` feature.
+//!
+//! Conversions keep the day, which has a different date in chrono before 1582-10-15
+//! (see [`SolarDate::julian_day_number`]).
+//!
+//! # Example
+//! ```
+//! use chrono::NaiveDate;
+//! use rs_klc::{NaiveDateExt, SolarDate};
+//!
+//! let date = NaiveDate::from_ymd_opt(2022, 7, 10).unwrap();
+//! assert_eq!(date.to_korean_lunar().unwrap().to_string(), "2022-06-12");
+//! assert_eq!(date.gapja().unwrap(), "임인년 정미월 갑자일");
+//!
+//! let solar = SolarDate::try_from(date).unwrap();
+//! assert_eq!(NaiveDate::from(solar), date);
+//! ```
+
+use chrono::{Datelike, NaiveDate};
+
+use super::{KlcError, LunarDate, LunarSolarConverter, SolarDate};
+
+/// Julian Day Number of 0000-12-31 in the proleptic Gregorian calendar, the day before
+/// chrono's day 1 of the common era.
+const CE_EPOCH_JDN: i64 = 1721425;
+
+/// Korean lunar calendar methods for [`chrono::NaiveDate`].
+pub trait NaiveDateExt {
+    /// Returns the Korean lunar date of this day.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the day is outside the supported range.
+    fn to_korean_lunar(&self) -> Result<LunarDate, KlcError>;
+
+    /// Returns the Gapja (간지) of this day, as
+    /// [`LunarSolarConverter::get_gapja_string`] (e.g. "임인년 정미월 갑자일").
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the day is outside the supported range.
+    fn gapja(&self) -> Result<String, KlcError>;
+}
+
+impl NaiveDateExt for NaiveDate {
+    fn to_korean_lunar(&self) -> Result<LunarDate, KlcError> {
+        Ok(SolarDate::try_from(*self)?.to_lunar())
+    }
+
+    fn gapja(&self) -> Result<String, KlcError> {
+        let solar = SolarDate::try_from(*self)?;
+        let mut converter = LunarSolarConverter::new();
+        converter.try_set_solar_date(solar.year(), solar.month(), solar.day())?;
+
+        Ok(converter.get_gapja_string())
+    }
+}
+
+impl TryFrom<NaiveDate> for SolarDate {
+    type Error = KlcError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, KlcError> {
+        SolarDate::from_julian_day_number(date.num_days_from_ce() as i64 + CE_EPOCH_JDN)
+    }
+}
+
+impl From<SolarDate> for NaiveDate {
+    fn from(date: SolarDate) -> Self {
+        let days = date.julian_day_number() as i64 - CE_EPOCH_JDN;
+
+        NaiveDate::from_num_days_from_ce_opt(days as i32)
+            .expect("every supported date is within the range of NaiveDate")
+    }
+}
+
+impl TryFrom<NaiveDate> for LunarDate {
+    type Error = KlcError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, KlcError> {
+        date.to_korean_lunar()
+    }
+}
+
+impl From<LunarDate> for NaiveDate {
+    fn from(date: LunarDate) -> Self {
+        NaiveDate::from(date.to_solar())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, NaiveDate};
+
+    use super::NaiveDateExt;
+    use crate::{KlcError, LunarDate, SolarDate};
+
+    fn naive(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_gregorian_dates() {
+        let date = naive(2023, 4, 5);
+        let lunar = LunarDate::new(2023, 2, 15, true).unwrap();
+
+        assert_eq!(date.to_korean_lunar(), Ok(lunar));
+        assert_eq!(NaiveDate::from(lunar), date);
+        assert_eq!(
+            SolarDate::try_from(date),
+            Ok(SolarDate::new(2023, 4, 5).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_julian_dates() {
+        // chrono's proleptic 1582-10-14 is the day before the reform, Julian 1582-10-04
+        assert_eq!(
+            SolarDate::try_from(naive(1582, 10, 14)),
+            Ok(SolarDate::new(1582, 10, 4).unwrap())
+        );
+        assert_eq!(
+            SolarDate::try_from(naive(1582, 10, 15)),
+            Ok(SolarDate::new(1582, 10, 15).unwrap())
+        );
+        assert_eq!(
+            NaiveDate::from(SolarDate::new(1391, 2, 5).unwrap()),
+            naive(1391, 2, 13)
+        );
+    }
+
+    #[test]
+    fn test_days_from_ce() {
+        // Day 1 of the common era is the day after CE_EPOCH_JDN
+        assert_eq!(
+            NaiveDate::from(SolarDate::new(2000, 1, 1).unwrap()).num_days_from_ce(),
+            730120
+        );
+        assert_eq!(
+            SolarDate::try_from(naive(2200, 12, 31)),
+            Ok(SolarDate::new(2200, 12, 31).unwrap())
+        );
+        assert!(SolarDate::try_from(NaiveDate::MIN).is_err());
+        assert!(SolarDate::try_from(NaiveDate::MAX).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert!(matches!(
+            SolarDate::try_from(naive(1391, 2, 12)),
+            Err(KlcError::OutOfRange { .. })
+        ));
+        assert!(naive(2201, 1, 1).gapja().is_err());
+        assert!(naive(-100, 1, 1).to_korean_lunar().is_err());
+    }
+}
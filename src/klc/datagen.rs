@@ -0,0 +1,217 @@
+//! Regeneration and verification of the embedded `KOREAN_LUNAR_DATA` table.
+//!
+//! This module backs the `klc-datagen` binary. It is not part of the stable API.
+
+use std::fmt;
+
+use super::{KOREAN_LUNAR_BASE_YEAR, KOREAN_LUNAR_DATA, KlcError, LunarSolarConverter, lunation};
+
+/// First year of the embedded table.
+pub const TABLE_FIRST_YEAR: i32 = KOREAN_LUNAR_BASE_YEAR;
+
+/// Last year of the embedded table.
+pub const TABLE_LAST_YEAR: i32 = KOREAN_LUNAR_BASE_YEAR + KOREAN_LUNAR_DATA.len() as i32 - 1;
+
+/// Number of table entries per line in the emitted source, as in the embedded table.
+const ENTRIES_PER_LINE: usize = 8;
+
+/// The month lengths of a lunar year, decoded from a table entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearData {
+    /// The number of days (29 or 30) of each regular month.
+    pub month_days: [u32; 12],
+    /// The intercalary month and its number of days, if the year has one.
+    pub leap_month: Option<(u32, u32)>,
+}
+
+impl YearData {
+    /// Decodes a table entry.
+    pub fn decode(lunar_data: u32) -> Self {
+        let month_days = std::array::from_fn(|index| {
+            LunarSolarConverter::get_lunar_month_days(lunar_data, index as u32 + 1, false)
+        });
+        let leap_month = match LunarSolarConverter::get_lunar_intercalation_month(lunar_data) {
+            0 => None,
+            month => Some((
+                month,
+                LunarSolarConverter::get_lunar_month_days(lunar_data, month, true),
+            )),
+        };
+
+        YearData {
+            month_days,
+            leap_month,
+        }
+    }
+
+    /// Returns the number of days in the year.
+    pub fn year_days(&self) -> u32 {
+        self.month_days.iter().sum::<u32>() + self.leap_month.map_or(0, |(_, days)| days)
+    }
+}
+
+/// A year where the embedded table and the astronomical computation disagree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearDifference {
+    /// The lunar year.
+    pub year: i32,
+    /// The year as stored in the embedded table.
+    pub table: YearData,
+    /// The year as computed astronomically.
+    pub computed: YearData,
+}
+
+impl fmt::Display for YearDifference {
+    /// Lists each disagreement as `table -> computed`, e.g.
+    /// `1890: month 1 29 -> 30 days; leap month 2 (29 days) -> none`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut differences = Vec::new();
+
+        for (index, (table, computed)) in self
+            .table
+            .month_days
+            .iter()
+            .zip(self.computed.month_days)
+            .enumerate()
+        {
+            if *table != computed {
+                differences.push(format!(
+                    "month {} {} -> {} days",
+                    index + 1,
+                    table,
+                    computed
+                ));
+            }
+        }
+
+        if self.table.leap_month != self.computed.leap_month {
+            let describe = |leap_month: Option<(u32, u32)>| match leap_month {
+                Some((month, days)) => format!("{} ({} days)", month, days),
+                None => "none".to_string(),
+            };
+            differences.push(format!(
+                "leap month {} -> {}",
+                describe(self.table.leap_month),
+                describe(self.computed.leap_month)
+            ));
+        }
+
+        write!(f, "{}: {}", self.year, differences.join("; "))
+    }
+}
+
+/// Returns the embedded table entry of a year, or `None` outside the table.
+pub fn table_data(year: i32) -> Option<u32> {
+    (TABLE_FIRST_YEAR..=TABLE_LAST_YEAR)
+        .contains(&year)
+        .then(|| KOREAN_LUNAR_DATA[(year - TABLE_FIRST_YEAR) as usize])
+}
+
+/// Computes the table entry of a year astronomically, with the calendar reckoned at
+/// `utc_offset_minutes` east of Greenwich (540 for Korean Standard Time).
+pub fn computed_data(year: i32, utc_offset_minutes: i32) -> u32 {
+    lunation::lunar_year_data(year, utc_offset_minutes as f64 / 1440.0)
+}
+
+/// Compares the embedded table with the computation for the years `from..=to`, and
+/// returns the years where the month lengths or the intercalary month disagree.
+///
+/// # Errors
+/// Returns [`KlcError::OutOfRange`] if the years are not within the embedded table.
+pub fn diff(from: i32, to: i32, utc_offset_minutes: i32) -> Result<Vec<YearDifference>, KlcError> {
+    if from < TABLE_FIRST_YEAR || to > TABLE_LAST_YEAR {
+        return Err(KlcError::OutOfRange {
+            min: TABLE_FIRST_YEAR as u32 * 10000 + 101,
+            max: TABLE_LAST_YEAR as u32 * 10000 + 1231,
+        });
+    }
+
+    Ok((from..=to)
+        .filter_map(|year| {
+            let table = YearData::decode(table_data(year)?);
+            let computed = YearData::decode(computed_data(year, utc_offset_minutes));
+
+            (table != computed).then_some(YearDifference {
+                year,
+                table,
+                computed,
+            })
+        })
+        .collect())
+}
+
+/// Computes the years `from..=to` and emits them as the Rust source of a table.
+pub fn to_rust_source(name: &str, from: i32, to: i32, utc_offset_minutes: i32) -> String {
+    let entries: Vec<String> = (from..=to)
+        .map(|year| format!("{:#010x}", computed_data(year, utc_offset_minutes)))
+        .collect();
+
+    let mut source = format!(
+        "// Lunar years {}-{}, generated by klc-datagen\nconst {}: [u32; {}] = [\n",
+        from,
+        to,
+        name,
+        entries.len()
+    );
+    for line in entries.chunks(ENTRIES_PER_LINE) {
+        source.push_str(&format!("    {},\n", line.join(", ")));
+    }
+    source.push_str("];\n");
+
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{YearData, diff, table_data, to_rust_source};
+    use crate::KlcError;
+
+    const KST_MINUTES: i32 = 9 * 60;
+
+    #[test]
+    fn test_decode_year_data() {
+        // 2023 has 윤2월 of 29 days
+        let year = YearData::decode(table_data(2023).unwrap());
+
+        assert_eq!(year.leap_month, Some((2, 29)));
+        assert_eq!(year.month_days[1], 30);
+        assert_eq!(year.year_days(), 384);
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff(1912, 2050, KST_MINUTES), Ok(Vec::new()));
+
+        let differences = diff(1890, 1895, KST_MINUTES).unwrap();
+        let years: Vec<i32> = differences
+            .iter()
+            .map(|difference| difference.year)
+            .collect();
+        assert_eq!(years, [1890, 1895]);
+        assert_eq!(
+            differences[0].to_string(),
+            "1890: month 1 29 -> 30 days; month 2 30 -> 29 days; month 11 29 -> 30 days; \
+             month 12 30 -> 29 days; leap month 2 (29 days) -> none"
+        );
+        assert_eq!(differences[1].to_string(), "1895: month 12 29 -> 30 days");
+
+        assert!(matches!(
+            diff(2040, 2051, KST_MINUTES),
+            Err(KlcError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_to_rust_source() {
+        let source = to_rust_source("KOREAN_LUNAR_DATA", 2020, 2028, KST_MINUTES);
+        let lines: Vec<&str> = source.lines().collect();
+
+        assert_eq!(lines[1], "const KOREAN_LUNAR_DATA: [u32; 9] = [");
+        assert!(lines[2].starts_with(&format!("    {:#010x}, ", table_data(2020).unwrap())));
+        assert_eq!(
+            lines[3],
+            format!("    {:#010x},", table_data(2028).unwrap())
+        );
+        assert_eq!(lines[4], "];");
+    }
+}
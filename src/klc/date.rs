@@ -0,0 +1,284 @@
+use std::fmt;
+
+use super::calendar::{Korean, LunisolarCalendar};
+use super::{DayOfWeek, KlcError, LunarSolarConverter, astro};
+
+/// An immutable Solar (Gregorian) date within the supported range.
+///
+/// Dates before 1582-10-15 follow the Julian calendar, matching the rest of the crate.
+/// Values are validated on construction, so every `SolarDate` can be converted.
+///
+/// # Example
+/// ```
+/// use rs_klc::SolarDate;
+///
+/// let solar = SolarDate::new(2022, 7, 10).unwrap();
+/// let lunar = solar.to_lunar();
+/// assert_eq!(lunar.to_string(), "2022-06-12");
+/// assert_eq!(lunar.to_solar(), solar);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SolarDate {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+/// An immutable Korean Lunar date within the supported range.
+///
+/// Ordering follows the calendar: an intercalary month (윤달) sorts after the
+/// regular month with the same number.
+///
+/// # Example
+/// ```
+/// use rs_klc::LunarDate;
+///
+/// let lunar = LunarDate::new(2023, 2, 15, true).unwrap(); // 윤2월 15일
+/// assert_eq!(lunar.to_solar().to_string(), "2023-04-05");
+/// assert!(LunarDate::new(2022, 6, 12, true).is_err()); // 2022 has no 윤6월
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LunarDate {
+    // Field order matters for the derived `Ord`: a leap month follows its regular month.
+    year: i32,
+    month: u32,
+    is_leap_month: bool,
+    day: u32,
+}
+
+impl SolarDate {
+    /// Creates a new `SolarDate`.
+    ///
+    /// # Errors
+    /// Returns a [`KlcError`] if the date is invalid, within the 1582 Gregorian
+    /// reform gap or outside the supported range.
+    pub fn new(year: u32, month: u32, day: u32) -> Result<Self, KlcError> {
+        LunarSolarConverter::try_check_valid_date(false, false, year, month, day)?;
+
+        Ok(SolarDate { year, month, day })
+    }
+
+    /// Creates the `SolarDate` of a Julian Day Number.
+    pub(crate) fn from_julian_day_number(jdn: i64) -> Result<Self, KlcError> {
+        let (year, month, day) = astro::date_from_jdn(jdn);
+
+        // A negative year wraps around and is rejected as out of range
+        SolarDate::new(year as u32, month, day)
+    }
+
+    /// Creates the `SolarDate` of a 1-based day count since the Korean base date
+    /// (lunar 1391-01-01).
+    pub(super) fn from_abs_days(abs_days: u32) -> Self {
+        let (year, month, day) = LunarSolarConverter::get_solar_date_by_abs_days(abs_days);
+
+        SolarDate { year, month, day }
+    }
+
+    /// Returns the 1-based day count since the Korean base date.
+    pub(super) fn abs_days(&self) -> u32 {
+        LunarSolarConverter::get_solar_abs_days(self.year as i32, self.month, self.day)
+    }
+
+    /// Returns the solar year.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Returns the solar month (1-12).
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the solar day.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Converts this date to the corresponding Korean Lunar date.
+    pub fn to_lunar(&self) -> LunarDate {
+        LunarDate::from_abs_days(self.abs_days())
+    }
+
+    /// Returns the Julian Day Number (JDN) of this date.
+    ///
+    /// The conversions with `chrono`, `time` and `jiff` dates go through the JDN, so they
+    /// keep the day rather than its year, month and day. Those crates are proleptic
+    /// Gregorian, so before 1582-10-15 the same day has a different date there: their
+    /// 1582-10-14 is 1582-10-04 here, and 1391-02-13 is 1391-02-05.
+    pub fn julian_day_number(&self) -> u32 {
+        LunarSolarConverter::get_julian_day_number(self.year, self.month, self.day)
+            .expect("a validated SolarDate always has a JDN")
+    }
+
+    /// Returns the day of the week of this date.
+    pub fn day_of_week(&self) -> DayOfWeek {
+        LunarSolarConverter::get_day_of_week(self.year, self.month, self.day)
+            .expect("a validated SolarDate always has a day of week")
+    }
+}
+
+impl LunarDate {
+    /// Creates a new `LunarDate`.
+    ///
+    /// # Arguments
+    /// * `is_leap_month` - `true` if the month is an intercalary (leap) month (윤달).
+    ///
+    /// # Errors
+    /// Returns a [`KlcError`] if the date is invalid or outside the supported range,
+    /// including when `is_leap_month` is set but the year has no such intercalary month.
+    pub fn new(year: i32, month: u32, day: u32, is_leap_month: bool) -> Result<Self, KlcError> {
+        if year < 0 {
+            return Err(KlcError::NegativeYear(year));
+        }
+
+        LunarSolarConverter::try_check_valid_date(true, is_leap_month, year as u32, month, day)?;
+
+        Ok(LunarDate {
+            year,
+            month,
+            is_leap_month,
+            day,
+        })
+    }
+
+    /// Creates the `LunarDate` of a 1-based day count since the Korean base date.
+    pub(super) fn from_abs_days(abs_days: u32) -> Self {
+        let (year, month, day, is_leap_month) = Korean::tables().lunar_date(abs_days);
+
+        LunarDate {
+            year,
+            month,
+            is_leap_month,
+            day,
+        }
+    }
+
+    /// Returns the 1-based day count since the Korean base date.
+    pub(super) fn abs_days(&self) -> u32 {
+        Korean::tables().lunar_abs_days(self.year, self.month, self.day, self.is_leap_month)
+    }
+
+    /// Returns the lunar year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the lunar month (1-12).
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the lunar day.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns `true` if this date is in an intercalary month (윤달).
+    pub fn is_leap_month(&self) -> bool {
+        self.is_leap_month
+    }
+
+    /// Converts this date to the corresponding Solar date.
+    pub fn to_solar(&self) -> SolarDate {
+        SolarDate::from_abs_days(self.abs_days())
+    }
+}
+
+impl fmt::Display for SolarDate {
+    /// Formats the date as ISO 8601 (YYYY-MM-DD).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for LunarDate {
+    /// Formats the date as ISO 8601 (YYYY-MM-DD), followed by " Intercalation"
+    /// for an intercalary month, like [`LunarSolarConverter::get_lunar_iso_format`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)?;
+
+        if self.is_leap_month {
+            f.write_str(" Intercalation")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LunarDate, SolarDate};
+    use crate::{DayOfWeek, KlcError};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_solar_date_new() {
+        assert!(SolarDate::new(2022, 7, 10).is_ok());
+        assert!(SolarDate::new(2022, 2, 29).is_err());
+        assert!(SolarDate::new(1582, 10, 10).is_err());
+        assert!(SolarDate::new(1391, 2, 4).is_err());
+        assert!(SolarDate::new(2201, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_lunar_date_new() {
+        assert!(LunarDate::new(2022, 6, 12, false).is_ok());
+        assert!(LunarDate::new(2023, 2, 30, true).is_err()); // 윤2월 2023 has 29 days
+        assert!(LunarDate::new(2022, 6, 12, true).is_err());
+        assert_eq!(
+            LunarDate::new(-1, 1, 1, false),
+            Err(KlcError::NegativeYear(-1))
+        );
+    }
+
+    #[test]
+    fn test_solar_to_lunar() {
+        let lunar = SolarDate::new(2022, 7, 10).unwrap().to_lunar();
+
+        assert_eq!(lunar, LunarDate::new(2022, 6, 12, false).unwrap());
+    }
+
+    #[test]
+    fn test_lunar_to_solar_intercalation() {
+        let solar = LunarDate::new(2023, 2, 15, true).unwrap().to_solar();
+
+        assert_eq!(solar, SolarDate::new(2023, 4, 5).unwrap());
+        assert!(solar.to_lunar().is_leap_month());
+    }
+
+    #[test]
+    fn test_round_trip_reform_gap() {
+        let solar = SolarDate::new(1582, 10, 15).unwrap();
+
+        assert_eq!(solar.to_lunar().to_solar(), solar);
+        assert_eq!(solar.day_of_week(), DayOfWeek::Friday);
+    }
+
+    #[test]
+    fn test_lunar_date_ordering() {
+        let regular = LunarDate::new(2023, 2, 29, false).unwrap();
+        let leap = LunarDate::new(2023, 2, 1, true).unwrap();
+        let next = LunarDate::new(2023, 3, 1, false).unwrap();
+
+        assert!(regular < leap && leap < next);
+        assert!(regular.to_solar() < leap.to_solar());
+    }
+
+    #[test]
+    fn test_dates_as_map_keys() {
+        let mut map = HashMap::new();
+        map.insert(SolarDate::new(2022, 7, 10).unwrap(), "solar");
+        map.insert(SolarDate::new(2022, 7, 10).unwrap(), "again");
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&SolarDate::new(2022, 7, 10).unwrap()], "again");
+    }
+
+    #[test]
+    fn test_display() {
+        let lunar = LunarDate::new(2023, 2, 15, true).unwrap();
+
+        assert_eq!(lunar.to_string(), "2023-02-15 Intercalation");
+        assert_eq!(lunar.to_solar().to_string(), "2023-04-05");
+    }
+}
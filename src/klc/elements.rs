@@ -0,0 +1,378 @@
+//! The five elements (오행) and yin and yang (음양) of the stems and branches.
+
+use std::fmt;
+
+use super::sexagenary::display_locale;
+use super::{EarthlyBranch, FourPillars, Gapja, HeavenlyStem, Locale, Pillar, Sexagenary};
+
+const KOREAN_ELEMENTS: [&str; 5] = ["목", "화", "토", "금", "수"];
+const HANJA_ELEMENTS: [&str; 5] = ["木", "火", "土", "金", "水"];
+const ENGLISH_ELEMENTS: [&str; 5] = ["Wood", "Fire", "Earth", "Metal", "Water"];
+const VIETNAMESE_ELEMENTS: [&str; 5] = ["Mộc", "Hỏa", "Thổ", "Kim", "Thủy"];
+
+/// Elements of the earthly branches, from 자 (수) to 해 (수).
+const BRANCH_ELEMENTS: [Element; 12] = [
+    Element::Water,
+    Element::Earth,
+    Element::Wood,
+    Element::Wood,
+    Element::Earth,
+    Element::Fire,
+    Element::Fire,
+    Element::Earth,
+    Element::Metal,
+    Element::Metal,
+    Element::Earth,
+    Element::Water,
+];
+
+/// The five elements (오행, 五行), in the order of 상생 (相生): each generates the next.
+///
+/// `Display` writes the Korean name, or the Hanja name with the alternate flag (`{:#}`).
+///
+/// # Example
+/// ```
+/// use rs_klc::{Element, ElementRelation, HeavenlyStem};
+///
+/// let wood = HeavenlyStem::Gap.element();
+/// assert_eq!(format!("{} {:#}", wood, wood), "목 木");
+/// assert_eq!(wood.generates(), Element::Fire); // 목생화
+/// assert_eq!(wood.overcomes(), Element::Earth); // 목극토
+/// assert_eq!(Element::Metal.relation_to(wood), ElementRelation::Overcomes); // 금극목
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Element {
+    /// 목 (木)
+    Wood,
+    /// 화 (火)
+    Fire,
+    /// 토 (土)
+    Earth,
+    /// 금 (金)
+    Metal,
+    /// 수 (水)
+    Water,
+}
+
+/// The relation of an element to another under 상생 (相生) and 상극 (相剋).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElementRelation {
+    /// The same element (비화, 比和).
+    Same,
+    /// The element generates the other (상생, e.g. 목생화).
+    Generates,
+    /// The element is generated by the other (e.g. 화 from 목).
+    GeneratedBy,
+    /// The element overcomes the other (상극, e.g. 목극토).
+    Overcomes,
+    /// The element is overcome by the other (e.g. 토 by 목).
+    OvercomeBy,
+}
+
+/// Yin and yang (음양, 陰陽).
+///
+/// `Display` writes the Korean name, or the Hanja name with the alternate flag (`{:#}`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YinYang {
+    /// 양 (陽)
+    Yang,
+    /// 음 (陰)
+    Yin,
+}
+
+/// The number of stems and branches of each element across a set of pillars.
+///
+/// Each pillar counts twice: once for the element of its stem and once for that of its
+/// branch.
+///
+/// # Example
+/// ```
+/// use rs_klc::{Element, FourPillars, SolarDate};
+///
+/// // 갑진년 병인월 무술일 신유시
+/// let pillars = FourPillars::new(SolarDate::new(2024, 2, 4).unwrap(), 17, 30).unwrap();
+/// let distribution = pillars.element_distribution();
+///
+/// assert_eq!(distribution.count(Element::Earth), 3);
+/// assert_eq!(distribution.total(), 8);
+/// assert_eq!(distribution.missing().collect::<Vec<_>>(), [Element::Water]);
+/// assert_eq!(distribution.to_string(), "목2 화1 토3 금2 수0");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ElementDistribution {
+    counts: [u32; 5],
+}
+
+impl Element {
+    /// All elements, in the order of 상생 starting from 목.
+    pub const ALL: [Element; 5] = [
+        Element::Wood,
+        Element::Fire,
+        Element::Earth,
+        Element::Metal,
+        Element::Water,
+    ];
+
+    /// Returns the position of this element in [`Element::ALL`] (0 for 목).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the element at the given position in [`Element::ALL`], wrapping around.
+    pub fn from_index(index: usize) -> Element {
+        Self::ALL[index % Self::ALL.len()]
+    }
+
+    /// Returns the element this one generates (상생: 목생화, 화생토, 토생금, 금생수, 수생목).
+    pub fn generates(self) -> Element {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Returns the element this one overcomes (상극: 목극토, 토극수, 수극화, 화극금, 금극목).
+    pub fn overcomes(self) -> Element {
+        Self::from_index(self.index() + 2)
+    }
+
+    /// Returns the relation of this element to another.
+    pub fn relation_to(self, other: Element) -> ElementRelation {
+        match (other.index() + Self::ALL.len() - self.index()) % Self::ALL.len() {
+            0 => ElementRelation::Same,
+            1 => ElementRelation::Generates,
+            2 => ElementRelation::Overcomes,
+            3 => ElementRelation::OvercomeBy,
+            _ => ElementRelation::GeneratedBy,
+        }
+    }
+
+    /// Returns the name of this element in a locale (e.g. "목", "木", "Wood", "Mộc").
+    pub fn name_in(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Korean => KOREAN_ELEMENTS[self.index()],
+            Locale::Hanja | Locale::Japanese => HANJA_ELEMENTS[self.index()],
+            Locale::English => ENGLISH_ELEMENTS[self.index()],
+            Locale::Vietnamese => VIETNAMESE_ELEMENTS[self.index()],
+        }
+    }
+}
+
+impl YinYang {
+    /// Returns the name in a locale (e.g. "양", "陽", "Yang", "Dương").
+    pub fn name_in(self, locale: Locale) -> &'static str {
+        match (self, locale) {
+            (YinYang::Yang, Locale::Korean) => "양",
+            (YinYang::Yin, Locale::Korean) => "음",
+            (YinYang::Yang, Locale::Hanja | Locale::Japanese) => "陽",
+            (YinYang::Yin, Locale::Hanja | Locale::Japanese) => "陰",
+            (YinYang::Yang, Locale::English) => "Yang",
+            (YinYang::Yin, Locale::English) => "Yin",
+            (YinYang::Yang, Locale::Vietnamese) => "Dương",
+            (YinYang::Yin, Locale::Vietnamese) => "Âm",
+        }
+    }
+
+    fn from_index(index: usize) -> YinYang {
+        if index.is_multiple_of(2) {
+            YinYang::Yang
+        } else {
+            YinYang::Yin
+        }
+    }
+}
+
+impl HeavenlyStem {
+    /// Returns the element of this stem (갑을 목, 병정 화, 무기 토, 경신 금, 임계 수).
+    pub fn element(self) -> Element {
+        Element::from_index(self.index() / 2)
+    }
+
+    /// Returns the polarity of this stem: 갑, 병, 무, 경 and 임 are yang.
+    pub fn yin_yang(self) -> YinYang {
+        YinYang::from_index(self.index())
+    }
+}
+
+impl EarthlyBranch {
+    /// Returns the element of this branch (인묘 목, 사오 화, 신유 금, 해자 수, and
+    /// 진술축미 토).
+    pub fn element(self) -> Element {
+        BRANCH_ELEMENTS[self.index()]
+    }
+
+    /// Returns the polarity of this branch by its position in the cycle: 자, 인, 진, 오,
+    /// 신 and 술 are yang.
+    pub fn yin_yang(self) -> YinYang {
+        YinYang::from_index(self.index())
+    }
+}
+
+impl Sexagenary {
+    /// Returns the polarity of the pair, which its stem and branch share.
+    pub fn yin_yang(&self) -> YinYang {
+        self.stem().yin_yang()
+    }
+}
+
+impl ElementDistribution {
+    /// Counts the elements of the stems and branches of some pillars.
+    pub fn from_pillars(pillars: impl IntoIterator<Item = Sexagenary>) -> Self {
+        let mut distribution = ElementDistribution::default();
+
+        for pillar in pillars {
+            distribution.counts[pillar.stem().element().index()] += 1;
+            distribution.counts[pillar.branch().element().index()] += 1;
+        }
+
+        distribution
+    }
+
+    /// Returns the number of stems and branches of an element.
+    pub fn count(&self, element: Element) -> u32 {
+        self.counts[element.index()]
+    }
+
+    /// Returns the number of stems and branches counted, twice the number of pillars.
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the elements absent from the pillars, in the order of [`Element::ALL`].
+    pub fn missing(&self) -> impl Iterator<Item = Element> + '_ {
+        Element::ALL
+            .into_iter()
+            .filter(|&element| self.count(element) == 0)
+    }
+}
+
+impl Gapja {
+    /// Counts the elements of the year, month and day pillars, and of the hour pillar
+    /// if given.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{Element, LunarSolarConverter};
+    ///
+    /// let mut converter = LunarSolarConverter::new();
+    /// converter.set_solar_date(2022, 7, 10); // 임인년 정미월 갑자일
+    /// let gapja = converter.gapja().unwrap();
+    ///
+    /// let distribution = gapja.element_distribution(None);
+    /// assert_eq!(distribution.count(Element::Water), 2);
+    /// assert_eq!(distribution.total(), 6);
+    /// ```
+    pub fn element_distribution(&self, hour: Option<Pillar>) -> ElementDistribution {
+        ElementDistribution::from_pillars([self.year, self.month, self.day].into_iter().chain(hour))
+    }
+}
+
+impl FourPillars {
+    /// Counts the elements of the year, month, day and hour pillars.
+    pub fn element_distribution(&self) -> ElementDistribution {
+        ElementDistribution::from_pillars([self.year(), self.month(), self.day(), self.hour()])
+    }
+}
+
+impl fmt::Display for Element {
+    /// Formats the element with its Korean name (e.g. "목"), or its Hanja name with `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name_in(display_locale(f)))
+    }
+}
+
+impl fmt::Display for YinYang {
+    /// Formats the polarity with its Korean name (e.g. "양"), or its Hanja name with `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name_in(display_locale(f)))
+    }
+}
+
+impl fmt::Display for ElementDistribution {
+    /// Formats the counts after the Korean names of the elements (e.g. "목2 화1 토3 금2 수0").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, element) in Element::ALL.into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}{}", element, self.count(element))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Element, ElementDistribution, ElementRelation, YinYang};
+    use crate::{EarthlyBranch, HeavenlyStem, Sexagenary};
+
+    #[test]
+    fn test_relations() {
+        for element in Element::ALL {
+            assert_eq!(element.relation_to(element), ElementRelation::Same);
+            assert_eq!(
+                element.relation_to(element.generates()),
+                ElementRelation::Generates
+            );
+            assert_eq!(
+                element.generates().relation_to(element),
+                ElementRelation::GeneratedBy
+            );
+            assert_eq!(
+                element.relation_to(element.overcomes()),
+                ElementRelation::Overcomes
+            );
+            assert_eq!(
+                element.overcomes().relation_to(element),
+                ElementRelation::OvercomeBy
+            );
+        }
+
+        assert_eq!(Element::Water.overcomes(), Element::Fire);
+        assert_eq!(Element::Fire.overcomes(), Element::Metal);
+        assert_eq!(Element::Water.generates(), Element::Wood);
+    }
+
+    #[test]
+    fn test_stem_and_branch_attributes() {
+        let stems: String = HeavenlyStem::ALL
+            .iter()
+            .map(|stem| stem.element().to_string())
+            .collect();
+        let branches: String = EarthlyBranch::ALL
+            .iter()
+            .map(|branch| branch.element().to_string())
+            .collect();
+
+        assert_eq!(stems, "목목화화토토금금수수");
+        assert_eq!(branches, "수토목목토화화토금금토수");
+        assert_eq!(HeavenlyStem::Gye.yin_yang(), YinYang::Yin);
+        assert_eq!(EarthlyBranch::O.yin_yang(), YinYang::Yang);
+        assert_eq!(format!("{} {:#}", YinYang::Yin, YinYang::Yin), "음 陰");
+
+        for index in 1..=60 {
+            let pair = Sexagenary::from_cycle_index(index).unwrap();
+            assert_eq!(pair.stem().yin_yang(), pair.branch().yin_yang());
+        }
+    }
+
+    #[test]
+    fn test_distribution() {
+        let empty = ElementDistribution::from_pillars([]);
+        assert_eq!(empty.total(), 0);
+        assert_eq!(empty.missing().count(), 5);
+
+        // 경신년 경진월 경신일 경진시: 금 6, 토 2
+        let gyeongsin = Sexagenary::new(HeavenlyStem::Gyeong, EarthlyBranch::Sin).unwrap();
+        let gyeongjin = Sexagenary::new(HeavenlyStem::Gyeong, EarthlyBranch::Jin).unwrap();
+        let distribution =
+            ElementDistribution::from_pillars([gyeongsin, gyeongjin, gyeongsin, gyeongjin]);
+
+        assert_eq!(distribution.count(Element::Metal), 6);
+        assert_eq!(distribution.count(Element::Earth), 2);
+        assert_eq!(
+            distribution.missing().collect::<Vec<_>>(),
+            [Element::Wood, Element::Fire, Element::Water]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::error::Error;
+use std::fmt;
+
+/// Describes why a date was rejected by the converter.
+///
+/// # Example
+/// ```
+/// use rs_klc::{KlcError, LunarSolarConverter};
+///
+/// let mut converter = LunarSolarConverter::new();
+/// assert_eq!(
+///     converter.try_set_lunar_date(2022, 6, 12, true),
+///     Err(KlcError::InvalidLeapMonth { year: 2022, month: 6, leap_month: None })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KlcError {
+    /// The date is outside the supported range.
+    /// `min` and `max` are the inclusive bounds encoded as `YYYYMMDD`.
+    OutOfRange { min: u32, max: u32 },
+    /// The month is not within 1-12.
+    InvalidMonth(u32),
+    /// The day is zero or exceeds the number of days in the month.
+    InvalidDay { day: u32, days_in_month: u32 },
+    /// An intercalary month (윤달) was requested, but the lunar year has no
+    /// intercalary month (`leap_month` is `None`) or a different one.
+    InvalidLeapMonth {
+        year: i32,
+        month: u32,
+        leap_month: Option<u32>,
+    },
+    /// The solar date falls within the Gregorian reform gap (1582-10-05 to 1582-10-14).
+    GregorianGap { day: u32 },
+    /// The year is negative.
+    NegativeYear(i32),
+    /// The time of day is not within 00:00-23:59.
+    InvalidTime { hour: u32, minute: u32 },
+}
+
+fn fmt_date_value(f: &mut fmt::Formatter<'_>, date_value: u32) -> fmt::Result {
+    write!(
+        f,
+        "{:04}-{:02}-{:02}",
+        date_value / 10000,
+        date_value / 100 % 100,
+        date_value % 100
+    )
+}
+
+impl fmt::Display for KlcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            KlcError::OutOfRange { min, max } => {
+                f.write_str("date is out of the supported range ")?;
+                fmt_date_value(f, min)?;
+                f.write_str(" to ")?;
+                fmt_date_value(f, max)
+            }
+            KlcError::InvalidMonth(month) => write!(f, "invalid month {}", month),
+            KlcError::InvalidDay { day, days_in_month } => write!(
+                f,
+                "invalid day {} for a month of {} days",
+                day, days_in_month
+            ),
+            KlcError::InvalidLeapMonth {
+                year,
+                month,
+                leap_month: Some(leap_month),
+            } => write!(
+                f,
+                "lunar year {} has intercalary month {}, not {}",
+                year, leap_month, month
+            ),
+            KlcError::InvalidLeapMonth {
+                year,
+                leap_month: None,
+                ..
+            } => write!(f, "lunar year {} has no intercalary month", year),
+            KlcError::GregorianGap { day } => write!(
+                f,
+                "1582-10-{:02} does not exist (Gregorian reform gap)",
+                day
+            ),
+            KlcError::NegativeYear(year) => write!(f, "negative year {}", year),
+            KlcError::InvalidTime { hour, minute } => {
+                write!(f, "invalid time {:02}:{:02}", hour, minute)
+            }
+        }
+    }
+}
+
+impl Error for KlcError {}
+
+#[cfg(test)]
+mod tests {
+    use super::KlcError;
+
+    #[test]
+    fn test_display_out_of_range() {
+        let err = KlcError::OutOfRange {
+            min: 13910205,
+            max: 20501231,
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "date is out of the supported range 1391-02-05 to 2050-12-31"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_leap_month() {
+        let none = KlcError::InvalidLeapMonth {
+            year: 2022,
+            month: 6,
+            leap_month: None,
+        };
+        let other = KlcError::InvalidLeapMonth {
+            year: 2023,
+            month: 3,
+            leap_month: Some(2),
+        };
+
+        assert_eq!(none.to_string(), "lunar year 2022 has no intercalary month");
+        assert_eq!(
+            other.to_string(),
+            "lunar year 2023 has intercalary month 2, not 3"
+        );
+    }
+}
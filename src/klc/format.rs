@@ -0,0 +1,605 @@
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use super::{DatePair, Locale, LunarDate, ParseError, ParseErrorKind, Pillar, SolarDate};
+
+const KOREAN_DIGITS: [char; 10] = ['영', '일', '이', '삼', '사', '오', '육', '칠', '팔', '구'];
+const HANJA_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+const KOREAN_POWERS: [char; 3] = ['십', '백', '천'];
+const HANJA_POWERS: [char; 3] = ['十', '百', '千'];
+
+/// A compiled date pattern, in the manner of `strftime`.
+///
+/// A pattern is parsed once and can then format any number of dates. Directives:
+///
+/// | Directive | Meaning | Example |
+/// |-----------|---------|---------|
+/// | `%Y` `%m` `%d` | Year, month and day in the calendar of the pattern | `2023` `04` `05` |
+/// | `%sY` `%sm` `%sd` | Solar year, month and day | `2023` `04` `05` |
+/// | `%lY` `%lm` `%ld` | Lunar year, month and day | `2023` `02` `15` |
+/// | `%lB` | Name of the lunar month (`%B` in a lunar pattern) | `이월` |
+/// | `%L` | 윤 in an intercalary month, otherwise nothing | `윤` |
+/// | `%y` `%M` `%D` | Gapja of the year, month and day | `계묘` `을묘` `계사` |
+/// | `%G` | Gapja of the date, as [`LunarSolarConverter::get_gapja_string`](crate::LunarSolarConverter::get_gapja_string) | `계묘년 을묘월 계사일` |
+/// | `%a` `%A` | Day of the week | `수` `수요일` |
+/// | `%z` | Zodiac animal (띠) of the year | `토끼` |
+/// | `%t` | Solar term beginning on the day, otherwise nothing | `청명` |
+/// | `%T` | Solar term in effect on the day | `춘분` |
+/// | `%%` | A literal `%` | `%` |
+///
+/// The fields without a prefix are solar in a pattern made with [`DatePattern::new`] and
+/// lunar in one made with [`DatePattern::lunar`].
+///
+/// Numbers are zero-padded to 4 digits for years and 2 for months and days. A modifier
+/// after `%` changes the style of a directive:
+/// * `-` drops the padding of a number (`%-lm` is `2`),
+/// * `K` writes a number in Korean numerals (`%KlY` is `이천이십삼`),
+/// * `H` writes a number in Hanja numerals (`%Hld` is `十五`) and a name in Hanja
+///   (`%Hy` is `癸卯`, `%HL` is `閏`, `%HA` is `水曜日`).
+///
+/// Names are in Korean unless the pattern is given another [`Locale`] with
+/// [`DatePattern::with_locale`]; the `H` modifier always writes Hanja.
+///
+/// The year and month Gapja follow the
+/// [`GapjaConvention::LunarCalendar`](crate::GapjaConvention::LunarCalendar) convention.
+///
+/// # Example
+/// ```
+/// use rs_klc::{DatePattern, SolarDate};
+///
+/// let pattern = DatePattern::new("음력 %lY년 %L%-lm월 %-ld일 (%y년)").unwrap();
+/// let date = SolarDate::new(2023, 4, 5).unwrap();
+/// assert_eq!(pattern.format(date).to_string(), "음력 2023년 윤2월 15일 (계묘년)");
+///
+/// let pattern = DatePattern::new("%Hy年 %HM月 %HD日 %HL%Hlm月%Hld日").unwrap();
+/// assert_eq!(pattern.format(date).to_string(), "癸卯年 乙卯月 癸巳日 閏二月十五日");
+///
+/// assert_eq!(date.format("%Y.%m.%d(%a) %t").unwrap(), "2023.04.05(수) 청명");
+/// ```
+///
+/// The Gapja directives write Korean names, so a pattern of Hanja units takes the Hanja
+/// locale:
+/// ```
+/// use rs_klc::{DatePattern, Locale, SolarDate};
+///
+/// let date = SolarDate::new(2023, 4, 5).unwrap();
+/// let pattern = DatePattern::new("%y年 %M月 %D日").unwrap();
+/// assert_eq!(pattern.format(date).to_string(), "계묘年 을묘月 계사日");
+///
+/// let pattern = pattern.with_locale(Locale::Hanja);
+/// assert_eq!(pattern.format(date).to_string(), "癸卯年 乙卯月 癸巳日");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DatePattern {
+    items: Vec<Item>,
+    locale: Locale,
+}
+
+/// A date formatted with a [`DatePattern`], written out by its `Display` implementation.
+///
+/// Created by [`DatePattern::format`].
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedDate<'a> {
+    pattern: &'a DatePattern,
+    date: DatePair,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Item {
+    Literal(String),
+    Directive(Directive, Style),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Directive {
+    SolarYear,
+    SolarMonth,
+    SolarDay,
+    LunarYear,
+    LunarMonth,
+    LunarDay,
+    LunarMonthName,
+    LeapMarker,
+    YearGapja,
+    MonthGapja,
+    DayGapja,
+    Gapja,
+    ShortWeekday,
+    Weekday,
+    Zodiac,
+    SolarTermOnDay,
+    SolarTerm,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Style {
+    /// Zero-padded numbers and Korean names.
+    Default,
+    Unpadded,
+    Korean,
+    Hanja,
+}
+
+impl Directive {
+    /// Returns the directive of a character after its calendar prefix, `Some(true)` for
+    /// `l` and `Some(false)` for `s`. Without a prefix, a field is lunar if
+    /// `lunar_fields` is set.
+    fn from_char(prefix: Option<bool>, lunar_fields: bool, c: char) -> Option<Self> {
+        let directive = match (prefix.unwrap_or(lunar_fields), c) {
+            (false, 'Y') => Directive::SolarYear,
+            (false, 'm') => Directive::SolarMonth,
+            (false, 'd') => Directive::SolarDay,
+            (true, 'Y') => Directive::LunarYear,
+            (true, 'm') => Directive::LunarMonth,
+            (true, 'd') => Directive::LunarDay,
+            (true, 'B') => Directive::LunarMonthName,
+            _ if prefix.is_some() => return None,
+            (_, 'L') => Directive::LeapMarker,
+            (_, 'y') => Directive::YearGapja,
+            (_, 'M') => Directive::MonthGapja,
+            (_, 'D') => Directive::DayGapja,
+            (_, 'G') => Directive::Gapja,
+            (_, 'a') => Directive::ShortWeekday,
+            (_, 'A') => Directive::Weekday,
+            (_, 'z') => Directive::Zodiac,
+            (_, 't') => Directive::SolarTermOnDay,
+            (_, 'T') => Directive::SolarTerm,
+            _ => return None,
+        };
+
+        Some(directive)
+    }
+
+    fn is_number(self) -> bool {
+        matches!(
+            self,
+            Directive::SolarYear
+                | Directive::SolarMonth
+                | Directive::SolarDay
+                | Directive::LunarYear
+                | Directive::LunarMonth
+                | Directive::LunarDay
+        )
+    }
+}
+
+impl DatePattern {
+    /// Compiles a pattern whose `%Y`, `%m` and `%d` are the solar date.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] of kind [`ParseErrorKind::UnexpectedToken`] with the span
+    /// of an unknown or incomplete directive, or of a modifier the directive does not take
+    /// (only `H` applies to names).
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::DatePattern;
+    ///
+    /// let pattern = "%Y-%m-%d %q";
+    /// let err = DatePattern::new(pattern).unwrap_err();
+    /// assert_eq!(&pattern[err.span()], "%q");
+    /// ```
+    pub fn new(pattern: &str) -> Result<Self, ParseError> {
+        DatePattern::compile(pattern, false)
+    }
+
+    /// Compiles a pattern whose `%Y`, `%m`, `%d` and `%B` are the lunar date. `%sY`,
+    /// `%sm` and `%sd` still write the solar date.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] as [`DatePattern::new`] does.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{DatePattern, SolarDate};
+    ///
+    /// let pattern = DatePattern::lunar("음력 %Y년 %L%m월 %d일 (%G)").unwrap();
+    /// let date = SolarDate::new(2023, 4, 5).unwrap();
+    /// assert_eq!(
+    ///     pattern.format(date).to_string(),
+    ///     "음력 2023년 윤02월 15일 (계묘년 을묘월 계사일)"
+    /// );
+    ///
+    /// // Without the lunar calendar, the same fields are the solar date
+    /// let pattern = DatePattern::new("%Y년 %m월 %d일").unwrap();
+    /// assert_eq!(pattern.format(date).to_string(), "2023년 04월 05일");
+    /// ```
+    pub fn lunar(pattern: &str) -> Result<Self, ParseError> {
+        DatePattern::compile(pattern, true)
+    }
+
+    fn compile(pattern: &str, lunar_fields: bool) -> Result<Self, ParseError> {
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.char_indices().peekable();
+
+        while let Some((start, c)) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+
+            let mut next = || chars.next().map(|(_, c)| c);
+            let mut c = next();
+            let style = match c {
+                Some('-') => Style::Unpadded,
+                Some('K') => Style::Korean,
+                Some('H') => Style::Hanja,
+                _ => Style::Default,
+            };
+            if style != Style::Default {
+                c = next();
+            }
+            let prefix = match c {
+                Some('l') => Some(true),
+                Some('s') => Some(false),
+                _ => None,
+            };
+            if prefix.is_some() {
+                c = next();
+            }
+
+            let end = chars.peek().map_or(pattern.len(), |&(end, _)| end);
+            let unexpected = || ParseError::new(ParseErrorKind::UnexpectedToken, start..end);
+
+            if c == Some('%') && style == Style::Default && prefix.is_none() {
+                literal.push('%');
+                continue;
+            }
+            let directive = c
+                .and_then(|c| Directive::from_char(prefix, lunar_fields, c))
+                .ok_or_else(unexpected)?;
+            if !directive.is_number() && !matches!(style, Style::Default | Style::Hanja) {
+                return Err(unexpected());
+            }
+
+            if !literal.is_empty() {
+                items.push(Item::Literal(std::mem::take(&mut literal)));
+            }
+            items.push(Item::Directive(directive, style));
+        }
+
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+
+        Ok(DatePattern {
+            items,
+            locale: Locale::Korean,
+        })
+    }
+
+    /// Sets the locale of the names written by the pattern, Korean by default.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{DatePattern, Locale, SolarDate};
+    ///
+    /// let pattern = DatePattern::new("%A, %lB %-ld, %G (%z)")
+    ///     .unwrap()
+    ///     .with_locale(Locale::English);
+    /// let date = SolarDate::new(2022, 7, 10).unwrap();
+    /// assert_eq!(
+    ///     pattern.format(date).to_string(),
+    ///     "Sunday, Sixth Month 12, Imin-nyeon Jeongmi-wol Gapja-il (Tiger)"
+    /// );
+    /// ```
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Formats a date, given as a [`SolarDate`], a [`LunarDate`] or a [`DatePair`].
+    ///
+    /// The result implements `Display`, so it can be written into an existing buffer
+    /// without an intermediate `String`.
+    pub fn format(&self, date: impl Into<DatePair>) -> FormattedDate<'_> {
+        FormattedDate {
+            pattern: self,
+            date: date.into(),
+        }
+    }
+}
+
+impl FromStr for DatePattern {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        DatePattern::new(s)
+    }
+}
+
+impl SolarDate {
+    /// Formats the date with a pattern of [`DatePattern`] directives.
+    ///
+    /// To format many dates, compile the pattern once with [`DatePattern::new`].
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the pattern is invalid.
+    pub fn format(&self, pattern: &str) -> Result<String, ParseError> {
+        Ok(DatePattern::new(pattern)?.format(*self).to_string())
+    }
+}
+
+impl LunarDate {
+    /// Formats the date with a pattern of [`DatePattern`] directives.
+    ///
+    /// To format many dates, compile the pattern once with [`DatePattern::new`].
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the pattern is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::LunarDate;
+    ///
+    /// let date = LunarDate::new(2023, 2, 15, true).unwrap();
+    /// assert_eq!(date.format("%lY-%L%lm-%ld").unwrap(), "2023-윤02-15");
+    /// assert_eq!(date.format("%Y-%m-%d").unwrap(), "2023-04-05");
+    /// ```
+    pub fn format(&self, pattern: &str) -> Result<String, ParseError> {
+        Ok(DatePattern::new(pattern)?.format(*self).to_string())
+    }
+}
+
+impl fmt::Display for FormattedDate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in &self.pattern.items {
+            match item {
+                Item::Literal(literal) => f.write_str(literal)?,
+                Item::Directive(directive, style) => self.write_directive(f, *directive, *style)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FormattedDate<'_> {
+    fn write_directive(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        directive: Directive,
+        style: Style,
+    ) -> fmt::Result {
+        let DatePair {
+            solar,
+            lunar,
+            year_gapja,
+            month_gapja,
+            day_gapja,
+        } = self.date;
+        let locale = if style == Style::Hanja {
+            Locale::Hanja
+        } else {
+            self.pattern.locale
+        };
+
+        match directive {
+            Directive::SolarYear => write_number(f, solar.year(), 4, style),
+            Directive::SolarMonth => write_number(f, solar.month(), 2, style),
+            Directive::SolarDay => write_number(f, solar.day(), 2, style),
+            Directive::LunarYear => write_number(f, lunar.year() as u32, 4, style),
+            Directive::LunarMonth => write_number(f, lunar.month(), 2, style),
+            Directive::LunarDay => write_number(f, lunar.day(), 2, style),
+            Directive::LunarMonthName => f.write_str(locale.lunar_month_name(lunar.month())),
+            Directive::LeapMarker if lunar.is_leap_month() => f.write_str(locale.leap_marker()),
+            Directive::LeapMarker => Ok(()),
+            Directive::YearGapja => write_pillar(f, year_gapja, locale),
+            Directive::MonthGapja => write_pillar(f, month_gapja, locale),
+            Directive::DayGapja => write_pillar(f, day_gapja, locale),
+            Directive::Gapja => self.date.gapja().write_in(f, locale),
+            Directive::ShortWeekday => f.write_str(locale.short_weekday_name(solar.day_of_week())),
+            Directive::Weekday => f.write_str(locale.weekday_name(solar.day_of_week())),
+            Directive::Zodiac => f.write_str(locale.zodiac_name(year_gapja.branch_index())),
+            Directive::SolarTermOnDay | Directive::SolarTerm => {
+                let time = solar.solar_term();
+                if directive == Directive::SolarTermOnDay && time.date() != Ok(solar) {
+                    return Ok(());
+                }
+
+                f.write_str(time.term().name_in(locale))
+            }
+        }
+    }
+}
+
+fn write_pillar(f: &mut fmt::Formatter<'_>, pillar: Pillar, locale: Locale) -> fmt::Result {
+    locale.write_pillar(f, pillar.stem_index(), pillar.branch_index())
+}
+
+fn write_number(f: &mut fmt::Formatter<'_>, value: u32, width: usize, style: Style) -> fmt::Result {
+    match style {
+        Style::Default => write!(f, "{:0width$}", value),
+        Style::Unpadded => write!(f, "{}", value),
+        Style::Korean => write_numerals(f, value, &KOREAN_DIGITS, &KOREAN_POWERS),
+        Style::Hanja => write_numerals(f, value, &HANJA_DIGITS, &HANJA_POWERS),
+    }
+}
+
+/// Writes a number below 10000 in Korean or Hanja numerals, omitting the one before
+/// 십, 백 and 천 (2023 is 이천이십삼, 15 is 십오).
+fn write_numerals(
+    f: &mut fmt::Formatter<'_>,
+    value: u32,
+    digits: &[char; 10],
+    powers: &[char; 3],
+) -> fmt::Result {
+    if value == 0 {
+        return f.write_char(digits[0]);
+    }
+
+    for (place, power) in [
+        (1000, Some(powers[2])),
+        (100, Some(powers[1])),
+        (10, Some(powers[0])),
+        (1, None),
+    ] {
+        let digit = (value / place % 10) as usize;
+
+        match (digit, power) {
+            (0, _) => {}
+            (1, Some(power)) => f.write_char(power)?,
+            (_, Some(power)) => {
+                f.write_char(digits[digit])?;
+                f.write_char(power)?;
+            }
+            (_, None) => f.write_char(digits[digit])?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DatePattern;
+    use crate::{DateRange, Locale, LunarDate, LunarSolarConverter, ParseErrorKind, SolarDate};
+
+    fn format(pattern: &str, date: SolarDate) -> String {
+        DatePattern::new(pattern).unwrap().format(date).to_string()
+    }
+
+    #[test]
+    fn test_fields() {
+        let date = SolarDate::new(2023, 4, 5).unwrap();
+
+        assert_eq!(format("%Y-%m-%d", date), "2023-04-05");
+        assert_eq!(format("%lY-%lm-%ld %L", date), "2023-02-15 윤");
+        assert_eq!(format("%-m/%-d %-lm/%-ld", date), "4/5 2/15");
+        assert_eq!(format("%y %M %D", date), "계묘 을묘 계사");
+        assert_eq!(format("%G", date), "계묘년 을묘월 계사일");
+        assert_eq!(format("%HG", date), "癸卯年 乙卯月 癸巳日");
+
+        let mut converter = LunarSolarConverter::new();
+        converter.set_solar_date(2023, 4, 5);
+        assert_eq!(converter.get_gapja_string(), format("%G (%L월)", date));
+        assert_eq!(
+            converter.get_chinese_gapja_string(),
+            format("%HG (%HL月)", date)
+        );
+        assert_eq!(format("%a %A %Ha %HA", date), "수 수요일 水 水曜日");
+        assert_eq!(format("%z띠 %Hz", date), "토끼띠 兎");
+        assert_eq!(format("%t|%T|%Ht", date), "청명|청명|淸明");
+        assert_eq!(format("100%%", date), "100%");
+        assert_eq!(format("%L%lB %HL%HlB", date), "윤이월 閏二月");
+
+        let next_day = SolarDate::new(2023, 4, 6).unwrap();
+        assert_eq!(format("[%t] %T", next_day), "[] 청명");
+        assert_eq!(format("[%L]", next_day), "[윤]");
+        assert_eq!(format("[%L]", SolarDate::new(2023, 3, 1).unwrap()), "[]");
+    }
+
+    #[test]
+    fn test_lunar_pattern() {
+        let date = SolarDate::new(2023, 4, 5).unwrap();
+        let pattern = |pattern| {
+            DatePattern::lunar(pattern)
+                .unwrap()
+                .format(date)
+                .to_string()
+        };
+
+        assert_eq!(pattern("%Y-%L%m-%d %B"), "2023-윤02-15 이월");
+        assert_eq!(pattern("%lY-%lm-%ld"), "2023-02-15");
+        assert_eq!(pattern("%sY-%sm-%sd (%-sm/%-sd)"), "2023-04-05 (4/5)");
+        assert_eq!(
+            format("%sY-%sm-%sd %Y-%m-%d", date),
+            "2023-04-05 2023-04-05"
+        );
+        assert_eq!(pattern("%HY年 %Hm月"), "二千二十三年 二月");
+    }
+
+    #[test]
+    fn test_locales() {
+        let date = SolarDate::new(2023, 4, 5).unwrap();
+        let pattern = DatePattern::new("%a %A|%G|%z|%t|%L %lB|%Hy").unwrap();
+
+        assert_eq!(
+            pattern
+                .clone()
+                .with_locale(Locale::English)
+                .format(date)
+                .to_string(),
+            "Wed Wednesday|Gyemyo-nyeon Eulmyo-wol Gyesa-il|Rabbit|Pure Brightness|Leap Second Month|癸卯"
+        );
+        assert_eq!(
+            pattern
+                .clone()
+                .with_locale(Locale::Japanese)
+                .format(date)
+                .to_string(),
+            "水 水曜日|癸卯年 乙卯月 癸巳日|うさぎ|清明|閏 如月|癸卯"
+        );
+        assert_eq!(
+            pattern
+                .with_locale(Locale::Vietnamese)
+                .format(date)
+                .to_string(),
+            "T4 Thứ Tư|năm Quý Mão tháng Ất Mão ngày Quý Tỵ|Mèo|Thanh minh|nhuận Tháng Hai|癸卯"
+        );
+    }
+
+    #[test]
+    fn test_numerals() {
+        let date = SolarDate::new(2023, 11, 10).unwrap();
+
+        assert_eq!(
+            format("%KY년 %Km월 %Kd일", date),
+            "이천이십삼년 십일월 십일"
+        );
+        assert_eq!(
+            format("%HY年 %Hm月 %Hd日", date),
+            "二千二十三年 十一月 十日"
+        );
+
+        let date = SolarDate::new(1391, 2, 5).unwrap();
+        assert_eq!(format("%KY %Hm %Kd", date), "천삼백구십일 二 오");
+        assert_eq!(format("%Y-%m-%d", date), "1391-02-05");
+    }
+
+    #[test]
+    fn test_same_output_for_both_calendars() {
+        let pattern = DatePattern::new("%Y-%m-%d %lY-%L%lm-%ld %G").unwrap();
+        let lunar = LunarDate::new(2024, 1, 1, false).unwrap();
+
+        assert_eq!(
+            pattern.format(lunar).to_string(),
+            pattern.format(lunar.to_solar()).to_string()
+        );
+
+        let start = SolarDate::new(2024, 2, 9).unwrap();
+        let end = SolarDate::new(2024, 2, 10).unwrap();
+        let dates: Vec<String> = DateRange::solar(start, end)
+            .map(|date| pattern.format(date).to_string())
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                "2024-02-09 2023-12-30 계묘년 을축월 계묘일",
+                "2024-02-10 2024-01-01 갑진년 병인월 갑진일"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        for (pattern, directive) in [
+            ("%Y-%q", "%q"),
+            ("%Y %", "%"),
+            ("%lz", "%lz"),
+            ("%-A", "%-A"),
+            ("%KG", "%KG"),
+            ("%H", "%H"),
+            ("%l%", "%l%"),
+            ("%sB", "%sB"),
+            ("%sz", "%sz"),
+            ("%B", "%B"),
+        ] {
+            let err = DatePattern::new(pattern).unwrap_err();
+
+            assert_eq!(err.kind(), ParseErrorKind::UnexpectedToken);
+            assert_eq!(&pattern[err.span()], directive, "{}", pattern);
+        }
+    }
+}
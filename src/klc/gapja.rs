@@ -0,0 +1,186 @@
+use std::fmt;
+
+use super::locale::PillarUnit;
+use super::{
+    KOREAN_CHEONGAN, KOREAN_GANJI, KOREAN_LUNAR_BASE_YEAR, Locale, Sexagenary, SolarTerm,
+    SolarTermTime,
+};
+
+/// Selects how the year and month pillars of the Gapja (간지) are determined.
+///
+/// The day pillar is the same under both conventions.
+///
+/// # Example
+/// ```
+/// use rs_klc::{GapjaConvention, LunarSolarConverter};
+///
+/// let mut converter = LunarSolarConverter::new();
+/// converter.set_solar_date(2024, 2, 4); // 입춘, lunar 2023-12-25
+/// assert_eq!(converter.get_gapja_string(), "계묘년 을축월 무술일");
+///
+/// converter.set_gapja_convention(GapjaConvention::SolarTerm);
+/// assert_eq!(converter.get_gapja_string(), "갑진년 병인월 무술일");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GapjaConvention {
+    /// The calendar-printing convention: the year pillar follows the lunar year and
+    /// the month pillar follows the lunar month. An intercalary month shares the
+    /// pillar of its regular month.
+    #[default]
+    LunarCalendar,
+    /// The saju (사주명리) convention: the year pillar changes on the day of 입춘 and
+    /// the month pillar changes on the day of each sectional term (절: 입춘, 경칩, 청명 …),
+    /// regardless of lunar months.
+    SolarTerm,
+}
+
+/// The Gapja (간지) of a date: the year, month and day pillars.
+///
+/// `Display` writes the Korean string of
+/// [`LunarSolarConverter::get_gapja_string`](crate::LunarSolarConverter::get_gapja_string)
+/// without the intercalary month note (e.g. "임인년 정미월 갑자일").
+///
+/// # Example
+/// ```
+/// use rs_klc::{EarthlyBranch, Locale, LunarSolarConverter};
+///
+/// let mut converter = LunarSolarConverter::new();
+/// converter.set_solar_date(2022, 7, 10);
+/// let gapja = converter.gapja().unwrap();
+///
+/// assert_eq!(gapja.year.branch(), EarthlyBranch::In);
+/// assert_eq!(gapja.day.cycle_index(), 1);
+/// assert_eq!(gapja.to_string(), "임인년 정미월 갑자일");
+/// assert_eq!(gapja.to_string_in(Locale::Hanja), "壬寅年 丁未月 甲子日");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gapja {
+    /// The year pillar (e.g. 임인).
+    pub year: Sexagenary,
+    /// The month pillar.
+    pub month: Sexagenary,
+    /// The day pillar.
+    pub day: Sexagenary,
+}
+
+impl Gapja {
+    /// Returns the string of the Gapja in a locale, such as
+    /// "Imin-nyeon Jeongmi-wol Gapja-il" in [`Locale::English`].
+    pub fn to_string_in(&self, locale: Locale) -> String {
+        let mut string = String::new();
+        self.write_in(&mut string, locale)
+            .expect("writing to a String cannot fail");
+
+        string
+    }
+
+    pub(super) fn write_in(&self, f: &mut impl fmt::Write, locale: Locale) -> fmt::Result {
+        let pillars = [
+            (self.year, PillarUnit::Year),
+            (self.month, PillarUnit::Month),
+            (self.day, PillarUnit::Day),
+        ];
+
+        for (i, (pillar, unit)) in pillars.into_iter().enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+            pillar.write_with_unit(f, locale, unit)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Gapja {
+    /// Formats the Gapja in Korean (e.g. "임인년 정미월 갑자일").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_in(f, Locale::Korean)
+    }
+}
+
+/// Returns the stem and branch indices of the year and month pillars of a lunar month
+/// under [`GapjaConvention::LunarCalendar`], as
+/// `[year_stem, year_branch, month_stem, month_branch]`.
+pub(super) fn lunar_calendar_pillars(year: i32, month: u32) -> [usize; 4] {
+    let year_count = (year + 7 - KOREAN_LUNAR_BASE_YEAR) as usize;
+    let month_count = month as usize + 12 * (year - KOREAN_LUNAR_BASE_YEAR) as usize;
+
+    [
+        year_count % KOREAN_CHEONGAN.len(),
+        year_count % KOREAN_GANJI.len(),
+        (month_count + 5) % KOREAN_CHEONGAN.len(),
+        (month_count + 1) % KOREAN_GANJI.len(),
+    ]
+}
+
+/// Returns the stem and branch indices of the year and month pillars that start at a
+/// solar term, as `[year_stem, year_branch, month_stem, month_branch]`.
+pub(super) fn solar_term_pillars(time: SolarTermTime) -> [usize; 4] {
+    let term = time.term();
+
+    // 소한 and 대한 still belong to the year that started at the previous 입춘, which is
+    // in the previous solar year only when they fall in January: before 1582 the Julian
+    // 소한 falls in late December, after that year's 입춘.
+    let year = match term {
+        SolarTerm::Sohan | SolarTerm::Daehan if time.month() == 1 => time.year() as i32 - 1,
+        _ => time.year() as i32,
+    };
+    let year_count = (year + 7 - KOREAN_LUNAR_BASE_YEAR) as usize;
+    let year_stem = year_count % KOREAN_CHEONGAN.len();
+
+    // The month starting at 입춘 is 인월, whose stem follows the year stem:
+    // 갑기년 병인월, 을경년 무인월, 병신년 경인월, 정임년 임인월, 무계년 갑인월.
+    let month = term.index() / 2;
+    let month_stem = ((year_stem % 5) * 2 + 2 + month) % KOREAN_CHEONGAN.len();
+    let month_branch = (month + 2) % KOREAN_GANJI.len();
+
+    [
+        year_stem,
+        year_count % KOREAN_GANJI.len(),
+        month_stem,
+        month_branch,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::solar_term_pillars;
+    use crate::{GapjaConvention, LunarSolarConverter, SolarDate, SolarTerm};
+
+    #[test]
+    fn test_solar_term_pillars_around_ipchun() {
+        // 2024-02-03: 계묘년 을축월, 2024-02-04 (입춘): 갑진년 병인월
+        let before = SolarDate::new(2024, 2, 3).unwrap();
+        let after = SolarDate::new(2024, 2, 4).unwrap();
+
+        assert_eq!(solar_term_pillars(before.solar_term()), [9, 3, 1, 1]);
+        assert_eq!(solar_term_pillars(after.solar_term()), [0, 4, 2, 2]);
+    }
+
+    #[test]
+    fn test_solar_term_pillars_early_january() {
+        // 2024-01-03 is before 소한, so it is still 계묘년 갑자월
+        let day = SolarDate::new(2024, 1, 3).unwrap();
+
+        assert_eq!(solar_term_pillars(day.solar_term()), [9, 3, 0, 0]);
+    }
+
+    #[test]
+    fn test_solar_term_pillars_julian_december() {
+        // The Julian 소한 of 1400 falls in December: 1400-12-31 is 경진년 기축월, and
+        // 대한 in January 1401 still belongs to 경진년
+        let sohan = SolarDate::new(1400, 12, 31).unwrap().solar_term();
+        let daehan = SolarDate::new(1401, 1, 20).unwrap().solar_term();
+
+        assert_eq!((sohan.term(), sohan.month()), (SolarTerm::Sohan, 12));
+        assert_eq!(solar_term_pillars(sohan), [6, 4, 5, 1]);
+        assert_eq!(daehan.term(), SolarTerm::Daehan);
+        assert_eq!(solar_term_pillars(daehan), [6, 4, 5, 1]);
+
+        let mut converter = LunarSolarConverter::new();
+        converter.set_gapja_convention(GapjaConvention::SolarTerm);
+        converter.set_solar_date(1400, 12, 31);
+        assert_eq!(converter.get_gapja_string(), "경진년 기축월 병오일");
+    }
+}
@@ -0,0 +1,555 @@
+//! Korean public holidays (공휴일).
+//!
+//! Holidays follow the Regulations on Holidays of Government Offices (관공서의 공휴일에
+//! 관한 규정) since 1949, including the lunar holidays and the substitute holiday
+//! (대체공휴일) rules introduced in 2014 and extended in 2021 and 2023. Election days and
+//! one-off temporary holidays are not included.
+
+use std::collections::BTreeSet;
+
+use super::{KlcError, Locale, LunarDate, SolarDate};
+
+const HOLIDAY_MIN_YEAR: u32 = 1949;
+const HOLIDAY_MAX_YEAR: u32 = 2050;
+
+/// Holiday names as (Korean, Hanja, Japanese, Vietnamese). Native Korean names such as
+/// 어린이날 have no Hanja form and stay in Hangul.
+const HOLIDAY_NAMES: [(&str, &str, &str, &str); 18] = [
+    ("신정", "新正", "新正", "Tết Dương lịch"),
+    ("삼일절", "三一節", "三一節", "Ngày Phong trào Độc lập"),
+    ("사방의 날", "砂防의 날", "砂防の日", "Ngày Chống xói mòn"),
+    ("식목일", "植木日", "植木日", "Ngày Trồng cây"),
+    ("어린이날", "어린이날", "こどもの日", "Ngày Thiếu nhi"),
+    ("현충일", "顯忠日", "顕忠日", "Ngày Tưởng niệm"),
+    ("제헌절", "制憲節", "制憲節", "Ngày Lập hiến"),
+    ("광복절", "光復節", "光復節", "Ngày Giải phóng"),
+    ("국군의 날", "國軍의 날", "国軍の日", "Ngày Quân đội"),
+    ("개천절", "開天節", "開天節", "Ngày Lập quốc"),
+    ("한글날", "한글날", "ハングルの日", "Ngày Hangul"),
+    ("유엔의 날", "國際聯合日", "国連の日", "Ngày Liên Hợp Quốc"),
+    ("기독탄신일", "基督誕辰日", "クリスマス", "Lễ Giáng sinh"),
+    ("민속의 날", "民俗의 날", "民俗の日", "Ngày Dân gian"),
+    ("설날", "설날", "旧正月", "Tết Nguyên đán"),
+    ("부처님오신날", "釋迦誕辰日", "釈迦誕生日", "Lễ Phật đản"),
+    ("추석", "秋夕", "秋夕", "Tết Trung thu"),
+    ("대체공휴일", "代替公休日", "振替休日", "Ngày nghỉ bù"),
+];
+
+/// Whether a holiday is fixed in the solar or in the lunar calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayKind {
+    /// Fixed on a solar date (e.g. 삼일절 on 03-01).
+    Solar,
+    /// Fixed on a lunar date (설날, 추석 and 부처님오신날).
+    Lunar,
+}
+
+/// A public holiday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Holiday {
+    /// The solar date of the holiday.
+    pub date: SolarDate,
+    /// The Korean name (e.g. "추석").
+    pub name_ko: &'static str,
+    /// The English name (e.g. "Chuseok").
+    pub name_en: &'static str,
+    /// Whether the holiday is fixed in the solar or the lunar calendar. A substitute
+    /// holiday has the kind of the holiday it replaces.
+    pub kind: HolidayKind,
+    /// `true` for a substitute holiday (대체공휴일).
+    pub is_substitute: bool,
+}
+
+impl Holiday {
+    /// Returns the name of the holiday in a locale (e.g. "추석", "秋夕", "Chuseok").
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::Locale;
+    /// use rs_klc::holidays::holidays;
+    ///
+    /// let chuseok = holidays(2024)
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .find(|holiday| holiday.name_ko == "추석")
+    ///     .unwrap();
+    /// assert_eq!(chuseok.name_in(Locale::Hanja), "秋夕");
+    /// assert_eq!(chuseok.name_in(Locale::Vietnamese), "Tết Trung thu");
+    /// ```
+    pub fn name_in(&self, locale: Locale) -> &'static str {
+        let (_, hanja, japanese, vietnamese) = HOLIDAY_NAMES
+            .iter()
+            .find(|names| names.0 == self.name_ko)
+            .expect("every holiday has localized names");
+
+        match locale {
+            Locale::Korean => self.name_ko,
+            Locale::Hanja => hanja,
+            Locale::English => self.name_en,
+            Locale::Japanese => japanese,
+            Locale::Vietnamese => vietnamese,
+        }
+    }
+}
+
+/// Days of the week that give a holiday a substitute holiday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Substitution {
+    Never,
+    /// 설날 and 추석 are substituted when they fall on a Sunday.
+    OnSunday,
+    /// Other holidays are substituted when they fall on a Saturday or a Sunday.
+    OnWeekend,
+}
+
+struct Entry {
+    jdn: i64,
+    /// The last day of the holiday period (설날 and 추석 last three days).
+    period_end: i64,
+    holiday: Holiday,
+    substitution: Substitution,
+}
+
+/// Returns the public holidays of a solar year in chronological order, including
+/// substitute holidays.
+///
+/// Holidays that fall on the same day are listed separately.
+///
+/// # Errors
+/// Returns [`KlcError::OutOfRange`] if the year is outside 1949-2050.
+///
+/// # Example
+/// ```
+/// use rs_klc::holidays::{self, HolidayKind};
+///
+/// let holidays = holidays::holidays(2024).unwrap();
+/// let seollal: Vec<String> = holidays
+///     .iter()
+///     .filter(|holiday| holiday.date.month() == 2)
+///     .map(|holiday| format!("{} {}", holiday.date, holiday.name_ko))
+///     .collect();
+///
+/// assert_eq!(
+///     seollal,
+///     ["2024-02-09 설날", "2024-02-10 설날", "2024-02-11 설날", "2024-02-12 대체공휴일"]
+/// );
+/// assert_eq!(holidays[1].kind, HolidayKind::Lunar);
+/// ```
+pub fn holidays(year: u32) -> Result<Vec<Holiday>, KlcError> {
+    if !(HOLIDAY_MIN_YEAR..=HOLIDAY_MAX_YEAR).contains(&year) {
+        return Err(KlcError::OutOfRange {
+            min: HOLIDAY_MIN_YEAR * 10000 + 101,
+            max: HOLIDAY_MAX_YEAR * 10000 + 1231,
+        });
+    }
+
+    let mut entries = Vec::new();
+    let since = |first_year: u32, substitution: Substitution| {
+        if year >= first_year {
+            substitution
+        } else {
+            Substitution::Never
+        }
+    };
+
+    let mut add_solar = |month, day, name_ko, name_en, substitution| -> Result<(), KlcError> {
+        let date = SolarDate::new(year, month, day)?;
+        let jdn = date.julian_day_number() as i64;
+        entries.push(Entry {
+            jdn,
+            period_end: jdn,
+            holiday: Holiday {
+                date,
+                name_ko,
+                name_en,
+                kind: HolidayKind::Solar,
+                is_substitute: false,
+            },
+            substitution,
+        });
+
+        Ok(())
+    };
+
+    add_solar(1, 1, "신정", "New Year's Day", Substitution::Never)?;
+    if year <= 1998 {
+        add_solar(1, 2, "신정", "New Year's Day", Substitution::Never)?;
+    }
+    if year <= 1989 {
+        add_solar(1, 3, "신정", "New Year's Day", Substitution::Never)?;
+    }
+    add_solar(
+        3,
+        1,
+        "삼일절",
+        "Independence Movement Day",
+        since(2021, Substitution::OnWeekend),
+    )?;
+    // In 1960 식목일 gave way to 사방의 날, its only year as a holiday
+    if year == 1960 {
+        add_solar(
+            3,
+            15,
+            "사방의 날",
+            "Erosion Control Day",
+            Substitution::Never,
+        )?;
+    } else if year <= 2005 {
+        add_solar(4, 5, "식목일", "Arbor Day", Substitution::Never)?;
+    }
+    if year >= 1975 {
+        add_solar(
+            5,
+            5,
+            "어린이날",
+            "Children's Day",
+            since(2014, Substitution::OnWeekend),
+        )?;
+    }
+    if year >= 1956 {
+        add_solar(6, 6, "현충일", "Memorial Day", Substitution::Never)?;
+    }
+    if year <= 2007 {
+        add_solar(7, 17, "제헌절", "Constitution Day", Substitution::Never)?;
+    }
+    add_solar(
+        8,
+        15,
+        "광복절",
+        "Liberation Day",
+        since(2021, Substitution::OnWeekend),
+    )?;
+    if (1976..=1990).contains(&year) {
+        add_solar(10, 1, "국군의 날", "Armed Forces Day", Substitution::Never)?;
+    }
+    add_solar(
+        10,
+        3,
+        "개천절",
+        "National Foundation Day",
+        since(2021, Substitution::OnWeekend),
+    )?;
+    if year <= 1990 || year >= 2013 {
+        add_solar(
+            10,
+            9,
+            "한글날",
+            "Hangul Day",
+            since(2021, Substitution::OnWeekend),
+        )?;
+    }
+    if (1950..=1975).contains(&year) {
+        add_solar(
+            10,
+            24,
+            "유엔의 날",
+            "United Nations Day",
+            Substitution::Never,
+        )?;
+    }
+    add_solar(
+        12,
+        25,
+        "기독탄신일",
+        "Christmas Day",
+        since(2023, Substitution::OnWeekend),
+    )?;
+
+    // Lunar holidays always fall on the regular (non-leap) month
+    let mut add_lunar = |month,
+                         day,
+                         days_before: i64,
+                         days_after: i64,
+                         name_ko,
+                         name_en,
+                         substitution|
+     -> Result<(), KlcError> {
+        let lunar = LunarDate::new(year as i32, month, day, false)?;
+        let jdn = lunar.to_solar().julian_day_number() as i64;
+
+        for offset in -days_before..=days_after {
+            entries.push(Entry {
+                jdn: jdn + offset,
+                period_end: jdn + days_after,
+                holiday: Holiday {
+                    date: SolarDate::from_julian_day_number(jdn + offset)?,
+                    name_ko,
+                    name_en,
+                    kind: HolidayKind::Lunar,
+                    is_substitute: false,
+                },
+                substitution,
+            });
+        }
+
+        Ok(())
+    };
+
+    // 설날 includes the last day of the previous lunar year, whether 12월 has 29 or 30 days
+    match year {
+        1985..=1988 => add_lunar(1, 1, 0, 0, "민속의 날", "Folk Day", Substitution::Never)?,
+        1989.. => add_lunar(
+            1,
+            1,
+            1,
+            1,
+            "설날",
+            "Seollal",
+            since(2014, Substitution::OnSunday),
+        )?,
+        _ => {}
+    }
+    if year >= 1975 {
+        add_lunar(
+            4,
+            8,
+            0,
+            0,
+            "부처님오신날",
+            "Buddha's Birthday",
+            since(2023, Substitution::OnWeekend),
+        )?;
+    }
+    // 추석 gained the day after in 1986 and the day before in 1989
+    match year {
+        1989.. => add_lunar(
+            8,
+            15,
+            1,
+            1,
+            "추석",
+            "Chuseok",
+            since(2014, Substitution::OnSunday),
+        )?,
+        1986..=1988 => add_lunar(8, 15, 0, 1, "추석", "Chuseok", Substitution::Never)?,
+        _ => add_lunar(8, 15, 0, 0, "추석", "Chuseok", Substitution::Never)?,
+    }
+
+    entries.sort_by_key(|entry| entry.jdn);
+
+    let substitutes = substitute_holidays(&entries)?;
+    let mut holidays: Vec<Holiday> = entries.into_iter().map(|entry| entry.holiday).collect();
+    holidays.extend(substitutes);
+    holidays.sort_by_key(|holiday| holiday.date);
+
+    Ok(holidays)
+}
+
+/// Assigns a substitute holiday to every day on which a holiday falls on a weekend
+/// covered by its rule or on another holiday. The substitute is the first weekday
+/// after the holiday (period) that is not a holiday.
+fn substitute_holidays(entries: &[Entry]) -> Result<Vec<Holiday>, KlcError> {
+    let mut occupied: BTreeSet<i64> = entries.iter().map(|entry| entry.jdn).collect();
+    let mut handled = BTreeSet::new();
+    let mut substitutes = Vec::new();
+
+    for entry in entries {
+        // JDN mod 7: 0=Mon, ..., 5=Sat, 6=Sun
+        let on_weekend = match entry.substitution {
+            Substitution::Never => continue,
+            Substitution::OnSunday => entry.jdn % 7 == 6,
+            Substitution::OnWeekend => entry.jdn % 7 >= 5,
+        };
+        let overlaps = entries
+            .iter()
+            .filter(|other| other.jdn == entry.jdn)
+            .count()
+            > 1;
+
+        if !(on_weekend || overlaps) || !handled.insert(entry.jdn) {
+            continue;
+        }
+
+        let mut jdn = entry.period_end + 1;
+        while jdn % 7 >= 5 || occupied.contains(&jdn) {
+            jdn += 1;
+        }
+        occupied.insert(jdn);
+
+        substitutes.push(Holiday {
+            date: SolarDate::from_julian_day_number(jdn)?,
+            name_ko: "대체공휴일",
+            name_en: "Substitute Holiday",
+            kind: entry.holiday.kind,
+            is_substitute: true,
+        });
+    }
+
+    Ok(substitutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::holidays;
+    use crate::{KlcError, Locale};
+
+    fn substitutes(year: u32) -> Vec<String> {
+        holidays(year)
+            .unwrap()
+            .iter()
+            .filter(|holiday| holiday.is_substitute)
+            .map(|holiday| holiday.date.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_holidays_2024() {
+        let holidays = holidays(2024).unwrap();
+        let dates: Vec<String> = holidays
+            .iter()
+            .map(|holiday| format!("{} {}", holiday.date, holiday.name_en))
+            .collect();
+
+        assert_eq!(
+            dates,
+            [
+                "2024-01-01 New Year's Day",
+                "2024-02-09 Seollal",
+                "2024-02-10 Seollal",
+                "2024-02-11 Seollal",
+                "2024-02-12 Substitute Holiday",
+                "2024-03-01 Independence Movement Day",
+                "2024-05-05 Children's Day",
+                "2024-05-06 Substitute Holiday",
+                "2024-05-15 Buddha's Birthday",
+                "2024-06-06 Memorial Day",
+                "2024-08-15 Liberation Day",
+                "2024-09-16 Chuseok",
+                "2024-09-17 Chuseok",
+                "2024-09-18 Chuseok",
+                "2024-10-03 National Foundation Day",
+                "2024-10-09 Hangul Day",
+                "2024-12-25 Christmas Day",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_seollal_eve_after_short_12th_month() {
+        // Lunar 2024-12 has 29 days, so 설날 2025 starts on 12/29 (2025-01-28)
+        let holidays = holidays(2025).unwrap();
+        let seollal: Vec<String> = holidays
+            .iter()
+            .filter(|holiday| holiday.name_ko == "설날")
+            .map(|holiday| holiday.date.to_lunar().to_string())
+            .collect();
+
+        assert_eq!(seollal, ["2024-12-29", "2025-01-01", "2025-01-02"]);
+    }
+
+    #[test]
+    fn test_chuseok_in_year_with_leap_8th_month() {
+        // 1995 has 윤8월; 추석 falls in the regular 8월
+        let chuseok: Vec<String> = holidays(1995)
+            .unwrap()
+            .iter()
+            .filter(|holiday| holiday.name_ko == "추석")
+            .map(|holiday| holiday.date.to_string())
+            .collect();
+
+        assert_eq!(chuseok, ["1995-09-08", "1995-09-09", "1995-09-10"]);
+    }
+
+    #[test]
+    fn test_substitute_holidays() {
+        // 2014: 추석 on a Sunday, first year of substitute holidays
+        assert_eq!(substitutes(2014), ["2014-09-10"]);
+        // 2017: 설날 on a Sunday and 추석 overlapping 개천절
+        assert_eq!(substitutes(2017), ["2017-01-30", "2017-10-06"]);
+        // 2020: 광복절 on a Saturday is not substituted before 2021
+        assert_eq!(substitutes(2020), ["2020-01-27"]);
+        // 2021: 광복절, 개천절 and 한글날 on weekends
+        assert_eq!(
+            substitutes(2021),
+            ["2021-08-16", "2021-10-04", "2021-10-11"]
+        );
+        // 2023: 설날 on a Sunday and 부처님오신날 on a Saturday
+        assert_eq!(substitutes(2023), ["2023-01-24", "2023-05-29"]);
+        // 2025: 어린이날 and 부처님오신날 on the same Monday get a single substitute
+        assert_eq!(
+            substitutes(2025),
+            ["2025-03-03", "2025-05-06", "2025-10-08"]
+        );
+    }
+
+    #[test]
+    fn test_historical_holidays() {
+        let names: Vec<&str> = holidays(1988)
+            .unwrap()
+            .iter()
+            .map(|holiday| holiday.name_ko)
+            .collect();
+
+        assert!(names.contains(&"민속의 날"));
+        assert!(names.contains(&"제헌절"));
+        assert!(names.contains(&"식목일"));
+        assert_eq!(names.iter().filter(|name| **name == "신정").count(), 3);
+        assert!(!names.contains(&"대체공휴일"));
+    }
+
+    fn names(year: u32) -> Vec<&'static str> {
+        holidays(year)
+            .unwrap()
+            .iter()
+            .map(|holiday| holiday.name_ko)
+            .collect()
+    }
+
+    fn chuseok(year: u32) -> Vec<String> {
+        holidays(year)
+            .unwrap()
+            .iter()
+            .filter(|holiday| holiday.name_ko == "추석")
+            .map(|holiday| holiday.date.to_lunar().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_holiday_history() {
+        // 1960: 사방의 날 instead of 식목일
+        assert!(names(1960).contains(&"사방의 날"));
+        assert!(!names(1960).contains(&"식목일"));
+        assert!(names(1961).contains(&"식목일"));
+
+        // 1975: the last 유엔의 날, before 국군의 날
+        assert!(names(1975).contains(&"유엔의 날"));
+        assert!(!names(1975).contains(&"국군의 날"));
+        assert_eq!(chuseok(1975), ["1975-08-15"]);
+
+        // 1987: 국군의 날, and 추석 with the day after
+        assert!(names(1987).contains(&"국군의 날"));
+        assert!(!names(1987).contains(&"유엔의 날"));
+        assert_eq!(chuseok(1987), ["1987-08-15", "1987-08-16"]);
+
+        // 1990: the last 국군의 날, and 추석 with the days before and after
+        assert!(names(1990).contains(&"국군의 날"));
+        assert!(!names(1991).contains(&"국군의 날"));
+        assert_eq!(chuseok(1990), ["1990-08-14", "1990-08-15", "1990-08-16"]);
+    }
+
+    #[test]
+    fn test_holidays_out_of_range() {
+        assert!(matches!(holidays(1948), Err(KlcError::OutOfRange { .. })));
+        assert!(holidays(2050).is_ok());
+        assert!(holidays(2051).is_err());
+    }
+
+    #[test]
+    fn test_names_in_every_locale() {
+        for year in 1949..=2050 {
+            for holiday in holidays(year).unwrap() {
+                assert_eq!(holiday.name_in(Locale::Korean), holiday.name_ko);
+                assert_eq!(holiday.name_in(Locale::English), holiday.name_en);
+                for locale in Locale::ALL {
+                    assert!(!holiday.name_in(locale).is_empty());
+                }
+            }
+        }
+
+        let names: Vec<&str> = holidays(2024).unwrap()[..4]
+            .iter()
+            .map(|holiday| holiday.name_in(Locale::Japanese))
+            .collect();
+        assert_eq!(names, ["新正", "旧正月", "旧正月", "旧正月"]);
+    }
+}
@@ -0,0 +1,167 @@
+//! Conversions with [`jiff`] dates and zoned date times, enabled by the `jiff` feature.
+//!
+//! [`jiff::civil::Date`] converts to and from [`SolarDate`] by counting days from
+//! 1970-01-01 (see [`SolarDate::julian_day_number`] for dates before 1582-10-15).
+//!
+//! A [`jiff::Zoned`] is an instant, so [`ZonedExt`] first finds the civil date and time
+//! in Korea at that instant, with the offsets Korea used at the time, and then the
+//! lunar date of that Korean day.
+//!
+//! # Example
+//! ```
+//! use jiff::civil::date;
+//! use jiff::tz::{self, TimeZone};
+//! use rs_klc::{SolarDate, ZonedExt};
+//!
+//! // 2024-02-09 20:00 in New York is 2024-02-10 10:00 in Seoul, lunar new year
+//! let zoned = date(2024, 2, 9)
+//!     .at(20, 0, 0, 0)
+//!     .to_zoned(TimeZone::fixed(tz::offset(-5)))
+//!     .unwrap();
+//! assert_eq!(zoned.to_korean_lunar().unwrap().to_string(), "2024-01-01");
+//!
+//! let solar = SolarDate::try_from(zoned.date()).unwrap();
+//! assert_eq!(solar.to_lunar().to_string(), "2023-12-30");
+//! ```
+
+use jiff::civil::{self, Date};
+use jiff::{Span, Zoned};
+
+use super::{KlcError, KoreanDateTime, LunarDate, SolarDate};
+
+/// Julian Day Number of 1970-01-01.
+const UNIX_EPOCH_JDN: i64 = 2440588;
+
+/// Korean calendar methods for [`jiff::Zoned`].
+pub trait ZonedExt {
+    /// Returns the civil date and time in Korea at the instant of this zoned date time.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the Korean date is outside the supported range.
+    fn to_korean_date_time(&self) -> Result<KoreanDateTime, KlcError>;
+
+    /// Returns the Korean lunar date of the day in Korea at the instant of this zoned
+    /// date time.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the Korean date is outside the supported range.
+    fn to_korean_lunar(&self) -> Result<LunarDate, KlcError>;
+}
+
+impl ZonedExt for Zoned {
+    fn to_korean_date_time(&self) -> Result<KoreanDateTime, KlcError> {
+        KoreanDateTime::from_unix_timestamp(self.timestamp().as_second())
+    }
+
+    fn to_korean_lunar(&self) -> Result<LunarDate, KlcError> {
+        Ok(self.to_korean_date_time()?.to_lunar())
+    }
+}
+
+impl TryFrom<Date> for SolarDate {
+    type Error = KlcError;
+
+    fn try_from(date: Date) -> Result<Self, KlcError> {
+        let days = civil::date(1970, 1, 1)
+            .until(date)
+            .expect("the days between two jiff dates fit in a span")
+            .get_days();
+
+        SolarDate::from_julian_day_number(UNIX_EPOCH_JDN + days as i64)
+    }
+}
+
+impl From<SolarDate> for Date {
+    fn from(date: SolarDate) -> Self {
+        let days = date.julian_day_number() as i64 - UNIX_EPOCH_JDN;
+
+        civil::date(1970, 1, 1)
+            .checked_add(Span::new().days(days))
+            .expect("every supported date is within the range of jiff::civil::Date")
+    }
+}
+
+impl TryFrom<Date> for LunarDate {
+    type Error = KlcError;
+
+    fn try_from(date: Date) -> Result<Self, KlcError> {
+        Ok(SolarDate::try_from(date)?.to_lunar())
+    }
+}
+
+impl From<LunarDate> for Date {
+    fn from(date: LunarDate) -> Self {
+        Date::from(date.to_solar())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::Timestamp;
+    use jiff::civil::{Date, date};
+    use jiff::tz::{self, TimeZone};
+
+    use super::ZonedExt;
+    use crate::{KlcError, SolarDate};
+
+    #[test]
+    fn test_julian_dates() {
+        assert_eq!(
+            SolarDate::try_from(date(1582, 10, 14)),
+            Ok(SolarDate::new(1582, 10, 4).unwrap())
+        );
+        assert_eq!(
+            Date::from(SolarDate::new(1391, 2, 5).unwrap()),
+            date(1391, 2, 13)
+        );
+    }
+
+    #[test]
+    fn test_days_until() {
+        // The span from 1970-01-01 must stay in days, never balanced into months or years
+        assert_eq!(
+            SolarDate::try_from(date(1970, 1, 1))
+                .unwrap()
+                .julian_day_number(),
+            2440588
+        );
+        assert_eq!(
+            SolarDate::try_from(date(2200, 12, 31)),
+            Ok(SolarDate::new(2200, 12, 31).unwrap())
+        );
+        assert_eq!(
+            SolarDate::try_from(date(1391, 2, 13)),
+            Ok(SolarDate::new(1391, 2, 5).unwrap())
+        );
+
+        // The span to either end of jiff's range fits, and is rejected as out of range
+        assert!(SolarDate::try_from(Date::MIN).is_err());
+        assert!(SolarDate::try_from(Date::MAX).is_err());
+    }
+
+    #[test]
+    fn test_zoned_uses_historical_korean_offset() {
+        // 1988-07-01 00:00 UTC is 10:00 in Korea, during summer time
+        let zoned = Timestamp::from_second(583718400)
+            .unwrap()
+            .to_zoned(TimeZone::fixed(tz::offset(-10)));
+        let korean = zoned.to_korean_date_time().unwrap();
+
+        assert_eq!(zoned.date(), date(1988, 6, 30));
+        assert_eq!(korean.to_string(), "1988-07-01 10:00:00+10:00");
+        assert_eq!(zoned.to_korean_lunar().unwrap().to_string(), "1988-05-18");
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert!(matches!(
+            SolarDate::try_from(date(1391, 2, 12)),
+            Err(KlcError::OutOfRange { .. })
+        ));
+
+        let zoned = Timestamp::from_second(7289654400)
+            .unwrap()
+            .to_zoned(TimeZone::UTC);
+        assert!(zoned.to_korean_lunar().is_err());
+    }
+}
@@ -0,0 +1,527 @@
+use std::fmt;
+
+use super::{CHINESE_GAPJA_UNIT, DayOfWeek, KOREAN_GAPJA_UNIT};
+
+/// The language of the names produced by the string APIs of this crate.
+///
+/// # Example
+/// ```
+/// use rs_klc::{DayOfWeek, Locale, LunarSolarConverter};
+///
+/// let mut converter = LunarSolarConverter::new();
+/// converter.set_solar_date(2022, 7, 10);
+/// assert_eq!(converter.get_gapja_string_in(Locale::English), "Imin-nyeon Jeongmi-wol Gapja-il");
+/// assert_eq!(converter.get_gapja_string_in(Locale::Vietnamese), "năm Nhâm Dần tháng Đinh Mùi ngày Giáp Tý");
+///
+/// assert_eq!(Locale::Japanese.stem_name(0), "甲");
+/// assert_eq!(Locale::Vietnamese.zodiac_name(3), "Mèo");
+/// assert_eq!(Locale::Korean.lunar_month_name(11), "동짓달");
+/// assert_eq!(DayOfWeek::Monday.name_in(Locale::Vietnamese), "Thứ Hai");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// Korean in Hangul (갑자, 월요일, 정월).
+    #[default]
+    Korean,
+    /// Korean in Hanja (甲子, 月曜日, 正月), as returned by the `chinese_*` methods.
+    Hanja,
+    /// English, with the Gapja in the Revised Romanization of Korean (Gapja, Monday).
+    English,
+    /// Japanese (甲子, 月曜日, 睦月).
+    Japanese,
+    /// Vietnamese (Giáp Tý, Thứ Hai, Tháng Giêng).
+    Vietnamese,
+}
+
+/// The unit after a pillar of the Gapja: year, month, day or hour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PillarUnit {
+    Year,
+    Month,
+    Day,
+    Hour,
+}
+
+const HANJA_STEMS: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+const KOREAN_STEMS: [&str; 10] = ["갑", "을", "병", "정", "무", "기", "경", "신", "임", "계"];
+const ROMANIZED_STEMS: [&str; 10] = [
+    "Gap", "Eul", "Byeong", "Jeong", "Mu", "Gi", "Gyeong", "Sin", "Im", "Gye",
+];
+const VIETNAMESE_STEMS: [&str; 10] = [
+    "Giáp", "Ất", "Bính", "Đinh", "Mậu", "Kỷ", "Canh", "Tân", "Nhâm", "Quý",
+];
+
+const HANJA_BRANCHES: [&str; 12] = [
+    "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+];
+const KOREAN_BRANCHES: [&str; 12] = [
+    "자", "축", "인", "묘", "진", "사", "오", "미", "신", "유", "술", "해",
+];
+const ROMANIZED_BRANCHES: [&str; 12] = [
+    "Ja", "Chuk", "In", "Myo", "Jin", "Sa", "O", "Mi", "Sin", "Yu", "Sul", "Hae",
+];
+/// Branches as the second syllable of a romanized Gapja (Gapja, Imin).
+const ROMANIZED_BRANCH_SYLLABLES: [&str; 12] = [
+    "ja", "chuk", "in", "myo", "jin", "sa", "o", "mi", "sin", "yu", "sul", "hae",
+];
+const VIETNAMESE_BRANCHES: [&str; 12] = [
+    "Tý", "Sửu", "Dần", "Mão", "Thìn", "Tỵ", "Ngọ", "Mùi", "Thân", "Dậu", "Tuất", "Hợi",
+];
+
+const KOREAN_ZODIAC: [&str; 12] = [
+    "쥐",
+    "소",
+    "호랑이",
+    "토끼",
+    "용",
+    "뱀",
+    "말",
+    "양",
+    "원숭이",
+    "닭",
+    "개",
+    "돼지",
+];
+const HANJA_ZODIAC: [&str; 12] = [
+    "鼠", "牛", "虎", "兎", "龍", "蛇", "馬", "羊", "猴", "鷄", "狗", "猪",
+];
+const ENGLISH_ZODIAC: [&str; 12] = [
+    "Rat", "Ox", "Tiger", "Rabbit", "Dragon", "Snake", "Horse", "Goat", "Monkey", "Rooster", "Dog",
+    "Pig",
+];
+const JAPANESE_ZODIAC: [&str; 12] = [
+    "ねずみ",
+    "うし",
+    "とら",
+    "うさぎ",
+    "たつ",
+    "へび",
+    "うま",
+    "ひつじ",
+    "さる",
+    "とり",
+    "いぬ",
+    "いのしし",
+];
+const VIETNAMESE_ZODIAC: [&str; 12] = [
+    "Chuột", "Trâu", "Hổ", "Mèo", "Rồng", "Rắn", "Ngựa", "Dê", "Khỉ", "Gà", "Chó", "Lợn",
+];
+
+const KOREAN_WEEKDAYS: [&str; 7] = [
+    "월요일",
+    "화요일",
+    "수요일",
+    "목요일",
+    "금요일",
+    "토요일",
+    "일요일",
+];
+const HANJA_WEEKDAYS: [&str; 7] = [
+    "月曜日",
+    "火曜日",
+    "水曜日",
+    "木曜日",
+    "金曜日",
+    "土曜日",
+    "日曜日",
+];
+const ENGLISH_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const VIETNAMESE_WEEKDAYS: [&str; 7] = [
+    "Thứ Hai",
+    "Thứ Ba",
+    "Thứ Tư",
+    "Thứ Năm",
+    "Thứ Sáu",
+    "Thứ Bảy",
+    "Chủ Nhật",
+];
+
+const KOREAN_SHORT_WEEKDAYS: [&str; 7] = ["월", "화", "수", "목", "금", "토", "일"];
+const HANJA_SHORT_WEEKDAYS: [&str; 7] = ["月", "火", "水", "木", "金", "土", "日"];
+const ENGLISH_SHORT_WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const VIETNAMESE_SHORT_WEEKDAYS: [&str; 7] = ["T2", "T3", "T4", "T5", "T6", "T7", "CN"];
+
+const KOREAN_LUNAR_MONTHS: [&str; 12] = [
+    "정월",
+    "이월",
+    "삼월",
+    "사월",
+    "오월",
+    "유월",
+    "칠월",
+    "팔월",
+    "구월",
+    "시월",
+    "동짓달",
+    "섣달",
+];
+const HANJA_LUNAR_MONTHS: [&str; 12] = [
+    "正月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "冬月", "臘月",
+];
+const ENGLISH_LUNAR_MONTHS: [&str; 12] = [
+    "First Month",
+    "Second Month",
+    "Third Month",
+    "Fourth Month",
+    "Fifth Month",
+    "Sixth Month",
+    "Seventh Month",
+    "Eighth Month",
+    "Ninth Month",
+    "Tenth Month",
+    "Eleventh Month",
+    "Twelfth Month",
+];
+const JAPANESE_LUNAR_MONTHS: [&str; 12] = [
+    "睦月",
+    "如月",
+    "弥生",
+    "卯月",
+    "皐月",
+    "水無月",
+    "文月",
+    "葉月",
+    "長月",
+    "神無月",
+    "霜月",
+    "師走",
+];
+const VIETNAMESE_LUNAR_MONTHS: [&str; 12] = [
+    "Tháng Giêng",
+    "Tháng Hai",
+    "Tháng Ba",
+    "Tháng Tư",
+    "Tháng Năm",
+    "Tháng Sáu",
+    "Tháng Bảy",
+    "Tháng Tám",
+    "Tháng Chín",
+    "Tháng Mười",
+    "Tháng Mười Một",
+    "Tháng Chạp",
+];
+
+/// Romanized units of the Gapja, joined with a hyphen (Imin-nyeon).
+const ROMANIZED_UNITS: [&str; 4] = ["nyeon", "wol", "il", "si"];
+/// Vietnamese units of the Gapja, written before the pillar (năm Nhâm Dần).
+const VIETNAMESE_UNITS: [&str; 4] = ["năm", "tháng", "ngày", "giờ"];
+const KOREAN_HOUR_UNIT: char = '시';
+const HANJA_HOUR_UNIT: char = '時';
+
+impl Locale {
+    /// All locales.
+    pub const ALL: [Locale; 5] = [
+        Locale::Korean,
+        Locale::Hanja,
+        Locale::English,
+        Locale::Japanese,
+        Locale::Vietnamese,
+    ];
+
+    /// Returns the name of a heavenly stem (0 = 갑 甲 … 9 = 계 癸).
+    ///
+    /// # Panics
+    /// Panics if `index` is not within 0-9.
+    pub fn stem_name(self, index: usize) -> &'static str {
+        match self {
+            Locale::Korean => KOREAN_STEMS[index],
+            Locale::Hanja | Locale::Japanese => HANJA_STEMS[index],
+            Locale::English => ROMANIZED_STEMS[index],
+            Locale::Vietnamese => VIETNAMESE_STEMS[index],
+        }
+    }
+
+    /// Returns the name of an earthly branch (0 = 자 子 … 11 = 해 亥).
+    ///
+    /// # Panics
+    /// Panics if `index` is not within 0-11.
+    pub fn branch_name(self, index: usize) -> &'static str {
+        match self {
+            Locale::Korean => KOREAN_BRANCHES[index],
+            Locale::Hanja | Locale::Japanese => HANJA_BRANCHES[index],
+            Locale::English => ROMANIZED_BRANCHES[index],
+            Locale::Vietnamese => VIETNAMESE_BRANCHES[index],
+        }
+    }
+
+    /// Returns the zodiac animal (띠) of an earthly branch (0 = 쥐 … 11 = 돼지).
+    ///
+    /// The animals follow local tradition: the fourth is a cat (Mèo) in Vietnamese, and
+    /// the last a boar (いのしし) in Japanese.
+    ///
+    /// # Panics
+    /// Panics if `index` is not within 0-11.
+    pub fn zodiac_name(self, index: usize) -> &'static str {
+        match self {
+            Locale::Korean => KOREAN_ZODIAC[index],
+            Locale::Hanja => HANJA_ZODIAC[index],
+            Locale::English => ENGLISH_ZODIAC[index],
+            Locale::Japanese => JAPANESE_ZODIAC[index],
+            Locale::Vietnamese => VIETNAMESE_ZODIAC[index],
+        }
+    }
+
+    /// Returns the name of a day of the week (월요일, 月曜日, Monday, Thứ Hai).
+    pub fn weekday_name(self, day: DayOfWeek) -> &'static str {
+        let index = day.index();
+
+        match self {
+            Locale::Korean => KOREAN_WEEKDAYS[index],
+            Locale::Hanja | Locale::Japanese => HANJA_WEEKDAYS[index],
+            Locale::English => ENGLISH_WEEKDAYS[index],
+            Locale::Vietnamese => VIETNAMESE_WEEKDAYS[index],
+        }
+    }
+
+    /// Returns the abbreviated name of a day of the week (월, 月, Mon, T2).
+    pub fn short_weekday_name(self, day: DayOfWeek) -> &'static str {
+        let index = day.index();
+
+        match self {
+            Locale::Korean => KOREAN_SHORT_WEEKDAYS[index],
+            Locale::Hanja | Locale::Japanese => HANJA_SHORT_WEEKDAYS[index],
+            Locale::English => ENGLISH_SHORT_WEEKDAYS[index],
+            Locale::Vietnamese => VIETNAMESE_SHORT_WEEKDAYS[index],
+        }
+    }
+
+    /// Returns the traditional name of a lunar month (1 = 정월 … 11 = 동짓달, 12 = 섣달).
+    ///
+    /// # Panics
+    /// Panics if `month` is not within 1-12.
+    pub fn lunar_month_name(self, month: u32) -> &'static str {
+        let index = month as usize - 1;
+
+        match self {
+            Locale::Korean => KOREAN_LUNAR_MONTHS[index],
+            Locale::Hanja => HANJA_LUNAR_MONTHS[index],
+            Locale::English => ENGLISH_LUNAR_MONTHS[index],
+            Locale::Japanese => JAPANESE_LUNAR_MONTHS[index],
+            Locale::Vietnamese => VIETNAMESE_LUNAR_MONTHS[index],
+        }
+    }
+
+    /// Returns the marker of an intercalary month (윤, 閏, Leap, nhuận).
+    pub fn leap_marker(self) -> &'static str {
+        match self {
+            Locale::Korean => "윤",
+            Locale::Hanja | Locale::Japanese => "閏",
+            Locale::English => "Leap",
+            Locale::Vietnamese => "nhuận",
+        }
+    }
+
+    /// Returns the name of a lunar month with its intercalary marker, if any
+    /// (윤이월, 閏二月, Leap Second Month, Tháng Hai nhuận).
+    ///
+    /// # Panics
+    /// Panics if `month` is not within 1-12.
+    pub fn lunar_month(self, month: u32, is_leap_month: bool) -> String {
+        let name = self.lunar_month_name(month);
+
+        match (is_leap_month, self) {
+            (false, _) => name.to_string(),
+            (true, Locale::Korean | Locale::Hanja | Locale::Japanese) => {
+                format!("{}{}", self.leap_marker(), name)
+            }
+            (true, Locale::English) => format!("{} {}", self.leap_marker(), name),
+            (true, Locale::Vietnamese) => format!("{} {}", name, self.leap_marker()),
+        }
+    }
+
+    /// Returns the words marking the Gapja of an intercalary month (윤월, 閏月).
+    pub(super) fn leap_month_note(self) -> &'static str {
+        match self {
+            Locale::Korean => "윤월",
+            Locale::Hanja | Locale::Japanese => "閏月",
+            Locale::English => "leap month",
+            Locale::Vietnamese => "tháng nhuận",
+        }
+    }
+
+    /// Writes the name of a pillar (갑자, 甲子, Gapja, Giáp Tý).
+    ///
+    /// The romanized name follows the pronunciation across the syllables, as the
+    /// Revised Romanization does: 갑오 is Gabo and 을유 Euryu.
+    pub(super) fn write_pillar(
+        self,
+        f: &mut impl fmt::Write,
+        stem: usize,
+        branch: usize,
+    ) -> fmt::Result {
+        match self {
+            Locale::Korean => write!(f, "{}{}", KOREAN_STEMS[stem], KOREAN_BRANCHES[branch]),
+            Locale::Hanja | Locale::Japanese => {
+                write!(f, "{}{}", HANJA_STEMS[stem], HANJA_BRANCHES[branch])
+            }
+            Locale::English => {
+                let syllable = ROMANIZED_BRANCH_SYLLABLES[branch];
+                let stem = match ROMANIZED_STEMS[stem] {
+                    "Gap" if syllable.starts_with(['i', 'o', 'y']) => "Gab",
+                    "Eul" if syllable.starts_with(['i', 'o', 'y']) => "Eur",
+                    stem => stem,
+                };
+
+                write!(f, "{}{}", stem, syllable)
+            }
+            Locale::Vietnamese => {
+                write!(
+                    f,
+                    "{} {}",
+                    VIETNAMESE_STEMS[stem], VIETNAMESE_BRANCHES[branch]
+                )
+            }
+        }
+    }
+
+    /// Writes the name of a pillar with its unit (임인년, 壬寅年, Imin-nyeon, năm Nhâm Dần).
+    pub(super) fn write_pillar_with_unit(
+        self,
+        f: &mut impl fmt::Write,
+        stem: usize,
+        branch: usize,
+        unit: PillarUnit,
+    ) -> fmt::Result {
+        let index = unit as usize;
+
+        match self {
+            Locale::Korean | Locale::Hanja | Locale::Japanese => {
+                self.write_pillar(f, stem, branch)?;
+                f.write_char(match (self, unit) {
+                    (Locale::Korean, PillarUnit::Hour) => KOREAN_HOUR_UNIT,
+                    (Locale::Korean, _) => KOREAN_GAPJA_UNIT[index],
+                    (_, PillarUnit::Hour) => HANJA_HOUR_UNIT,
+                    _ => CHINESE_GAPJA_UNIT[index],
+                })
+            }
+            Locale::English => {
+                self.write_pillar(f, stem, branch)?;
+                write!(f, "-{}", ROMANIZED_UNITS[index])
+            }
+            Locale::Vietnamese => {
+                write!(f, "{} ", VIETNAMESE_UNITS[index])?;
+                self.write_pillar(f, stem, branch)
+            }
+        }
+    }
+}
+
+impl DayOfWeek {
+    /// Returns the position of the day in the week, starting from Monday at 0.
+    pub(super) fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the name of the day in a locale (e.g. "월요일", "Monday").
+    pub fn name_in(self, locale: Locale) -> &'static str {
+        locale.weekday_name(self)
+    }
+}
+
+impl fmt::Display for DayOfWeek {
+    /// Formats the day with its Korean name (e.g. "월요일").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name_in(Locale::Korean))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Locale, PillarUnit};
+    use crate::DayOfWeek;
+    use crate::klc::{CHINESE_CHEONGAN, CHINESE_GANJI, KOREAN_CHEONGAN, KOREAN_GANJI};
+
+    fn pillar(locale: Locale, stem: usize, branch: usize) -> String {
+        let mut name = String::new();
+        locale.write_pillar(&mut name, stem, branch).unwrap();
+        name
+    }
+
+    #[test]
+    fn test_tables_match_the_char_tables() {
+        for (index, (korean, hanja)) in KOREAN_CHEONGAN.iter().zip(CHINESE_CHEONGAN).enumerate() {
+            assert_eq!(Locale::Korean.stem_name(index), korean.to_string());
+            assert_eq!(Locale::Hanja.stem_name(index), hanja.to_string());
+        }
+        for (index, (korean, hanja)) in KOREAN_GANJI.iter().zip(CHINESE_GANJI).enumerate() {
+            assert_eq!(Locale::Korean.branch_name(index), korean.to_string());
+            assert_eq!(Locale::Hanja.branch_name(index), hanja.to_string());
+        }
+    }
+
+    #[test]
+    fn test_revised_romanization() {
+        for (stem, branch, name) in [
+            (0, 0, "Gapja"),
+            (1, 1, "Eulchuk"),
+            (2, 2, "Byeongin"),
+            (8, 2, "Imin"),
+            (0, 6, "Gabo"),
+            (0, 2, "Gabin"),
+            (1, 9, "Euryu"),
+            (1, 11, "Eulhae"),
+            (4, 6, "Muo"),
+            (9, 9, "Gyeyu"),
+            (6, 10, "Gyeongsul"),
+        ] {
+            assert_eq!(pillar(Locale::English, stem, branch), name);
+        }
+
+        let mut name = String::new();
+        Locale::English
+            .write_pillar_with_unit(&mut name, 8, 2, PillarUnit::Year)
+            .unwrap();
+        assert_eq!(name, "Imin-nyeon");
+    }
+
+    #[test]
+    fn test_units() {
+        let cases = [
+            (Locale::Korean, "무술시"),
+            (Locale::Hanja, "戊戌時"),
+            (Locale::English, "Musul-si"),
+            (Locale::Japanese, "戊戌時"),
+            (Locale::Vietnamese, "giờ Mậu Tuất"),
+        ];
+
+        for (locale, want) in cases {
+            let mut name = String::new();
+            locale
+                .write_pillar_with_unit(&mut name, 4, 10, PillarUnit::Hour)
+                .unwrap();
+            assert_eq!(name, want);
+        }
+    }
+
+    #[test]
+    fn test_lunar_months() {
+        assert_eq!(Locale::Korean.lunar_month(2, true), "윤이월");
+        assert_eq!(Locale::Hanja.lunar_month(12, false), "臘月");
+        assert_eq!(Locale::English.lunar_month(2, true), "Leap Second Month");
+        assert_eq!(Locale::Japanese.lunar_month(2, true), "閏如月");
+        assert_eq!(Locale::Vietnamese.lunar_month(1, true), "Tháng Giêng nhuận");
+    }
+
+    #[test]
+    fn test_weekdays() {
+        let names: Vec<&str> = Locale::ALL
+            .iter()
+            .map(|locale| DayOfWeek::Monday.name_in(*locale))
+            .collect();
+
+        assert_eq!(names, ["월요일", "月曜日", "Monday", "月曜日", "Thứ Hai"]);
+        assert_eq!(
+            Locale::Vietnamese.short_weekday_name(DayOfWeek::Sunday),
+            "CN"
+        );
+        assert_eq!(DayOfWeek::Sunday.to_string(), "일요일");
+    }
+}
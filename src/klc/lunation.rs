@@ -0,0 +1,165 @@
+//! Lunar year data computed astronomically from new moons and solar terms.
+//!
+//! Years are generated with the rules of the Korean calendar:
+//!
+//! * a month begins on the local day of the new moon;
+//! * the month containing the winter solstice (동지) is the 11th month;
+//! * when 13 months fall between two such 11th months, the first month without a
+//!   principal term (중기, a solar longitude that is a multiple of 30°) is the
+//!   intercalary month and takes the number of the month before it.
+//!
+//! The result uses the same bit layout as `KOREAN_LUNAR_DATA`, so a computed year can
+//! stand in for a table entry.
+
+use super::{LunarSolarConverter, astro};
+
+/// A lunar month: its number, whether it is intercalary, and the local Julian Day
+/// Numbers of its first day and of the first day of the following month.
+#[derive(Debug, Clone, Copy)]
+struct Month {
+    number: u32,
+    is_intercalation: bool,
+    start_day: i64,
+    end_day: i64,
+}
+
+/// Returns the `KOREAN_LUNAR_DATA` encoding of a lunar year, with the dates evaluated
+/// at `utc_offset_days` east of Greenwich (9/24 for Korean Standard Time).
+pub(crate) fn lunar_year_data(year: i32, utc_offset_days: f64) -> u32 {
+    lunar_year(year, utc_offset_days).1
+}
+
+/// Returns the local Julian Day Number of the first day of a lunar year along with its
+/// `KOREAN_LUNAR_DATA` encoding.
+pub(crate) fn lunar_year(year: i32, utc_offset_days: f64) -> (i64, u32) {
+    let mut months: Vec<Month> = months_after_solstice(year - 1, utc_offset_days)
+        .into_iter()
+        .skip_while(|month| month.number != 1)
+        .collect();
+    months.extend(
+        months_after_solstice(year, utc_offset_days)
+            .into_iter()
+            .take_while(|month| month.number != 1),
+    );
+
+    let first_day = months[0].start_day;
+    let mut data = 0x8000_0000;
+    let mut year_days = 0;
+
+    for month in months {
+        let days = (month.end_day - month.start_day) as u32;
+        year_days += days;
+
+        if month.is_intercalation {
+            data |= month.number << 12;
+            if days == 30 {
+                data |= 1 << 16;
+            }
+        } else if days == 30 {
+            data |= 1 << (12 - month.number);
+        }
+    }
+
+    data |= year_days << 17;
+    if LunarSolarConverter::is_solar_leap_year(year as u32) {
+        data |= 1 << 30;
+    }
+
+    (first_day, data)
+}
+
+/// Returns the months from the 11th month of `year`, which contains its winter
+/// solstice, up to the 11th month of the following year (exclusive).
+fn months_after_solstice(year: i32, utc_offset_days: f64) -> Vec<Month> {
+    let first = eleventh_month_lunation(year, utc_offset_days);
+    let last = eleventh_month_lunation(year + 1, utc_offset_days);
+    let has_intercalation = last - first == 13;
+
+    let mut months = Vec::with_capacity(13);
+    let mut number = 11;
+    let mut intercalation_found = false;
+
+    for k in first..last {
+        let start_day = local_day(astro::new_moon(k), utc_offset_days);
+        let end_day = local_day(astro::new_moon(k + 1), utc_offset_days);
+        let is_intercalation = has_intercalation
+            && !intercalation_found
+            && k != first
+            && !has_principal_term(start_day, end_day, utc_offset_days);
+
+        if is_intercalation {
+            intercalation_found = true;
+        } else if k != first {
+            number = number % 12 + 1;
+        }
+
+        months.push(Month {
+            number,
+            is_intercalation,
+            start_day,
+            end_day,
+        });
+    }
+
+    months
+}
+
+/// Returns the lunation number of the 11th month, the one whose first day is the last
+/// new moon day on or before the winter solstice of `year`.
+fn eleventh_month_lunation(year: i32, utc_offset_days: f64) -> i64 {
+    let guess = astro::jdn_from_date(year, 12, 21) as f64;
+    let solstice = astro::find_sun_longitude(270.0, guess);
+    let solstice_day = local_day(solstice, utc_offset_days);
+
+    let k = astro::lunation_before(solstice);
+    if local_day(astro::new_moon(k + 1), utc_offset_days) == solstice_day {
+        k + 1
+    } else {
+        k
+    }
+}
+
+/// Returns `true` if a principal term falls on one of the local days
+/// `start_day..end_day`.
+fn has_principal_term(start_day: i64, end_day: i64, utc_offset_days: f64) -> bool {
+    let principal_term = |day: i64| {
+        let midnight = day as f64 - 0.5 - utc_offset_days;
+        (astro::sun_apparent_longitude(astro::ut_to_tt(midnight)) / 30.0).floor() as i64
+    };
+
+    principal_term(start_day) != principal_term(end_day)
+}
+
+/// Returns the Julian Day Number of the local date of an instant in UT.
+fn local_day(jd: f64, utc_offset_days: f64) -> i64 {
+    (jd + utc_offset_days + 0.5).floor() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lunar_year_data;
+    use crate::klc::{KOREAN_LUNAR_BASE_YEAR, KOREAN_LUNAR_DATA, solar_terms::KST_OFFSET_DAYS};
+
+    #[test]
+    fn test_matches_table_since_1912() {
+        // Earlier years follow the traditional calendars, which were not reckoned in KST
+        for year in 1912..=2050 {
+            let index = (year - KOREAN_LUNAR_BASE_YEAR) as usize;
+
+            assert_eq!(
+                lunar_year_data(year, KST_OFFSET_DAYS),
+                KOREAN_LUNAR_DATA[index],
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn test_intercalary_eleventh_month_2033() {
+        // The sun enters no principal term in the month after 11월 2033
+        let data = lunar_year_data(2033, KST_OFFSET_DAYS);
+
+        assert_eq!((data >> 12) & 0xF, 11);
+    }
+}
@@ -24,7 +24,7 @@
  */
 
 #[derive(Debug, Default)]
-pub struct LunarSolarConverter {
+pub struct LunarSolarConverter<C: LunisolarCalendar = Korean> {
     lunar_year: i32,
     lunar_month: u32,
     lunar_day: u32,
@@ -32,15 +32,76 @@ pub struct LunarSolarConverter {
     solar_year: u32,
     solar_month: u32,
     solar_day: u32,
-    gapja_year_inx: [Option<usize>; 3],
-    gapja_month_inx: [Option<usize>; 3],
-    gapja_day_inx: [Option<usize>; 3],
+    gapja_convention: GapjaConvention,
+    calendar: PhantomData<C>,
 }
 
+mod anniversary;
+mod arithmetic;
+mod astro;
+pub mod calendar;
+#[cfg(feature = "chrono")]
+mod chrono_support;
+#[doc(hidden)]
+pub mod datagen;
+mod date;
+mod elements;
+mod error;
+mod format;
+mod gapja;
+pub mod holidays;
+#[cfg(feature = "jiff")]
+mod jiff_support;
+mod locale;
+mod lunation;
+mod nayin;
+mod parse;
+mod pillars;
+mod range;
+pub mod seasonal_days;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod sexagenary;
+pub mod solar_terms;
+mod tables;
+#[cfg(feature = "time")]
+mod time_support;
+mod timezone;
+mod year_info;
+
+pub use anniversary::{
+    LunarAnniversary, MissingDayPolicy, MissingLeapMonthPolicy, Observance, Occurrences,
+};
+pub use arithmetic::{DayOverflowPolicy, LeapMonthPolicy};
+#[cfg(feature = "chrono")]
+pub use chrono_support::NaiveDateExt;
+pub use date::{LunarDate, SolarDate};
+pub use elements::{Element, ElementDistribution, ElementRelation, YinYang};
+pub use error::KlcError;
+pub use format::{DatePattern, FormattedDate};
+pub use gapja::{Gapja, GapjaConvention};
+#[cfg(feature = "jiff")]
+pub use jiff_support::ZonedExt;
+pub use locale::Locale;
+pub use nayin::Nayin;
+pub use parse::{DateField, DateParser, ParseError, ParseErrorKind, ParsedDate};
+pub use pillars::{FourPillars, JasiConvention};
+pub use range::{DatePair, DateRange, RangeStep};
+#[cfg(feature = "serde")]
+pub use serde_support::object as serde_object;
+pub use sexagenary::{EarthlyBranch, HeavenlyStem, Pillar, Sexagenary};
+pub use solar_terms::{SolarTerm, SolarTermTime};
+pub use timezone::KoreanDateTime;
+pub use year_info::{LunarMonthInfo, LunarYearInfo, LunarYears};
+
+use std::marker::PhantomData;
+
+use calendar::{CalendarDate, Korean, LunisolarCalendar};
+
 const KOREAN_LUNAR_MIN_VALUE: u32 = 13910101;
-const KOREAN_LUNAR_MAX_VALUE: u32 = 20501118;
+const KOREAN_LUNAR_MAX_VALUE: u32 = 22001125;
 const KOREAN_SOLAR_MIN_VALUE: u32 = 13910205;
-const KOREAN_SOLAR_MAX_VALUE: u32 = 20501231;
+const KOREAN_SOLAR_MAX_VALUE: u32 = 22001231;
 
 const KOREAN_LUNAR_BASE_YEAR: i32 = 1391;
 const SOLAR_LUNAR_DAY_DIFF: u32 = 35;
@@ -71,8 +132,6 @@ const CHINESE_GANJI: [char; 12] = [
 ];
 const CHINESE_GAPJA_UNIT: [char; 3] = ['\u{5e74}', '\u{6708}', '\u{65e5}'];
 
-const INTERCALATION_STR: [char; 2] = ['\u{c724}', '\u{958f}'];
-
 const KOREAN_LUNAR_DATA: [u32; 660] = [
     0x82c40653, 0xc301c6a9, 0x82c405aa, 0x82c60ab5, 0x830092bd, 0xc2c402b6, 0x82c60c37, 0x82fe552e,
     0x82c40c96, 0xc2c60e4b, 0x82fe3752, 0x82c60daa, 0x8301b5b4, 0xc2c6056d, 0x82c402ae, 0x83007a3d,
@@ -161,6 +220,7 @@ const KOREAN_LUNAR_DATA: [u32; 660] = [
 
 /// Represents the days of the week.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DayOfWeek {
     /// Monday (월요일)
     Monday, // Corresponds to JDN % 7 = 0
@@ -184,22 +244,11 @@ impl LunarSolarConverter {
         LunarSolarConverter::default()
     }
 
-    fn get_lunar_data(year: i32) -> u32 {
-        if year < KOREAN_LUNAR_BASE_YEAR {
-            0
-        } else {
-            *KOREAN_LUNAR_DATA
-                .get((year - KOREAN_LUNAR_BASE_YEAR) as usize)
-                .unwrap_or(&0)
-        }
-    }
-
-    fn get_lunar_intercalation_month(lunar_data: u32) -> u32 {
+    const fn get_lunar_intercalation_month(lunar_data: u32) -> u32 {
         (lunar_data >> 12) & 0x000F
     }
 
-    fn shift_lunar_days(year: i32) -> u32 {
-        let lunar_data = Self::get_lunar_data(year);
+    const fn shift_lunar_days(lunar_data: u32) -> u32 {
         if lunar_data == 0 {
             return 0;
         }
@@ -207,12 +256,14 @@ impl LunarSolarConverter {
         let mut total_days = 0;
         let month_bits = lunar_data & 0xFFF;
 
-        for month in 1..=12 {
+        let mut month = 1;
+        while month <= 12 {
             if ((month_bits >> (12 - month)) & 0x01) > 0 {
                 total_days += LUNAR_BIG_MONTH_DAY;
             } else {
                 total_days += LUNAR_SMALL_MONTH_DAY;
             }
+            month += 1;
         }
 
         let intercalation_month = Self::get_lunar_intercalation_month(lunar_data);
@@ -228,11 +279,11 @@ impl LunarSolarConverter {
     }
 
     fn get_lunar_days(year: i32, month: u32, is_intercalation: bool) -> u32 {
+        Korean::tables().lunar_days(year, month, is_intercalation)
+    }
+
+    const fn get_lunar_month_days(lunar_data: u32, month: u32, is_intercalation: bool) -> u32 {
         let mut days = 0;
-        if year < KOREAN_LUNAR_BASE_YEAR {
-            return 0;
-        }
-        let lunar_data = Self::get_lunar_data(year);
         let intercalation_month = Self::get_lunar_intercalation_month(lunar_data);
 
         if is_intercalation && intercalation_month == month {
@@ -252,51 +303,7 @@ impl LunarSolarConverter {
         days
     }
 
-    fn get_lunar_days_before_base_year(year: i32) -> u32 {
-        let mut days = 0;
-
-        for base_year in KOREAN_LUNAR_BASE_YEAR..year {
-            days += Self::shift_lunar_days(base_year);
-        }
-
-        days
-    }
-
-    fn get_lunar_days_before_base_month(year: i32, month: u32, is_intercalation: bool) -> u32 {
-        let mut days = 0;
-        if year < KOREAN_LUNAR_BASE_YEAR || month == 0 {
-            return 0;
-        }
-
-        let lunar_data = Self::get_lunar_data(year);
-        let intercalation_month = Self::get_lunar_intercalation_month(lunar_data);
-
-        for base_month in 1..month {
-            days += Self::get_lunar_days(year, base_month, false);
-
-            if intercalation_month > 0 && intercalation_month == base_month {
-                days += Self::get_lunar_days(year, intercalation_month, true);
-            }
-        }
-
-        if is_intercalation && intercalation_month == month {
-            days += Self::get_lunar_days(year, month, false);
-        }
-
-        days
-    }
-
-    fn get_lunar_abs_days(year: i32, month: u32, day: u32, is_intercalation: bool) -> u32 {
-        if year < KOREAN_LUNAR_BASE_YEAR {
-            0
-        } else {
-            Self::get_lunar_days_before_base_year(year)
-                + Self::get_lunar_days_before_base_month(year, month, is_intercalation)
-                + day
-        }
-    }
-
-    fn is_gregorian_leap(year: i32) -> bool {
+    const fn is_gregorian_leap(year: i32) -> bool {
         if year <= 1582 {
             // Before Gregorian reform, Julian calendar used
             year % 4 == 0
@@ -306,7 +313,7 @@ impl LunarSolarConverter {
         }
     }
 
-    fn shift_solar_days(year: i32) -> u32 {
+    const fn shift_solar_days(year: i32) -> u32 {
         let mut days;
 
         // Use standard Gregorian leap year calculation
@@ -323,7 +330,7 @@ impl LunarSolarConverter {
         days
     }
 
-    fn get_solar_days(year: i32, month: u32) -> u32 {
+    const fn get_solar_days(year: i32, month: u32) -> u32 {
         let mut days = 0;
 
         if year < KOREAN_LUNAR_BASE_YEAR {
@@ -332,9 +339,9 @@ impl LunarSolarConverter {
 
         // Use standard Gregorian leap year calculation for February
         if month == 2 && Self::is_gregorian_leap(year) {
-            days = *SOLAR_DAYS.get(12).unwrap_or(&0); // Index 12 is 29
+            days = SOLAR_DAYS[12]; // Index 12 is 29
         } else if month > 0 && month < 13 {
-            days = *SOLAR_DAYS.get((month - 1) as usize).unwrap_or(&0);
+            days = SOLAR_DAYS[(month - 1) as usize];
         }
 
         if year == 1582 && month == 10 {
@@ -345,27 +352,37 @@ impl LunarSolarConverter {
     }
 
     fn get_solar_day_before_base_year(year: i32) -> u32 {
-        let mut days = 0;
+        if year < KOREAN_LUNAR_BASE_YEAR {
+            return 0;
+        }
 
-        for base_year in KOREAN_LUNAR_BASE_YEAR..year {
+        let index = (year - KOREAN_LUNAR_BASE_YEAR) as usize;
+        let last_index = tables::SOLAR_YEAR_START_DAYS.len() - 1;
+        let mut days = tables::SOLAR_YEAR_START_DAYS[index.min(last_index)];
+
+        for base_year in (KOREAN_LUNAR_BASE_YEAR + last_index as i32)..year {
             days += Self::shift_solar_days(base_year);
         }
 
         days
     }
 
-    fn get_solar_days_before_base_month(year: i32, month: u32) -> u32 {
-        let mut days = 0;
-
-        if year < KOREAN_LUNAR_BASE_YEAR {
-            return 0;
+    fn get_solar_month_start_days(year: i32) -> &'static [u16; 13] {
+        if year == 1582 {
+            &tables::SOLAR_MONTH_START_DAYS[2]
+        } else if Self::is_gregorian_leap(year) {
+            &tables::SOLAR_MONTH_START_DAYS[1]
+        } else {
+            &tables::SOLAR_MONTH_START_DAYS[0]
         }
+    }
 
-        for base_month in 1..month {
-            days += Self::get_solar_days(year, base_month);
+    fn get_solar_days_before_base_month(year: i32, month: u32) -> u32 {
+        if year < KOREAN_LUNAR_BASE_YEAR || month == 0 {
+            return 0;
         }
 
-        days
+        Self::get_solar_month_start_days(year)[month.min(13) as usize - 1] as u32
     }
 
     fn get_solar_abs_days(year: i32, month: u32, day: u32) -> u32 {
@@ -377,238 +394,45 @@ impl LunarSolarConverter {
                 + day;
             days -= SOLAR_LUNAR_DAY_DIFF;
 
-            days
-        }
-    }
-
-    /// Sets the converter's date based on a Lunar date.
-    ///
-    /// # Arguments
-    /// * `lunar_year` - The lunar year.
-    /// * `lunar_month` - The lunar month (1-12).
-    /// * `lunar_day` - The lunar day.
-    /// * `is_intercalation` - `true` if the month is an intercalary (leap) month (윤달).
-    ///
-    /// # Returns
-    /// `true` if the provided lunar date is valid and within the supported range,
-    /// `false` otherwise. If `true`, the corresponding solar date is calculated and stored.
-    pub fn set_lunar_date(
-        &mut self,
-        lunar_year: i32,
-        lunar_month: u32,
-        lunar_day: u32,
-        is_intercalation: bool,
-    ) -> bool {
-        let mut is_valid = false;
-
-        if Self::check_valid_date(
-            true,
-            is_intercalation,
-            lunar_year as u32,
-            lunar_month,
-            lunar_day,
-        ) {
-            self.lunar_year = lunar_year;
-            self.lunar_month = lunar_month;
-            self.lunar_day = lunar_day;
-            self.is_intercalation = is_intercalation
-                && (Self::get_lunar_intercalation_month(Self::get_lunar_data(lunar_year))
-                    == lunar_month);
-            self.set_solar_date_by_lunar_date(
-                lunar_year,
-                lunar_month,
-                lunar_day,
-                self.is_intercalation,
-            );
-
-            is_valid = true;
-        }
-
-        is_valid
-    }
-
-    /// Sets the converter's date based on a Solar (Gregorian) date.
-    ///
-    /// # Arguments
-    /// * `solar_year` - The solar year.
-    /// * `solar_month` - The solar month (1-12).
-    /// * `solar_day` - The solar day.
-    ///
-    /// # Returns
-    /// `true` if the provided solar date is valid and within the supported range
-    /// (handles the 1582 Gregorian reform gap), `false` otherwise. If `true`,
-    /// the corresponding lunar date is calculated and stored.
-    pub fn set_solar_date(&mut self, solar_year: u32, solar_month: u32, solar_day: u32) -> bool {
-        let mut is_valid = false;
-
-        if Self::check_valid_date(false, false, solar_year, solar_month, solar_day) {
-            self.solar_year = solar_year;
-            self.solar_month = solar_month;
-            self.solar_day = solar_day;
-            self.set_lunar_date_by_solar_date(solar_year, solar_month, solar_day);
-            is_valid = true;
-        }
-
-        is_valid
-    }
-
-    fn get_gapja(&mut self) {
-        let abs_days = Self::get_lunar_abs_days(
-            self.lunar_year,
-            self.lunar_month,
-            self.lunar_day,
-            self.is_intercalation,
-        );
-
-        if abs_days > 0 {
-            self.gapja_year_inx[0] = Some(
-                ((self.lunar_year + 7) - KOREAN_LUNAR_BASE_YEAR) as usize % KOREAN_CHEONGAN.len(),
-            );
-            self.gapja_year_inx[1] = Some(
-                ((self.lunar_year + 7) - KOREAN_LUNAR_BASE_YEAR) as usize % KOREAN_GANJI.len(),
-            );
-
-            let mut month_count = self.lunar_month;
-            month_count += 12 * ((self.lunar_year - KOREAN_LUNAR_BASE_YEAR) as u32);
-            self.gapja_month_inx[0] = Some((month_count + 5) as usize % KOREAN_CHEONGAN.len());
-            self.gapja_month_inx[1] = Some((month_count + 1) as usize % KOREAN_GANJI.len());
-
-            self.gapja_day_inx[0] = Some((abs_days + 4) as usize % KOREAN_CHEONGAN.len());
-            self.gapja_day_inx[1] = Some(abs_days as usize % KOREAN_GANJI.len());
-        } else {
-            self.gapja_year_inx = [None, None, None];
-            self.gapja_month_inx = [None, None, None];
-            self.gapja_day_inx = [None, None, None];
-        }
-    }
-
-    /// Returns the calculated Korean Gapja (간지) string for the current date.
-    /// Format: \"[Year]년 [Month]월 [Day]일\" (e.g., \"임인년 정미월 갑자일\").
-    /// Appends \" (윤월)\" if the current lunar month is intercalary.
-    /// Returns an empty string if the date is invalid.
-    pub fn get_gapja_string(&mut self) -> String {
-        self.get_gapja();
-
-        let mut gapja_string = String::new();
-
-        if let (Some(year_cheongan), Some(year_ganji)) =
-            (self.gapja_year_inx[0], self.gapja_year_inx[1])
-        {
-            gapja_string.push(KOREAN_CHEONGAN[year_cheongan]);
-            gapja_string.push(KOREAN_GANJI[year_ganji]);
-            gapja_string.push(KOREAN_GAPJA_UNIT[0]);
-        } else {
-            return "".to_string();
-        }
-
-        gapja_string.push(' ');
-
-        if let (Some(month_cheongan), Some(month_ganji)) =
-            (self.gapja_month_inx[0], self.gapja_month_inx[1])
-        {
-            gapja_string.push(KOREAN_CHEONGAN[month_cheongan]);
-            gapja_string.push(KOREAN_GANJI[month_ganji]);
-            gapja_string.push(KOREAN_GAPJA_UNIT[1]);
-        } else {
-            return "".to_string();
-        }
-
-        gapja_string.push(' ');
-
-        if let (Some(day_cheongan), Some(day_ganji)) =
-            (self.gapja_day_inx[0], self.gapja_day_inx[1])
-        {
-            gapja_string.push(KOREAN_CHEONGAN[day_cheongan]);
-            gapja_string.push(KOREAN_GANJI[day_ganji]);
-            gapja_string.push(KOREAN_GAPJA_UNIT[2]);
-        } else {
-            return "".to_string();
-        }
+            // 1582. 10. 15 directly follows 1582. 10. 4
+            if year == 1582 && month == 10 && day > 4 {
+                days -= 10;
+            }
 
-        if self.is_intercalation {
-            gapja_string.push_str(" (");
-            gapja_string.push(INTERCALATION_STR[0]);
-            gapja_string.push(KOREAN_GAPJA_UNIT[1]);
-            gapja_string.push(')');
+            days
         }
-
-        gapja_string
     }
 
-    /// Returns the calculated Chinese Gapja string for the current date.
-    /// Format: \"[Year]年 [Month]月 [Day]日\" (e.g., \"壬寅年 丁未月 甲子日\").
-    /// Appends \" (閏月)\" if the current lunar month is intercalary.
-    /// Returns an empty string if the date is invalid.
-    pub fn get_chinese_gapja_string(&mut self) -> String {
-        self.get_gapja();
+    /// Returns the solar date `(year, month, day)` of a 1-based day count since the Korean
+    /// base date, the inverse of `get_solar_abs_days`.
+    fn get_solar_date_by_abs_days(abs_days: u32) -> (u32, u32, u32) {
+        // Days since the start of the solar base year, 1-based
+        let days = abs_days + SOLAR_LUNAR_DAY_DIFF;
 
-        let mut gapja_string = String::new();
+        let year_index = tables::SOLAR_YEAR_START_DAYS.partition_point(|&start| start < days) - 1;
+        let year = (KOREAN_LUNAR_BASE_YEAR + year_index as i32) as u32;
 
-        if let (Some(year_cheongan), Some(year_ganji)) =
-            (self.gapja_year_inx[0], self.gapja_year_inx[1])
-        {
-            gapja_string.push(CHINESE_CHEONGAN[year_cheongan]);
-            gapja_string.push(CHINESE_GANJI[year_ganji]);
-            gapja_string.push(CHINESE_GAPJA_UNIT[0]);
-        } else {
-            return "".to_string();
-        }
+        let day_of_year = days - tables::SOLAR_YEAR_START_DAYS[year_index];
+        let month_starts = Self::get_solar_month_start_days(year as i32);
+        let month_index = month_starts.partition_point(|&start| (start as u32) < day_of_year) - 1;
 
-        gapja_string.push(' ');
+        let month = month_index as u32 + 1;
+        let mut day = day_of_year - month_starts[month_index] as u32;
 
-        if let (Some(month_cheongan), Some(month_ganji)) =
-            (self.gapja_month_inx[0], self.gapja_month_inx[1])
-        {
-            gapja_string.push(CHINESE_CHEONGAN[month_cheongan]);
-            gapja_string.push(CHINESE_GANJI[month_ganji]);
-            gapja_string.push(CHINESE_GAPJA_UNIT[1]);
-        } else {
-            return "".to_string();
-        }
-
-        gapja_string.push(' ');
-
-        if let (Some(day_cheongan), Some(day_ganji)) =
-            (self.gapja_day_inx[0], self.gapja_day_inx[1])
-        {
-            gapja_string.push(CHINESE_CHEONGAN[day_cheongan]);
-            gapja_string.push(CHINESE_GANJI[day_ganji]);
-            gapja_string.push(CHINESE_GAPJA_UNIT[2]);
-        } else {
-            return "".to_string();
+        if year == 1582 && month == 10 && day > 4 {
+            day += 10;
         }
 
-        if self.is_intercalation {
-            gapja_string.push_str(" (");
-            gapja_string.push(INTERCALATION_STR[1]);
-            gapja_string.push(CHINESE_GAPJA_UNIT[1]);
-            gapja_string.push(')');
-        }
-
-        gapja_string
+        (year, month, day)
     }
 
-    /// Returns the calculated Lunar date in ISO 8601 format (YYYY-MM-DD).
-    /// Appends " Intercalation" if the current lunar month is intercalary.
-    pub fn get_lunar_iso_format(&self) -> String {
-        let mut iso_str = format!(
-            "{:04}-{:02}-{:02}",
-            self.lunar_year, self.lunar_month, self.lunar_day
-        );
-
-        if self.is_intercalation {
-            iso_str.push_str(" Intercalation");
-        }
-
-        iso_str
-    }
-
-    /// Returns the calculated Solar date in ISO 8601 format (YYYY-MM-DD).
-    pub fn get_solar_iso_format(&self) -> String {
-        format!(
-            "{:04}-{:02}-{:02}",
-            self.solar_year, self.solar_month, self.solar_day
-        )
+    /// Returns the cheongan and ganji indices of the day Gapja for a count of days
+    /// since the base date (see `get_lunar_abs_days` and `get_solar_abs_days`).
+    const fn get_day_gapja_inx(abs_days: u32) -> [usize; 2] {
+        [
+            (abs_days + 4) as usize % KOREAN_CHEONGAN.len(),
+            abs_days as usize % KOREAN_GANJI.len(),
+        ]
     }
 
     /// Calculates the Julian Day Number (JDN) for a given Solar date.
@@ -734,7 +558,8 @@ impl LunarSolarConverter {
 
     /// Gets the intercalary (leap) month (윤달) for a given lunar year, if one exists.
     ///
-    /// Based on the pre-calculated `KOREAN_LUNAR_DATA`.
+    /// Based on the pre-calculated `KOREAN_LUNAR_DATA`, or on the astronomically computed
+    /// years after 2050.
     ///
     /// # Arguments
     /// * `year` - The lunar year.
@@ -751,56 +576,306 @@ impl LunarSolarConverter {
     /// assert_eq!(LunarSolarConverter::get_lunar_intercalary_month(2022), None);
     /// ```
     pub fn get_lunar_intercalary_month(year: i32) -> Option<u32> {
-        if year < KOREAN_LUNAR_BASE_YEAR
-            || year > KOREAN_LUNAR_BASE_YEAR + KOREAN_LUNAR_DATA.len() as i32 - 1
-        {
-            return None; // Year out of supported range
+        Korean::intercalary_month(year)
+    }
+
+    fn try_check_valid_date(
+        is_lunar: bool,
+        is_intercalation: bool,
+        year: u32,
+        month: u32,
+        day: u32,
+    ) -> Result<(), KlcError> {
+        Korean::tables().try_check_valid_date(is_lunar, is_intercalation, year, month, day)
+    }
+}
+
+impl<C: LunisolarCalendar> LunarSolarConverter<C> {
+    /// Sets the converter's date based on a Lunar date.
+    ///
+    /// # Arguments
+    /// * `lunar_year` - The lunar year.
+    /// * `lunar_month` - The lunar month (1-12).
+    /// * `lunar_day` - The lunar day.
+    /// * `is_intercalation` - `true` if the month is an intercalary (leap) month (윤달).
+    ///
+    /// # Returns
+    /// `true` if the provided lunar date is valid and within the supported range,
+    /// `false` otherwise. If `true`, the corresponding solar date is calculated and stored.
+    /// Use [`try_set_lunar_date`](Self::try_set_lunar_date) to learn why a date was rejected.
+    pub fn set_lunar_date(
+        &mut self,
+        lunar_year: i32,
+        lunar_month: u32,
+        lunar_day: u32,
+        is_intercalation: bool,
+    ) -> bool {
+        self.try_set_lunar_date(lunar_year, lunar_month, lunar_day, is_intercalation)
+            .is_ok()
+    }
+
+    /// Sets the converter's date based on a Lunar date, reporting why an invalid date was rejected.
+    ///
+    /// # Arguments
+    /// * `lunar_year` - The lunar year.
+    /// * `lunar_month` - The lunar month (1-12).
+    /// * `lunar_day` - The lunar day.
+    /// * `is_intercalation` - `true` if the month is an intercalary (leap) month (윤달).
+    ///
+    /// # Errors
+    /// Returns a [`KlcError`] if the lunar date is invalid or outside the supported range.
+    /// The converter is left unchanged in that case.
+    pub fn try_set_lunar_date(
+        &mut self,
+        lunar_year: i32,
+        lunar_month: u32,
+        lunar_day: u32,
+        is_intercalation: bool,
+    ) -> Result<(), KlcError> {
+        if lunar_year < 0 {
+            return Err(KlcError::NegativeYear(lunar_year));
         }
-        let lunar_data = Self::get_lunar_data(year);
-        let intercalary_month = Self::get_lunar_intercalation_month(lunar_data);
-        if intercalary_month > 0 {
-            Some(intercalary_month)
-        } else {
-            None
+
+        C::tables().try_check_valid_date(
+            true,
+            is_intercalation,
+            lunar_year as u32,
+            lunar_month,
+            lunar_day,
+        )?;
+
+        self.lunar_year = lunar_year;
+        self.lunar_month = lunar_month;
+        self.lunar_day = lunar_day;
+        self.is_intercalation =
+            is_intercalation && C::tables().intercalation_month(lunar_year) == Some(lunar_month);
+        self.set_solar_date_by_lunar_date(
+            lunar_year,
+            lunar_month,
+            lunar_day,
+            self.is_intercalation,
+        );
+
+        Ok(())
+    }
+
+    /// Sets the converter's date based on a Solar (Gregorian) date.
+    ///
+    /// # Arguments
+    /// * `solar_year` - The solar year.
+    /// * `solar_month` - The solar month (1-12).
+    /// * `solar_day` - The solar day.
+    ///
+    /// # Returns
+    /// `true` if the provided solar date is valid and within the supported range
+    /// (handles the 1582 Gregorian reform gap), `false` otherwise. If `true`,
+    /// the corresponding lunar date is calculated and stored.
+    /// Use [`try_set_solar_date`](Self::try_set_solar_date) to learn why a date was rejected.
+    pub fn set_solar_date(&mut self, solar_year: u32, solar_month: u32, solar_day: u32) -> bool {
+        self.try_set_solar_date(solar_year, solar_month, solar_day)
+            .is_ok()
+    }
+
+    /// Sets the converter's date based on a Solar (Gregorian) date, reporting why an invalid
+    /// date was rejected.
+    ///
+    /// # Arguments
+    /// * `solar_year` - The solar year.
+    /// * `solar_month` - The solar month (1-12).
+    /// * `solar_day` - The solar day.
+    ///
+    /// # Errors
+    /// Returns a [`KlcError`] if the solar date is invalid, within the 1582 Gregorian
+    /// reform gap or outside the supported range. The converter is left unchanged in that case.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{KlcError, LunarSolarConverter};
+    ///
+    /// let mut converter = LunarSolarConverter::new();
+    /// assert_eq!(converter.try_set_solar_date(2022, 7, 10), Ok(()));
+    /// assert_eq!(
+    ///     converter.try_set_solar_date(2022, 2, 29),
+    ///     Err(KlcError::InvalidDay { day: 29, days_in_month: 28 })
+    /// );
+    /// ```
+    pub fn try_set_solar_date(
+        &mut self,
+        solar_year: u32,
+        solar_month: u32,
+        solar_day: u32,
+    ) -> Result<(), KlcError> {
+        C::tables().try_check_valid_date(false, false, solar_year, solar_month, solar_day)?;
+
+        self.solar_year = solar_year;
+        self.solar_month = solar_month;
+        self.solar_day = solar_day;
+        self.set_lunar_date_by_solar_date(solar_year, solar_month, solar_day);
+
+        Ok(())
+    }
+
+    /// Returns the Gapja (간지) of the current date: the year, month and day pillars
+    /// under the [`GapjaConvention`] of the converter.
+    /// Returns `None` if the date is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{GapjaConvention, LunarSolarConverter};
+    ///
+    /// let mut converter = LunarSolarConverter::new();
+    /// converter.set_solar_date(2024, 2, 4); // 입춘, lunar 2023-12-25
+    /// assert_eq!(converter.gapja().unwrap().year.to_string(), "계묘");
+    ///
+    /// converter.set_gapja_convention(GapjaConvention::SolarTerm);
+    /// assert_eq!(converter.gapja().unwrap().year.to_string(), "갑진");
+    /// ```
+    pub fn gapja(&self) -> Option<Gapja> {
+        let abs_days = C::tables().lunar_abs_days(
+            self.lunar_year,
+            self.lunar_month,
+            self.lunar_day,
+            self.is_intercalation,
+        );
+        if abs_days == 0 {
+            return None;
         }
+
+        let [year_cheongan, year_ganji, month_cheongan, month_ganji] = match self.gapja_convention {
+            GapjaConvention::LunarCalendar => {
+                gapja::lunar_calendar_pillars(self.lunar_year, self.lunar_month)
+            }
+            GapjaConvention::SolarTerm => {
+                let solar =
+                    SolarDate::new(self.solar_year, self.solar_month, self.solar_day).ok()?;
+                gapja::solar_term_pillars(solar.solar_term())
+            }
+        };
+        let [day_cheongan, day_ganji] = LunarSolarConverter::get_day_gapja_inx(abs_days);
+
+        Some(Gapja {
+            year: Sexagenary::from_indices(year_cheongan, year_ganji),
+            month: Sexagenary::from_indices(month_cheongan, month_ganji),
+            day: Sexagenary::from_indices(day_cheongan, day_ganji),
+        })
+    }
+
+    /// Sets how the year and month pillars of the Gapja are determined.
+    ///
+    /// The default is [`GapjaConvention::LunarCalendar`].
+    pub fn set_gapja_convention(&mut self, convention: GapjaConvention) {
+        self.gapja_convention = convention;
+    }
+
+    /// Returns the convention used for the year and month pillars of the Gapja.
+    pub fn gapja_convention(&self) -> GapjaConvention {
+        self.gapja_convention
+    }
+
+    /// Returns the calculated Korean Gapja (간지) string for the current date.
+    /// Format: \"[Year]년 [Month]월 [Day]일\" (e.g., \"임인년 정미월 갑자일\").
+    /// Appends \" (윤월)\" if the current lunar month is intercalary and the
+    /// [`GapjaConvention::LunarCalendar`] convention is used.
+    /// Returns an empty string if the date is invalid.
+    pub fn get_gapja_string(&self) -> String {
+        self.get_gapja_string_in(Locale::Korean)
+    }
+
+    /// Returns the calculated Chinese Gapja string for the current date.
+    /// Format: \"[Year]年 [Month]月 [Day]日\" (e.g., \"壬寅年 丁未月 甲子日\").
+    /// Appends \" (閏月)\" if the current lunar month is intercalary and the
+    /// [`GapjaConvention::LunarCalendar`] convention is used.
+    /// Returns an empty string if the date is invalid.
+    pub fn get_chinese_gapja_string(&self) -> String {
+        self.get_gapja_string_in(Locale::Hanja)
+    }
+
+    /// Returns the calculated Gapja string for the current date in a locale, such as
+    /// \"Imin-nyeon Jeongmi-wol Gapja-il\" in [`Locale::English`] (Revised Romanization)
+    /// or \"năm Nhâm Dần tháng Đinh Mùi ngày Giáp Tý\" in [`Locale::Vietnamese`].
+    /// Appends the intercalary month note (e.g. \" (윤월)\") as
+    /// [`get_gapja_string`](Self::get_gapja_string) does.
+    /// Returns an empty string if the date is invalid.
+    pub fn get_gapja_string_in(&self, locale: Locale) -> String {
+        let Some(gapja) = self.gapja() else {
+            return "".to_string();
+        };
+        let mut gapja_string = gapja.to_string_in(locale);
+
+        if self.is_intercalation && self.gapja_convention == GapjaConvention::LunarCalendar {
+            gapja_string.push_str(" (");
+            gapja_string.push_str(locale.leap_month_note());
+            gapja_string.push(')');
+        }
+
+        gapja_string
+    }
+
+    /// Returns the calculated Lunar date in ISO 8601 format (YYYY-MM-DD).
+    /// Appends " Intercalation" if the current lunar month is intercalary.
+    pub fn get_lunar_iso_format(&self) -> String {
+        let mut iso_str = format!(
+            "{:04}-{:02}-{:02}",
+            self.lunar_year, self.lunar_month, self.lunar_day
+        );
+
+        if self.is_intercalation {
+            iso_str.push_str(" Intercalation");
+        }
+
+        iso_str
+    }
+
+    /// Returns the calculated Solar date in ISO 8601 format (YYYY-MM-DD).
+    pub fn get_solar_iso_format(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}",
+            self.solar_year, self.solar_month, self.solar_day
+        )
     }
 
     // --- Getters for date fields ---
+
     /// Returns the currently stored solar year.
     #[allow(dead_code)]
     pub fn solar_year(&self) -> u32 {
         self.solar_year
     }
+
     /// Returns the currently stored solar month.
     #[allow(dead_code)]
     pub fn solar_month(&self) -> u32 {
         self.solar_month
     }
+
     /// Returns the currently stored solar day.
     #[allow(dead_code)]
     pub fn solar_day(&self) -> u32 {
         self.solar_day
     }
+
     /// Returns the currently stored lunar year.
     pub fn lunar_year(&self) -> i32 {
         self.lunar_year
     }
+
     /// Returns the currently stored lunar month.
     #[allow(dead_code)]
     pub fn lunar_month(&self) -> u32 {
         self.lunar_month
     }
+
     /// Returns the currently stored lunar day.
     #[allow(dead_code)]
     pub fn lunar_day(&self) -> u32 {
         self.lunar_day
     }
+
     /// Returns `true` if the currently stored lunar date is an intercalary month.
     #[allow(dead_code)]
     pub fn is_intercalation(&self) -> bool {
         self.is_intercalation
     }
-    // ------------------------------
 
     // --- Internal helper methods ---
 
@@ -812,132 +887,39 @@ impl LunarSolarConverter {
         is_intercalation: bool,
     ) {
         let abs_days =
-            Self::get_lunar_abs_days(lunar_year, lunar_month, lunar_day, is_intercalation);
-
-        if abs_days < Self::get_solar_abs_days(lunar_year + 1, 1, 1) {
-            self.solar_year = lunar_year as u32;
-        } else {
-            self.solar_year = (lunar_year + 1) as u32;
-        }
-
-        for month in (1..=12).rev() {
-            let abs_days_by_month = Self::get_solar_abs_days(self.solar_year as i32, month, 1);
-
-            if abs_days >= abs_days_by_month {
-                self.solar_month = month;
-                self.solar_day = abs_days - abs_days_by_month + 1;
-                break;
-            }
-        }
+            C::tables().lunar_abs_days(lunar_year, lunar_month, lunar_day, is_intercalation);
 
-        if self.solar_year == 1582 && self.solar_month == 10 && self.solar_day > 4 {
-            self.solar_day += 10;
-        }
+        (self.solar_year, self.solar_month, self.solar_day) =
+            LunarSolarConverter::get_solar_date_by_abs_days(abs_days);
     }
 
     fn set_lunar_date_by_solar_date(&mut self, solar_year: u32, solar_month: u32, solar_day: u32) {
-        let abs_days = Self::get_solar_abs_days(solar_year as i32, solar_month, solar_day);
-
-        self.is_intercalation = false;
-
-        if abs_days >= Self::get_lunar_abs_days(solar_year as i32, 1, 1, false) {
-            self.lunar_year = solar_year as i32;
-        } else {
-            self.lunar_year = solar_year as i32 - 1;
-        }
-
-        for month in (1..=12).rev() {
-            let abs_days_by_month = Self::get_lunar_abs_days(self.lunar_year, month, 1, false);
-
-            if abs_days >= abs_days_by_month {
-                self.lunar_month = month;
-
-                if Self::get_lunar_intercalation_month(Self::get_lunar_data(self.lunar_year))
-                    == month
-                {
-                    self.is_intercalation =
-                        abs_days >= Self::get_lunar_abs_days(self.lunar_year, month, 1, true);
-                }
-
-                self.lunar_day = abs_days
-                    - Self::get_lunar_abs_days(
-                        self.lunar_year,
-                        self.lunar_month,
-                        1,
-                        self.is_intercalation,
-                    )
-                    + 1;
-
-                break;
-            }
-        }
-    }
+        let abs_days =
+            LunarSolarConverter::get_solar_abs_days(solar_year as i32, solar_month, solar_day);
 
-    fn is_valid_min(is_lunar: bool, date_value: u32) -> bool {
-        if is_lunar {
-            KOREAN_LUNAR_MIN_VALUE <= date_value
-        } else {
-            KOREAN_SOLAR_MIN_VALUE <= date_value
-        }
+        (
+            self.lunar_year,
+            self.lunar_month,
+            self.lunar_day,
+            self.is_intercalation,
+        ) = C::tables().lunar_date(abs_days);
     }
 
-    fn is_valid_max(is_lunar: bool, date_value: u32) -> bool {
-        if is_lunar {
-            KOREAN_LUNAR_MAX_VALUE >= date_value
-        } else {
-            KOREAN_SOLAR_MAX_VALUE >= date_value
+    /// Returns the current lunar date.
+    pub(super) fn calendar_date(&self) -> CalendarDate {
+        CalendarDate {
+            year: self.lunar_year,
+            month: self.lunar_month,
+            day: self.lunar_day,
+            is_leap_month: self.is_intercalation,
         }
     }
-
-    fn check_valid_date(
-        is_lunar: bool,
-        is_intercalation: bool,
-        year: u32,
-        month: u32,
-        day: u32,
-    ) -> bool {
-        let mut is_valid = false;
-        let date_value = year * 10000 + month * 100 + day;
-
-        //1582. 10. 5 ~ 1582. 10. 14 is not enable
-        if Self::is_valid_min(is_lunar, date_value) && Self::is_valid_max(is_lunar, date_value) {
-            let mut day_limit;
-
-            if month > 0 && month < 13 && day > 0 {
-                if is_lunar {
-                    if is_intercalation
-                        && Self::get_lunar_intercalation_month(Self::get_lunar_data(year as i32))
-                            != month
-                    {
-                        return false;
-                    }
-                    day_limit = Self::get_lunar_days(year as i32, month, is_intercalation);
-                } else {
-                    day_limit = Self::get_solar_days(year as i32, month);
-                }
-
-                if !is_lunar && year == 1582 && month == 10 {
-                    if day > 4 && day < 15 {
-                        return false;
-                    } else {
-                        day_limit += 10;
-                    }
-                }
-
-                if day <= day_limit {
-                    is_valid = true;
-                }
-            }
-        }
-
-        is_valid
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::DayOfWeek;
-    use crate::LunarSolarConverter;
+    use super::{DayOfWeek, GapjaConvention, KlcError, tables};
+    use crate::{DatePair, LunarSolarConverter, SolarDate};
 
     #[test]
     fn test_lunar_iso_format() {
@@ -979,6 +961,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gapja_matches_date_pair() {
+        let mut converter = LunarSolarConverter::new();
+
+        for (year, month, day) in [(1391, 2, 5), (2023, 4, 5), (2024, 2, 10), (2200, 12, 31)] {
+            converter.set_solar_date(year, month, day);
+            let pair = DatePair::from(SolarDate::new(year, month, day).unwrap());
+
+            assert_eq!(converter.gapja(), Some(pair.gapja()));
+        }
+
+        assert_eq!(LunarSolarConverter::new().gapja(), None);
+    }
+
     #[test]
     fn test_solar_iso_format() {
         let mut converter = LunarSolarConverter::new();
@@ -991,6 +987,45 @@ mod tests {
         assert_eq!(solar, want, "got {:?} want {:?}", solar, want);
     }
 
+    #[test]
+    fn test_gapja_convention_around_ipchun() {
+        let mut converter = LunarSolarConverter::new();
+        converter.set_gapja_convention(GapjaConvention::SolarTerm);
+
+        // 입춘 2024 is on 02-04 (17:27 KST); lunar 2023-12-24 and 2023-12-25
+        assert!(converter.set_solar_date(2024, 2, 3));
+        assert_eq!(converter.get_gapja_string(), "계묘년 을축월 정유일");
+        assert!(converter.set_solar_date(2024, 2, 4));
+        assert_eq!(converter.get_gapja_string(), "갑진년 병인월 무술일");
+        assert_eq!(converter.get_chinese_gapja_string(), "甲辰年 丙寅月 戊戌日");
+
+        // The lunar new year (2024-02-10) does not change the pillars
+        assert!(converter.set_lunar_date(2024, 1, 1, false));
+        assert_eq!(converter.get_gapja_string(), "갑진년 병인월 갑진일");
+
+        converter.set_gapja_convention(GapjaConvention::LunarCalendar);
+        assert!(converter.set_solar_date(2024, 2, 4));
+        assert_eq!(converter.get_gapja_string(), "계묘년 을축월 무술일");
+    }
+
+    #[test]
+    fn test_gapja_convention_intercalation() {
+        let mut converter = LunarSolarConverter::new();
+        assert_eq!(converter.gapja_convention(), GapjaConvention::LunarCalendar);
+
+        // 윤2월 2023 spans 청명 (2023-04-05), so the month pillar changes inside it
+        assert!(converter.set_lunar_date(2023, 2, 14, true));
+        assert_eq!(converter.get_gapja_string(), "계묘년 을묘월 임진일 (윤월)");
+        assert!(converter.set_lunar_date(2023, 2, 15, true));
+        assert_eq!(converter.get_gapja_string(), "계묘년 을묘월 계사일 (윤월)");
+
+        converter.set_gapja_convention(GapjaConvention::SolarTerm);
+        assert!(converter.set_lunar_date(2023, 2, 14, true));
+        assert_eq!(converter.get_gapja_string(), "계묘년 을묘월 임진일");
+        assert!(converter.set_lunar_date(2023, 2, 15, true));
+        assert_eq!(converter.get_gapja_string(), "계묘년 병진월 계사일");
+    }
+
     #[test]
     fn test_gapja_string_intercalation() {
         let mut converter = LunarSolarConverter::new();
@@ -1043,6 +1078,31 @@ mod tests {
         assert!(!is_valid, "Expected solar date to be invalid");
     }
 
+    #[test]
+    fn test_gregorian_gap_abs_days() {
+        // 1582-10-15 is the day after 1582-10-04; the baseline counted the 10 skipped days
+        let before = LunarSolarConverter::get_solar_abs_days(1582, 10, 4);
+        let after = LunarSolarConverter::get_solar_abs_days(1582, 10, 15);
+        assert_eq!(before, 2299160 - 2229155); // JDN of 1582-10-04 less the base JDN
+        assert_eq!(after, before + 1);
+        assert_eq!(
+            LunarSolarConverter::get_solar_abs_days(1582, 11, 1),
+            before + 18
+        );
+
+        let mut converter = LunarSolarConverter::new();
+        for ((month, day), lunar, gapja) in [
+            ((10, 4), "1582-09-18", "임오년 경술월 계유일"),
+            ((10, 15), "1582-09-19", "임오년 경술월 갑술일"),
+            ((10, 31), "1582-10-06", "임오년 신해월 경인일"),
+            ((11, 1), "1582-10-07", "임오년 신해월 신묘일"),
+        ] {
+            converter.set_solar_date(1582, month, day);
+            assert_eq!(converter.get_lunar_iso_format(), lunar);
+            assert_eq!(converter.get_gapja_string(), gapja);
+        }
+    }
+
     #[test]
     fn test_invalid_lunar_date() {
         let mut converter = LunarSolarConverter::new();
@@ -1051,6 +1111,97 @@ mod tests {
         assert!(!is_valid, "Expected lunar date to be invalid");
     }
 
+    #[test]
+    fn test_try_set_solar_date_errors() {
+        let mut converter = LunarSolarConverter::new();
+
+        assert_eq!(
+            converter.try_set_solar_date(1582, 10, 10),
+            Err(KlcError::GregorianGap { day: 10 })
+        );
+        assert_eq!(
+            converter.try_set_solar_date(2201, 1, 1),
+            Err(KlcError::OutOfRange {
+                min: 13910205,
+                max: 22001231
+            })
+        );
+        assert_eq!(
+            converter.try_set_solar_date(2022, 13, 1),
+            Err(KlcError::InvalidMonth(13))
+        );
+        assert_eq!(
+            converter.try_set_solar_date(2022, 4, 31),
+            Err(KlcError::InvalidDay {
+                day: 31,
+                days_in_month: 30
+            })
+        );
+        assert_eq!(
+            converter.try_set_solar_date(2023, 1, u32::MAX),
+            Err(KlcError::InvalidDay {
+                day: u32::MAX,
+                days_in_month: 31
+            })
+        );
+        assert_eq!(
+            converter.try_set_solar_date(u32::MAX, 12, u32::MAX),
+            Err(KlcError::OutOfRange {
+                min: 13910205,
+                max: 22001231
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_set_lunar_date_errors() {
+        let mut converter = LunarSolarConverter::new();
+
+        assert_eq!(
+            converter.try_set_lunar_date(-1, 1, 1, false),
+            Err(KlcError::NegativeYear(-1))
+        );
+        assert_eq!(
+            converter.try_set_lunar_date(1390, 12, 31, false),
+            Err(KlcError::OutOfRange {
+                min: 13910101,
+                max: 22001125
+            })
+        );
+        assert_eq!(
+            converter.try_set_lunar_date(2023, 3, 1, true),
+            Err(KlcError::InvalidLeapMonth {
+                year: 2023,
+                month: 3,
+                leap_month: Some(2)
+            })
+        );
+        assert_eq!(
+            converter.try_set_lunar_date(2023, 2, 30, true),
+            Err(KlcError::InvalidDay {
+                day: 30,
+                days_in_month: 29
+            })
+        );
+        assert_eq!(
+            converter.try_set_lunar_date(2023, 2, u32::MAX, false),
+            Err(KlcError::InvalidDay {
+                day: u32::MAX,
+                days_in_month: 30
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_set_date_keeps_state_on_error() {
+        let mut converter = LunarSolarConverter::new();
+        converter.set_solar_date(2022, 7, 10);
+
+        assert!(converter.try_set_solar_date(2022, 2, 30).is_err());
+        assert_eq!(converter.get_solar_iso_format(), "2022-07-10");
+        assert_eq!(converter.get_lunar_iso_format(), "2022-06-12");
+    }
+
     #[test]
     fn test_get_lunar_days() {
         let days = LunarSolarConverter::get_lunar_days(2022, 6, false);
@@ -1087,14 +1238,14 @@ mod tests {
 
     #[test]
     fn test_get_lunar_abs_days() {
-        let days = LunarSolarConverter::get_lunar_abs_days(2022, 6, 12, false);
+        let days = tables::KOREAN_TABLES.lunar_abs_days(2022, 6, 12, false);
 
         assert_eq!(days, 230616, "Expected 230616 absolute lunar days");
     }
 
     #[test]
     fn test_get_lunar_abs_days_invalid_year() {
-        let days = LunarSolarConverter::get_lunar_abs_days(1390, 6, 12, false);
+        let days = tables::KOREAN_TABLES.lunar_abs_days(1390, 6, 12, false);
 
         assert_eq!(
             days, 0,
@@ -1120,6 +1271,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_round_trip_every_supported_day() {
+        let mut converter = LunarSolarConverter::new();
+        let mut expected_abs_days = 0;
+
+        for year in 1391..=2200 {
+            for month in 1..=12 {
+                for day in 1..=31 {
+                    if converter.set_solar_date(year, month, day) {
+                        expected_abs_days += 1;
+                        let abs_days = tables::KOREAN_TABLES.lunar_abs_days(
+                            converter.lunar_year,
+                            converter.lunar_month,
+                            converter.lunar_day,
+                            converter.is_intercalation,
+                        );
+                        assert_eq!(abs_days, expected_abs_days, "{}-{}-{}", year, month, day);
+
+                        let lunar = converter.get_lunar_iso_format();
+                        assert!(converter.set_lunar_date(
+                            converter.lunar_year,
+                            converter.lunar_month,
+                            converter.lunar_day,
+                            converter.is_intercalation,
+                        ));
+                        assert_eq!(
+                            (
+                                converter.solar_year,
+                                converter.solar_month,
+                                converter.solar_day
+                            ),
+                            (year, month, day),
+                            "{}",
+                            lunar
+                        );
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            expected_abs_days,
+            tables::KOREAN_TABLES.lunar_abs_days(2200, 11, 25, false)
+        );
+    }
+
+    #[test]
+    fn test_computed_years_after_table() {
+        let mut converter = LunarSolarConverter::new();
+
+        // The last day of the table is followed by the first computed year
+        assert!(converter.set_lunar_date(2050, 12, 29, false));
+        assert_eq!(converter.get_solar_iso_format(), "2051-02-10");
+        assert!(converter.set_solar_date(2051, 2, 11));
+        assert_eq!(converter.get_lunar_iso_format(), "2051-01-01");
+
+        assert!(converter.set_solar_date(2200, 12, 31));
+        assert_eq!(converter.get_lunar_iso_format(), "2200-11-25");
+    }
+
     #[test]
     fn test_invalid_date_for_get_gapja_string() {
         let mut converter = LunarSolarConverter::new();
@@ -1219,11 +1430,11 @@ mod tests {
     fn test_get_julian_day_number_invalid_range_after() {
         // Using a date beyond the KOREAN_SOLAR_MAX_VALUE
         // JDN function should work outside library range.
-        let jdn = LunarSolarConverter::get_julian_day_number(2051, 1, 1);
-        let want = Some(2470173);
+        let jdn = LunarSolarConverter::get_julian_day_number(2201, 1, 1);
+        let want = Some(2524959);
         assert_eq!(
             jdn, want,
-            "Expected JDN {:?} for 2051-01-01, got {:?}",
+            "Expected JDN {:?} for 2201-01-01, got {:?}",
             want, jdn
         );
     }
@@ -1338,11 +1549,11 @@ mod tests {
             None,
             "Year 1390 is out of range"
         );
-        // Max year is 2050 (1391 + 660 - 1)
+        // Max year is 2200 (1391 + 660 + 150 - 1)
         assert_eq!(
-            LunarSolarConverter::get_lunar_intercalary_month(2051),
+            LunarSolarConverter::get_lunar_intercalary_month(2201),
             None,
-            "Year 2051 is out of range"
+            "Year 2201 is out of range"
         );
     }
 }
@@ -0,0 +1,160 @@
+//! The nayin (납음오행, 納音五行) of the sexagenary cycle.
+
+use std::fmt;
+
+use super::{Element, Sexagenary};
+
+/// Korean and Hanja names and elements of the nayin, one for each two pairs of the
+/// cycle starting from 갑자/을축.
+const NAYIN_TABLE: [(&str, &str, Element); 30] = [
+    ("해중금", "海中金", Element::Metal),
+    ("노중화", "爐中火", Element::Fire),
+    ("대림목", "大林木", Element::Wood),
+    ("노방토", "路傍土", Element::Earth),
+    ("검봉금", "劍鋒金", Element::Metal),
+    ("산두화", "山頭火", Element::Fire),
+    ("간하수", "澗下水", Element::Water),
+    ("성두토", "城頭土", Element::Earth),
+    ("백랍금", "白鑞金", Element::Metal),
+    ("양류목", "楊柳木", Element::Wood),
+    ("천중수", "泉中水", Element::Water),
+    ("옥상토", "屋上土", Element::Earth),
+    ("벽력화", "霹靂火", Element::Fire),
+    ("송백목", "松柏木", Element::Wood),
+    ("장류수", "長流水", Element::Water),
+    ("사중금", "沙中金", Element::Metal),
+    ("산하화", "山下火", Element::Fire),
+    ("평지목", "平地木", Element::Wood),
+    ("벽상토", "壁上土", Element::Earth),
+    ("금박금", "金箔金", Element::Metal),
+    ("복등화", "覆燈火", Element::Fire),
+    ("천하수", "天河水", Element::Water),
+    ("대역토", "大驛土", Element::Earth),
+    ("차천금", "釵釧金", Element::Metal),
+    ("상자목", "桑柘木", Element::Wood),
+    ("대계수", "大溪水", Element::Water),
+    ("사중토", "沙中土", Element::Earth),
+    ("천상화", "天上火", Element::Fire),
+    ("석류목", "石榴木", Element::Wood),
+    ("대해수", "大海水", Element::Water),
+];
+
+/// The nayin (납음, 納音) of a pair of the sexagenary cycle, such as 해중금 (海中金) for
+/// 갑자 and 을축.
+///
+/// The 60 pairs share 30 nayin, two consecutive pairs each. `Display` writes the Korean
+/// name, or the Hanja name with the alternate flag (`{:#}`).
+///
+/// # Example
+/// ```
+/// use rs_klc::{Element, LunarSolarConverter};
+///
+/// let mut converter = LunarSolarConverter::new();
+/// converter.set_solar_date(2024, 2, 10); // 갑진년 병인월 갑진일
+/// let gapja = converter.gapja().unwrap();
+///
+/// let nayin = gapja.year.nayin();
+/// assert_eq!(nayin.korean_name(), "복등화");
+/// assert_eq!(nayin.hanja_name(), "覆燈火");
+/// assert_eq!(nayin.element(), Element::Fire);
+/// assert_eq!(format!("{} {:#}", gapja.month.nayin(), gapja.day.nayin()), "노중화 覆燈火");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nayin {
+    index: usize,
+}
+
+impl Nayin {
+    /// Returns the nayin at a position in the table, from 0 (해중금) to 29 (대해수),
+    /// wrapping around.
+    pub fn from_index(index: usize) -> Nayin {
+        Nayin {
+            index: index % NAYIN_TABLE.len(),
+        }
+    }
+
+    /// Returns the position of this nayin in the table (0 for 해중금).
+    pub fn index(self) -> usize {
+        self.index
+    }
+
+    /// Returns the Korean name of this nayin (e.g. "해중금").
+    pub fn korean_name(self) -> &'static str {
+        NAYIN_TABLE[self.index].0
+    }
+
+    /// Returns the Hanja name of this nayin (e.g. "海中金").
+    pub fn hanja_name(self) -> &'static str {
+        NAYIN_TABLE[self.index].1
+    }
+
+    /// Returns the element of this nayin, the last character of its name (e.g. 금).
+    pub fn element(self) -> Element {
+        NAYIN_TABLE[self.index].2
+    }
+
+    /// Returns the two pairs of the cycle that share this nayin (e.g. 갑자 and 을축).
+    pub fn pillars(self) -> [Sexagenary; 2] {
+        let first = Sexagenary::from_indices(2 * self.index, 2 * self.index);
+
+        [first, first.next()]
+    }
+}
+
+impl Sexagenary {
+    /// Returns the nayin (납음) of the pair (e.g. 해중금 for 갑자).
+    pub fn nayin(&self) -> Nayin {
+        Nayin::from_index(self.sexagenary_index() / 2)
+    }
+}
+
+impl fmt::Display for Nayin {
+    /// Formats the nayin with its Korean name (e.g. "해중금"), or its Hanja name with `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.hanja_name())
+        } else {
+            f.write_str(self.korean_name())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NAYIN_TABLE, Nayin};
+    use crate::{Element, Sexagenary};
+
+    #[test]
+    fn test_nayin_of_the_cycle() {
+        for index in 1..=60 {
+            let pair = Sexagenary::from_cycle_index(index).unwrap();
+            let nayin = pair.nayin();
+
+            assert_eq!(nayin.index(), (index as usize - 1) / 2);
+            assert!(nayin.pillars().contains(&pair));
+        }
+
+        let cycle = |index| Sexagenary::from_cycle_index(index).unwrap().nayin();
+        assert_eq!(cycle(1).korean_name(), "해중금");
+        assert_eq!(cycle(2).hanja_name(), "海中金");
+        assert_eq!(cycle(39).korean_name(), "금박금"); // 임인
+        assert_eq!(cycle(60).to_string(), "대해수");
+        assert_eq!(Nayin::from_index(30), cycle(1));
+    }
+
+    #[test]
+    fn test_names_end_with_the_element() {
+        for (korean, hanja, element) in NAYIN_TABLE {
+            assert!(korean.ends_with(&element.to_string()), "{}", korean);
+            assert!(hanja.ends_with(&format!("{:#}", element)), "{}", hanja);
+        }
+
+        for element in Element::ALL {
+            let count = NAYIN_TABLE
+                .iter()
+                .filter(|entry| entry.2 == element)
+                .count();
+            assert_eq!(count, 6);
+        }
+    }
+}
@@ -0,0 +1,959 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{
+    CHINESE_CHEONGAN, CHINESE_GANJI, KOREAN_CHEONGAN, KOREAN_GANJI, KOREAN_LUNAR_MAX_VALUE,
+    KlcError, KoreanDateTime, LunarDate, SolarDate,
+};
+
+/// A field of a date, as reported by [`ParseErrorKind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateField {
+    Year,
+    Month,
+    Day,
+}
+
+/// Describes why a string was not recognized as a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// The text at the span is not part of a date, or not a directive of a
+    /// [`DatePattern`](crate::DatePattern).
+    UnexpectedToken,
+    /// The number at the span is too large.
+    InvalidNumber,
+    /// The field was given twice; the span is the second occurrence.
+    DuplicateField(DateField),
+    /// The field is missing; the span is the end of the input.
+    MissingField(DateField),
+    /// A solar date was expected, but the span marks a lunar date (음력, 윤, a Gapja
+    /// year or a traditional month name).
+    ExpectedSolar,
+    /// A lunar date was expected, but the span marks a solar date (양력).
+    ExpectedLunar,
+    /// The fields were recognized, but do not form a valid date.
+    InvalidDate(KlcError),
+}
+
+/// An error from parsing a date, with the byte range of the offending text.
+///
+/// # Example
+/// ```
+/// use rs_klc::{KlcError, LunarDate, ParseErrorKind};
+///
+/// let input = "음력 2024년 윤2월 15일";
+/// let err = input.parse::<LunarDate>().unwrap_err();
+///
+/// assert!(matches!(
+///     err.kind(),
+///     ParseErrorKind::InvalidDate(KlcError::InvalidLeapMonth { .. })
+/// ));
+/// assert_eq!(&input[err.span()], "윤2월");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+}
+
+/// A date parsed by [`DateParser::parse`], in the calendar the input was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParsedDate {
+    Solar(SolarDate),
+    Lunar(LunarDate),
+}
+
+/// A tolerant parser for solar and lunar dates written in Korean, Hanja or ISO-like forms.
+///
+/// The parser recognizes:
+/// * a calendar prefix: 음력/陰曆/阴历 or 양력/陽曆/阳历 (also a bare 음/양, as in "(음)"),
+/// * fields with units (2023년 2월 15일, 2023年2月15日) or separated by `-`, `.` or `/`,
+/// * Arabic, Korean (이천이십삼) and Hanja (二〇二三, 十五, 廿一, 初五) numerals,
+/// * an intercalary month marker: 윤/閏/闰, `L` (2023-L02-15) or the " Intercalation"
+///   suffix of [`LunarSolarConverter::get_lunar_iso_format`](crate::LunarSolarConverter::get_lunar_iso_format),
+/// * traditional names: 정월/正月, 동짓달/冬月, 섣달/臘月, 초하루 and 보름,
+/// * a lunar year by its Gapja (계묘년, 癸卯年).
+///
+/// Whitespace, commas and parentheses are ignored. Without a prefix, a date with an
+/// intercalary marker, a Gapja year or a traditional name is lunar, and any other date
+/// is solar.
+///
+/// A Gapja year is the latest year with that Gapja not after the reference year, by
+/// default the current year in Korea.
+///
+/// # Example
+/// ```
+/// use rs_klc::{DateParser, LunarDate, ParsedDate, SolarDate};
+///
+/// let parser = DateParser::new().with_reference_year(2024);
+/// let lunar = LunarDate::new(2023, 2, 15, true).unwrap();
+///
+/// assert_eq!(parser.parse("음력 2023년 윤2월 15일"), Ok(ParsedDate::Lunar(lunar)));
+/// assert_eq!(parser.parse("陰曆 癸卯年 閏二月 十五日"), Ok(ParsedDate::Lunar(lunar)));
+/// assert_eq!(parser.parse_lunar("2023-02-15 Intercalation"), Ok(lunar));
+///
+/// let solar = SolarDate::new(2022, 7, 10).unwrap();
+/// assert_eq!(parser.parse("양력 2022.7.10"), Ok(ParsedDate::Solar(solar)));
+/// assert_eq!("2022-07-10".parse(), Ok(solar));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateParser {
+    reference_year: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Calendar {
+    Solar,
+    Lunar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Calendar(Calendar),
+    Leap,
+    Number(u32),
+    /// A Gapja by its sexagenary index.
+    Gapja(usize),
+    MonthName(u32),
+    DayName(u32),
+    Unit(DateField),
+}
+
+/// Words matched before numerals, with their tokens. Longer words come first where one
+/// is a prefix of another.
+const WORDS: [(&str, Token); 29] = [
+    ("음력", Token::Calendar(Calendar::Lunar)),
+    ("陰曆", Token::Calendar(Calendar::Lunar)),
+    ("阴历", Token::Calendar(Calendar::Lunar)),
+    ("음", Token::Calendar(Calendar::Lunar)),
+    ("양력", Token::Calendar(Calendar::Solar)),
+    ("陽曆", Token::Calendar(Calendar::Solar)),
+    ("阳历", Token::Calendar(Calendar::Solar)),
+    ("양", Token::Calendar(Calendar::Solar)),
+    ("윤", Token::Leap),
+    ("閏", Token::Leap),
+    ("闰", Token::Leap),
+    ("정월", Token::MonthName(1)),
+    ("正月", Token::MonthName(1)),
+    ("동짓달", Token::MonthName(11)),
+    ("동지달", Token::MonthName(11)),
+    ("冬月", Token::MonthName(11)),
+    ("섣달", Token::MonthName(12)),
+    ("臘月", Token::MonthName(12)),
+    ("腊月", Token::MonthName(12)),
+    ("초하루", Token::DayName(1)),
+    ("보름", Token::DayName(15)),
+    ("년", Token::Unit(DateField::Year)),
+    ("年", Token::Unit(DateField::Year)),
+    ("월", Token::Unit(DateField::Month)),
+    ("月", Token::Unit(DateField::Month)),
+    ("일", Token::Unit(DateField::Day)),
+    ("日", Token::Unit(DateField::Day)),
+    ("號", Token::Unit(DateField::Day)),
+    ("号", Token::Unit(DateField::Day)),
+];
+
+const UNIT_CHARS: [char; 6] = ['년', '年', '월', '月', '일', '日'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Numeral {
+    Digit(u32),
+    /// 十, 百 and 千, which multiply the digit before them.
+    Multiplier(u32),
+    /// 廿 and 卅, which stand for 20 and 30.
+    Tens(u32),
+}
+
+fn numeral(c: char) -> Option<Numeral> {
+    let numeral = match c {
+        '영' | '공' | '〇' | '零' => Numeral::Digit(0),
+        '일' | '一' => Numeral::Digit(1),
+        '이' | '二' => Numeral::Digit(2),
+        '삼' | '三' => Numeral::Digit(3),
+        '사' | '四' => Numeral::Digit(4),
+        '오' | '五' => Numeral::Digit(5),
+        '육' | '륙' | '六' => Numeral::Digit(6),
+        '칠' | '七' => Numeral::Digit(7),
+        '팔' | '八' => Numeral::Digit(8),
+        '구' | '九' => Numeral::Digit(9),
+        '십' | '十' => Numeral::Multiplier(10),
+        '백' | '百' => Numeral::Multiplier(100),
+        '천' | '千' => Numeral::Multiplier(1000),
+        '廿' => Numeral::Tens(20),
+        '卅' => Numeral::Tens(30),
+        _ => return None,
+    };
+
+    Some(numeral)
+}
+
+/// Evaluates Korean or Hanja numerals: positionally with 十, 百 and 千 (이천이십삼,
+/// 十五), or digit by digit without them (二〇二三).
+fn numeral_value(numerals: &[Numeral]) -> Option<u32> {
+    let digit_by_digit = numerals
+        .iter()
+        .all(|numeral| matches!(numeral, Numeral::Digit(_)));
+
+    if digit_by_digit {
+        return numerals
+            .iter()
+            .try_fold(0u32, |value, numeral| match numeral {
+                Numeral::Digit(digit) => value.checked_mul(10)?.checked_add(*digit),
+                _ => None,
+            });
+    }
+
+    let mut total = 0u32;
+    let mut digit = None;
+
+    for numeral in numerals {
+        match *numeral {
+            Numeral::Digit(d) if digit.is_none() => digit = Some(d),
+            Numeral::Multiplier(m) => {
+                total = total.checked_add(digit.take().unwrap_or(1).checked_mul(m)?)?;
+            }
+            Numeral::Tens(tens) if digit.is_none() => total = total.checked_add(tens)?,
+            _ => return None,
+        }
+    }
+
+    total.checked_add(digit.unwrap_or(0))
+}
+
+fn position(c: char, korean: &[char], chinese: &[char]) -> Option<usize> {
+    korean
+        .iter()
+        .position(|&k| k == c)
+        .or_else(|| chinese.iter().position(|&h| h == c))
+}
+
+struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn error(&self, kind: ParseErrorKind, len: usize) -> ParseError {
+        ParseError::new(kind, self.pos..self.pos + len)
+    }
+
+    /// Returns the next token and its span, or `None` at the end of the input.
+    fn next_token(&mut self) -> Option<Result<(Token, Range<usize>), ParseError>> {
+        let skipped = self
+            .rest()
+            .find(|c: char| !(c.is_whitespace() || "-./,()·".contains(c)))?;
+        self.pos += skipped;
+
+        let start = self.pos;
+        let result = self.lex();
+        if let Ok((_, len)) = result {
+            self.pos += len;
+        }
+
+        Some(result.map(|(token, len)| (token, start..start + len)))
+    }
+
+    /// Lexes the token at the current position, returning it with its length in bytes.
+    fn lex(&self) -> Result<(Token, usize), ParseError> {
+        let rest = self.rest();
+        let mut chars = rest.chars();
+        let first = chars
+            .next()
+            .expect("the lexer stops at the end of the input");
+
+        if first.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let number = rest[..len]
+                .parse()
+                .map_err(|_| self.error(ParseErrorKind::InvalidNumber, len))?;
+
+            return Ok((Token::Number(number), len));
+        }
+
+        if first.is_ascii_alphabetic() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+
+            return if word == "L" || word.eq_ignore_ascii_case("intercalation") {
+                Ok((Token::Leap, len))
+            } else {
+                Err(self.error(ParseErrorKind::UnexpectedToken, len))
+            };
+        }
+
+        if let Some(gapja) = self.lex_gapja(first, chars.next()) {
+            return Ok(gapja);
+        }
+        if let Some(number) = self.lex_numerals()? {
+            return Ok(number);
+        }
+
+        WORDS
+            .iter()
+            .find(|(word, _)| rest.starts_with(word))
+            .map(|&(word, token)| (token, word.len()))
+            .ok_or_else(|| self.error(ParseErrorKind::UnexpectedToken, first.len_utf8()))
+    }
+
+    /// Lexes a stem followed by a branch, both Korean or both Hanja, such as 계묘 or 癸卯.
+    fn lex_gapja(&self, stem: char, branch: Option<char>) -> Option<(Token, usize)> {
+        let branch = branch?;
+        let korean = KOREAN_CHEONGAN.contains(&stem) && KOREAN_GANJI.contains(&branch);
+        let chinese = CHINESE_CHEONGAN.contains(&stem) && CHINESE_GANJI.contains(&branch);
+        if !korean && !chinese {
+            return None;
+        }
+
+        let stem_index = position(stem, &KOREAN_CHEONGAN, &CHINESE_CHEONGAN)?;
+        let branch_index = position(branch, &KOREAN_GANJI, &CHINESE_GANJI)?;
+        if stem_index % 2 != branch_index % 2 {
+            return None;
+        }
+
+        let index = (6 * stem_index + 55 * branch_index) % 60;
+        Some((Token::Gapja(index), stem.len_utf8() + branch.len_utf8()))
+    }
+
+    /// Lexes a run of Korean or Hanja numerals, optionally after 初 (初五).
+    ///
+    /// 일 is both the numeral one and the unit of days, so a run ending in 일 that is
+    /// not followed by another unit ends before that 일: 십오일 is 15 and the unit,
+    /// while 십일월 is November.
+    fn lex_numerals(&self) -> Result<Option<(Token, usize)>, ParseError> {
+        let rest = self.rest();
+        let prefix = if rest.starts_with('初') {
+            '初'.len_utf8()
+        } else {
+            0
+        };
+
+        let mut numerals = Vec::new();
+        let mut len = prefix;
+        for c in rest[prefix..].chars() {
+            let Some(numeral) = numeral(c) else { break };
+            numerals.push(numeral);
+            len += c.len_utf8();
+        }
+
+        let followed_by_unit = rest[len..].starts_with(UNIT_CHARS);
+        if rest[..len].ends_with('일') && !followed_by_unit {
+            numerals.pop();
+            len -= '일'.len_utf8();
+        }
+        if numerals.is_empty() {
+            return Ok(None);
+        }
+
+        let value = numeral_value(&numerals)
+            .ok_or_else(|| self.error(ParseErrorKind::InvalidNumber, len))?;
+        Ok(Some((Token::Number(value), len)))
+    }
+}
+
+enum Year {
+    Number(u32),
+    Gapja(usize),
+}
+
+/// The fields of a date as written, before they are validated.
+#[derive(Default)]
+struct Fields {
+    year: Option<(Year, Range<usize>)>,
+    month: Option<(u32, Range<usize>)>,
+    day: Option<(u32, Range<usize>)>,
+    leap: Option<Range<usize>>,
+    calendar: Option<(Calendar, Range<usize>)>,
+    /// The span of the first element only found in lunar dates.
+    lunar_only: Option<Range<usize>>,
+}
+
+impl Fields {
+    fn read(input: &str) -> Result<Self, ParseError> {
+        let mut tokens = Vec::new();
+        let mut lexer = Lexer { input, pos: 0 };
+        while let Some(token) = lexer.next_token() {
+            tokens.push(token?);
+        }
+
+        let mut fields = Fields::default();
+        let mut next_field = Some(DateField::Year);
+        let mut tokens = tokens.into_iter().peekable();
+
+        while let Some((token, span)) = tokens.next() {
+            let unit = match tokens.peek() {
+                Some(&(Token::Unit(unit), ref unit_span)) => Some((unit, unit_span.end)),
+                _ => None,
+            };
+
+            let field = match token {
+                Token::Number(number) => {
+                    let (field, end) = match unit {
+                        Some((unit, end)) => {
+                            tokens.next();
+                            (unit, end)
+                        }
+                        None => {
+                            let field =
+                                next_field.ok_or_else(|| ParseError::unexpected(span.clone()))?;
+                            (field, span.end)
+                        }
+                    };
+                    fields.set(field, number, span.start..end)?;
+                    field
+                }
+                Token::Gapja(index) => {
+                    let end = match unit {
+                        Some((DateField::Year, end)) => {
+                            tokens.next();
+                            end
+                        }
+                        _ => span.end,
+                    };
+                    fields.mark_lunar(&span);
+                    fields.set_year(Year::Gapja(index), span.start..end)?;
+                    DateField::Year
+                }
+                Token::MonthName(month) => {
+                    fields.mark_lunar(&span);
+                    fields.set(DateField::Month, month, span)?;
+                    DateField::Month
+                }
+                Token::DayName(day) => {
+                    fields.mark_lunar(&span);
+                    fields.set(DateField::Day, day, span)?;
+                    DateField::Day
+                }
+                Token::Leap if fields.leap.is_none() => {
+                    fields.mark_lunar(&span);
+                    fields.leap = Some(span);
+                    continue;
+                }
+                Token::Calendar(calendar) if fields.calendar.is_none() => {
+                    fields.calendar = Some((calendar, span));
+                    continue;
+                }
+                Token::Leap | Token::Calendar(_) | Token::Unit(_) => {
+                    return Err(ParseError::unexpected(span));
+                }
+            };
+
+            next_field = match field {
+                DateField::Year => Some(DateField::Month),
+                DateField::Month => Some(DateField::Day),
+                DateField::Day => None,
+            };
+        }
+
+        Ok(fields)
+    }
+
+    fn mark_lunar(&mut self, span: &Range<usize>) {
+        self.lunar_only.get_or_insert_with(|| span.clone());
+    }
+
+    fn set(&mut self, field: DateField, value: u32, span: Range<usize>) -> Result<(), ParseError> {
+        let slot = match field {
+            DateField::Year => return self.set_year(Year::Number(value), span),
+            DateField::Month => &mut self.month,
+            DateField::Day => &mut self.day,
+        };
+        if slot.is_some() {
+            return Err(ParseError::new(ParseErrorKind::DuplicateField(field), span));
+        }
+
+        *slot = Some((value, span));
+        Ok(())
+    }
+
+    fn set_year(&mut self, year: Year, span: Range<usize>) -> Result<(), ParseError> {
+        if self.year.is_some() {
+            let kind = ParseErrorKind::DuplicateField(DateField::Year);
+            return Err(ParseError::new(kind, span));
+        }
+
+        self.year = Some((year, span));
+        Ok(())
+    }
+
+    /// Returns the span from the first to the last field.
+    fn date_span(&self) -> Range<usize> {
+        let spans = [
+            self.year.as_ref().map(|(_, span)| span),
+            self.month.as_ref().map(|(_, span)| span),
+            self.day.as_ref().map(|(_, span)| span),
+            self.leap.as_ref(),
+        ];
+        let spans = spans.iter().flatten();
+        let start = spans.clone().map(|span| span.start).min().unwrap_or(0);
+        let end = spans.map(|span| span.end).max().unwrap_or(0);
+
+        start..end
+    }
+
+    /// Returns the span of the field a date error is about.
+    fn error_span(&self, err: KlcError) -> Range<usize> {
+        let month = || self.month.as_ref().map(|(_, span)| span.clone());
+        let span = match err {
+            KlcError::InvalidMonth(_) => month(),
+            KlcError::InvalidDay { .. } => self.day.as_ref().map(|(_, span)| span.clone()),
+            KlcError::InvalidLeapMonth { .. } => match (self.leap.clone(), month()) {
+                (Some(leap), Some(month)) => {
+                    Some(leap.start.min(month.start)..leap.end.max(month.end))
+                }
+                (leap, month) => leap.or(month),
+            },
+            _ => None,
+        };
+
+        span.unwrap_or_else(|| self.date_span())
+    }
+}
+
+impl ParseError {
+    pub(super) fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        ParseError { kind, span }
+    }
+
+    fn unexpected(span: Range<usize>) -> Self {
+        ParseError::new(ParseErrorKind::UnexpectedToken, span)
+    }
+
+    /// Returns why the string was rejected.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the offending text in the input.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::UnexpectedToken => f.write_str("unexpected text")?,
+            ParseErrorKind::InvalidNumber => f.write_str("number too large")?,
+            ParseErrorKind::DuplicateField(field) => write!(f, "duplicate {}", field)?,
+            ParseErrorKind::MissingField(field) => write!(f, "missing {}", field)?,
+            ParseErrorKind::ExpectedSolar => f.write_str("expected a solar date")?,
+            ParseErrorKind::ExpectedLunar => f.write_str("expected a lunar date")?,
+            ParseErrorKind::InvalidDate(err) => write!(f, "{}", err)?,
+        }
+
+        write!(f, " at {}..{}", self.span.start, self.span.end)
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::InvalidDate(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DateField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DateField::Year => "year",
+            DateField::Month => "month",
+            DateField::Day => "day",
+        })
+    }
+}
+
+impl ParsedDate {
+    /// Returns the solar date of the same day.
+    pub fn to_solar(&self) -> SolarDate {
+        match self {
+            ParsedDate::Solar(date) => *date,
+            ParsedDate::Lunar(date) => date.to_solar(),
+        }
+    }
+
+    /// Returns the lunar date of the same day.
+    pub fn to_lunar(&self) -> LunarDate {
+        match self {
+            ParsedDate::Solar(date) => date.to_lunar(),
+            ParsedDate::Lunar(date) => *date,
+        }
+    }
+}
+
+impl DateParser {
+    /// Creates a parser that resolves a Gapja year relative to the current year in Korea.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves a Gapja year to the latest year with that Gapja not after `year`.
+    pub fn with_reference_year(mut self, year: i32) -> Self {
+        self.reference_year = Some(year);
+        self
+    }
+
+    /// Parses a solar or lunar date.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] with the span of the text that could not be parsed, or
+    /// of the field that makes the date invalid.
+    pub fn parse(&self, input: &str) -> Result<ParsedDate, ParseError> {
+        self.parse_as(input, None)
+    }
+
+    /// Parses a solar date.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`], of kind [`ParseErrorKind::ExpectedSolar`] if the input
+    /// is marked as a lunar date.
+    pub fn parse_solar(&self, input: &str) -> Result<SolarDate, ParseError> {
+        match self.parse_as(input, Some(Calendar::Solar))? {
+            ParsedDate::Solar(date) => Ok(date),
+            ParsedDate::Lunar(_) => unreachable!("a solar date was requested"),
+        }
+    }
+
+    /// Parses a lunar date.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`], of kind [`ParseErrorKind::ExpectedLunar`] if the input
+    /// is marked as a solar date.
+    pub fn parse_lunar(&self, input: &str) -> Result<LunarDate, ParseError> {
+        match self.parse_as(input, Some(Calendar::Lunar))? {
+            ParsedDate::Lunar(date) => Ok(date),
+            ParsedDate::Solar(_) => unreachable!("a lunar date was requested"),
+        }
+    }
+
+    fn parse_as(&self, input: &str, expected: Option<Calendar>) -> Result<ParsedDate, ParseError> {
+        let fields = Fields::read(input)?;
+
+        let calendar = match (&fields.calendar, expected) {
+            (Some((calendar, span)), Some(expected)) if *calendar != expected => {
+                return Err(ParseError::new(mismatch(expected), span.clone()));
+            }
+            (Some((calendar, _)), _) => *calendar,
+            (None, Some(expected)) => expected,
+            (None, None) if fields.lunar_only.is_some() => Calendar::Lunar,
+            (None, None) => Calendar::Solar,
+        };
+        if let (Calendar::Solar, Some(span)) = (calendar, &fields.lunar_only) {
+            return Err(ParseError::new(ParseErrorKind::ExpectedSolar, span.clone()));
+        }
+
+        let end = input.len()..input.len();
+        let missing = |field| ParseError::new(ParseErrorKind::MissingField(field), end.clone());
+        let (year, year_span) = fields
+            .year
+            .as_ref()
+            .ok_or_else(|| missing(DateField::Year))?;
+        let &(month, _) = fields
+            .month
+            .as_ref()
+            .ok_or_else(|| missing(DateField::Month))?;
+        let &(day, _) = fields.day.as_ref().ok_or_else(|| missing(DateField::Day))?;
+        let invalid =
+            |err| ParseError::new(ParseErrorKind::InvalidDate(err), fields.error_span(err));
+
+        match (calendar, year) {
+            (Calendar::Solar, &Year::Number(year)) => SolarDate::new(year, month, day)
+                .map(ParsedDate::Solar)
+                .map_err(invalid),
+            (Calendar::Lunar, year) => {
+                let year = match *year {
+                    Year::Number(year) => i32::try_from(year).map_err(|_| {
+                        ParseError::new(ParseErrorKind::InvalidNumber, year_span.clone())
+                    })?,
+                    Year::Gapja(index) => {
+                        let reference = self.reference_year.unwrap_or_else(current_year);
+                        reference - (reference - 4 - index as i32).rem_euclid(60)
+                    }
+                };
+
+                LunarDate::new(year, month, day, fields.leap.is_some())
+                    .map(ParsedDate::Lunar)
+                    .map_err(invalid)
+            }
+            (Calendar::Solar, Year::Gapja(_)) => unreachable!("a Gapja year marks a lunar date"),
+        }
+    }
+}
+
+fn mismatch(expected: Calendar) -> ParseErrorKind {
+    match expected {
+        Calendar::Solar => ParseErrorKind::ExpectedSolar,
+        Calendar::Lunar => ParseErrorKind::ExpectedLunar,
+    }
+}
+
+/// Returns the current year in Korea, or the last supported lunar year if the clock is
+/// beyond the supported range.
+fn current_year() -> i32 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+
+    KoreanDateTime::from_unix_timestamp(now)
+        .map_or((KOREAN_LUNAR_MAX_VALUE / 10000) as i32, |now| {
+            now.date().year() as i32
+        })
+}
+
+impl FromStr for SolarDate {
+    type Err = ParseError;
+
+    /// Parses a solar date with [`DateParser::parse_solar`].
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        DateParser::new().parse_solar(s)
+    }
+}
+
+impl FromStr for LunarDate {
+    type Err = ParseError;
+
+    /// Parses a lunar date with [`DateParser::parse_lunar`].
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        DateParser::new().parse_lunar(s)
+    }
+}
+
+impl FromStr for ParsedDate {
+    type Err = ParseError;
+
+    /// Parses a solar or lunar date with [`DateParser::parse`].
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        DateParser::new().parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateField, DateParser, ParseErrorKind, ParsedDate};
+    use crate::{KlcError, LunarDate, SolarDate};
+
+    fn lunar(year: i32, month: u32, day: u32, is_leap_month: bool) -> ParsedDate {
+        ParsedDate::Lunar(LunarDate::new(year, month, day, is_leap_month).unwrap())
+    }
+
+    fn solar(year: u32, month: u32, day: u32) -> ParsedDate {
+        ParsedDate::Solar(SolarDate::new(year, month, day).unwrap())
+    }
+
+    /// Returns the kind and the offending text of a parse error.
+    fn error(input: &str) -> (ParseErrorKind, &str) {
+        let err = DateParser::new()
+            .with_reference_year(2024)
+            .parse(input)
+            .unwrap_err();
+
+        (err.kind(), &input[err.span()])
+    }
+
+    #[test]
+    fn test_lunar_forms() {
+        let parser = DateParser::new().with_reference_year(2024);
+        let leap = lunar(2023, 2, 15, true);
+
+        for input in [
+            "음력 2023년 윤2월 15일",
+            "陰曆 癸卯年 閏二月 十五日",
+            "阴历 2023年 闰2月 15日",
+            "2023-02-15 Intercalation",
+            "2023-L02-15",
+            "음력 이천이십삼년 윤이월 십오일",
+            "2023.윤2.15 (음)",
+            "계묘년 윤2월 보름",
+        ] {
+            assert_eq!(parser.parse(input), Ok(leap), "{}", input);
+        }
+
+        assert_eq!(
+            parser.parse("음력 2023/2/15"),
+            Ok(lunar(2023, 2, 15, false))
+        );
+        assert_eq!(
+            parser.parse("二〇二四年 正月 初一"),
+            Ok(lunar(2024, 1, 1, false))
+        );
+        assert_eq!(
+            parser.parse("甲辰年 正月 初五日"),
+            Ok(lunar(2024, 1, 5, false))
+        );
+        assert_eq!(
+            parser.parse("2023년 동짓달 초하루"),
+            Ok(lunar(2023, 11, 1, false))
+        );
+        assert_eq!(
+            parser.parse("2023년 섣달 廿九日"),
+            Ok(lunar(2023, 12, 29, false))
+        );
+        assert_eq!(
+            parser.parse("음력 십일월 이십일일 2023년"),
+            Ok(lunar(2023, 11, 21, false))
+        );
+    }
+
+    #[test]
+    fn test_korean_day_unit() {
+        let parser = DateParser::new();
+
+        // 일 is both the numeral one and the unit of days
+        assert_eq!(
+            parser.parse("양력 이천이십사년 일월 일일"),
+            Ok(solar(2024, 1, 1))
+        );
+        assert_eq!(
+            parser.parse("양력 2024년 1월 십일일"),
+            Ok(solar(2024, 1, 11))
+        );
+        assert_eq!(
+            parser.parse("양력 2024년 십일월 이십일"),
+            Ok(solar(2024, 11, 20))
+        );
+    }
+
+    #[test]
+    fn test_solar_forms() {
+        let parser = DateParser::new();
+        let date = solar(2022, 7, 10);
+
+        for input in [
+            "양력 2022.7.10",
+            "2022-07-10",
+            "2022년 7월 10일",
+            "陽曆 2022年7月10日",
+            "2022/07/10.",
+        ] {
+            assert_eq!(parser.parse(input), Ok(date), "{}", input);
+        }
+        assert_eq!("2022-07-10".parse::<SolarDate>(), Ok(date.to_solar()));
+    }
+
+    #[test]
+    fn test_gapja_year_reference() {
+        let date = "계묘년 1월 1일";
+
+        let parser = DateParser::new().with_reference_year(2023);
+        assert_eq!(parser.parse(date), Ok(lunar(2023, 1, 1, false)));
+
+        let parser = DateParser::new().with_reference_year(2022);
+        assert_eq!(parser.parse(date), Ok(lunar(1963, 1, 1, false)));
+    }
+
+    #[test]
+    fn test_from_str_calendar() {
+        let leap = LunarDate::new(2023, 2, 15, true).unwrap();
+
+        assert_eq!("2023-02-15 Intercalation".parse(), Ok(leap));
+        assert_eq!(
+            "2023-02-15".parse(),
+            Ok(LunarDate::new(2023, 2, 15, false).unwrap())
+        );
+
+        let err = "양력 2023-02-15".parse::<LunarDate>().unwrap_err();
+        assert_eq!(
+            (err.kind(), err.span()),
+            (ParseErrorKind::ExpectedLunar, 0..6)
+        );
+
+        let err = "2023-윤02-15".parse::<SolarDate>().unwrap_err();
+        assert_eq!(
+            (err.kind(), err.span()),
+            (ParseErrorKind::ExpectedSolar, 5..8)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            error("2023-02-15 Intercalatio"),
+            (ParseErrorKind::UnexpectedToken, "Intercalatio")
+        );
+        assert_eq!(
+            error("2023년 2월 15일 ★"),
+            (ParseErrorKind::UnexpectedToken, "★")
+        );
+        assert_eq!(
+            error("2023-02-15-01"),
+            (ParseErrorKind::UnexpectedToken, "01")
+        );
+        assert_eq!(
+            error("2023년 월 15일"),
+            (ParseErrorKind::UnexpectedToken, "월")
+        );
+        assert_eq!(
+            error("99999999999-1-1"),
+            (ParseErrorKind::InvalidNumber, "99999999999")
+        );
+        assert_eq!(
+            error("2023년 2월 3월 15일"),
+            (ParseErrorKind::DuplicateField(DateField::Month), "3월")
+        );
+        assert_eq!(
+            error("2023년 2월"),
+            (ParseErrorKind::MissingField(DateField::Day), "")
+        );
+        assert_eq!(
+            error(""),
+            (ParseErrorKind::MissingField(DateField::Year), "")
+        );
+        assert_eq!(
+            error("양력 2023년 윤2월 1일"),
+            (ParseErrorKind::ExpectedSolar, "윤")
+        );
+        assert_eq!(
+            error("2023-13-01"),
+            (
+                ParseErrorKind::InvalidDate(KlcError::InvalidMonth(13)),
+                "13"
+            )
+        );
+        assert_eq!(
+            error("2023년 2월 30일"),
+            (
+                ParseErrorKind::InvalidDate(KlcError::InvalidDay {
+                    day: 30,
+                    days_in_month: 28
+                }),
+                "30일"
+            )
+        );
+        assert_eq!(
+            error("2023-1-4294967295"),
+            (
+                ParseErrorKind::InvalidDate(KlcError::InvalidDay {
+                    day: u32::MAX,
+                    days_in_month: 31
+                }),
+                "4294967295"
+            )
+        );
+        assert_eq!(
+            error("1582. 10. 10."),
+            (
+                ParseErrorKind::InvalidDate(KlcError::GregorianGap { day: 10 }),
+                "1582. 10. 10"
+            )
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        let err = "2023-02-15 Intercalatio".parse::<LunarDate>().unwrap_err();
+
+        assert_eq!(err.to_string(), "unexpected text at 11..23");
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+
+use super::locale::PillarUnit;
+use super::solar_terms::{self, KST_OFFSET_DAYS};
+use super::{KOREAN_GANJI, KlcError, Locale, Pillar, SolarDate, gapja};
+
+/// Selects which day the hour from 23:00 to 24:00 belongs to.
+///
+/// 자시 (子時) runs from 23:00 to 01:00 and always takes the stem of the 자시 that
+/// begins the next day. The conventions differ only in the day pillar of 23:00-24:00.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JasiConvention {
+    /// The day changes at 23:00, so 23:00-24:00 takes the next day's pillar.
+    #[default]
+    NextDay,
+    /// The day changes at midnight: 23:00-24:00 (야자시) keeps the current day's pillar,
+    /// and 00:00-01:00 (조자시) takes the next day's pillar.
+    CurrentDay,
+}
+
+/// The Four Pillars (사주팔자) of a solar date and local time in KST.
+///
+/// The year pillar changes at the instant of 입춘 and the month pillar at the instant of
+/// each sectional term (절), to the minute, as in [`GapjaConvention::SolarTerm`](crate::GapjaConvention::SolarTerm).
+/// The hour stem follows the day stem (갑기일 갑자시, 을경일 병자시, 병신일 무자시,
+/// 정임일 경자시, 무계일 임자시).
+///
+/// # Example
+/// ```
+/// use rs_klc::{FourPillars, Locale, SolarDate};
+///
+/// let date = SolarDate::new(2024, 2, 4).unwrap(); // 입춘 at 17:27 KST
+///
+/// let before = FourPillars::new(date, 17, 20).unwrap();
+/// assert_eq!(before.to_string(), "계묘년 을축월 무술일 신유시");
+///
+/// let after = FourPillars::new(date, 17, 30).unwrap();
+/// assert_eq!(after.to_string(), "갑진년 병인월 무술일 신유시");
+/// assert_eq!(after.chinese_string(), "甲辰年 丙寅月 戊戌日 辛酉時");
+/// assert_eq!(
+///     after.to_string_in(Locale::English),
+///     "Gapjin-nyeon Byeongin-wol Musul-il Sinyu-si"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FourPillars {
+    year: Pillar,
+    month: Pillar,
+    day: Pillar,
+    hour: Pillar,
+}
+
+impl FourPillars {
+    /// Computes the Four Pillars of a solar date and local time (KST), using
+    /// [`JasiConvention::NextDay`].
+    ///
+    /// # Errors
+    /// Returns [`KlcError::InvalidTime`] if `hour` is not within 0-23 or `minute` within 0-59.
+    pub fn new(date: SolarDate, hour: u32, minute: u32) -> Result<Self, KlcError> {
+        Self::with_jasi_convention(date, hour, minute, JasiConvention::default())
+    }
+
+    /// Computes the Four Pillars of a solar date and local time (KST), choosing which
+    /// day 23:00-24:00 belongs to.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::InvalidTime`] if `hour` is not within 0-23 or `minute` within 0-59.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{FourPillars, JasiConvention, SolarDate};
+    ///
+    /// let date = SolarDate::new(2024, 2, 4).unwrap();
+    ///
+    /// let next_day = FourPillars::with_jasi_convention(date, 23, 30, JasiConvention::NextDay);
+    /// assert_eq!(next_day.unwrap().to_string(), "갑진년 병인월 기해일 갑자시");
+    ///
+    /// let current_day = FourPillars::with_jasi_convention(date, 23, 30, JasiConvention::CurrentDay);
+    /// assert_eq!(current_day.unwrap().to_string(), "갑진년 병인월 무술일 갑자시");
+    /// ```
+    pub fn with_jasi_convention(
+        date: SolarDate,
+        hour: u32,
+        minute: u32,
+        convention: JasiConvention,
+    ) -> Result<Self, KlcError> {
+        if hour > 23 || minute > 59 {
+            return Err(KlcError::InvalidTime { hour, minute });
+        }
+
+        let jdn = date.julian_day_number() as i64;
+        let minute_of_day = hour * 60 + minute;
+        let instant = jdn as f64 - 0.5 + minute_of_day as f64 / 1440.0 - KST_OFFSET_DAYS;
+
+        Ok(Self::from_local_time(
+            jdn,
+            minute_of_day,
+            instant,
+            convention,
+        ))
+    }
+
+    /// Creates the Four Pillars from their pillars.
+    #[cfg(feature = "serde")]
+    pub(super) fn from_pillars(year: Pillar, month: Pillar, day: Pillar, hour: Pillar) -> Self {
+        FourPillars {
+            year,
+            month,
+            day,
+            hour,
+        }
+    }
+
+    /// Computes the Four Pillars of a local day (Julian Day Number) and minute of day.
+    /// The year and month pillars are taken at `instant` (Julian Day, UT), so the local
+    /// time may use any offset from UTC.
+    pub(super) fn from_local_time(
+        jdn: i64,
+        minute_of_day: u32,
+        instant: f64,
+        convention: JasiConvention,
+    ) -> Self {
+        let hour = minute_of_day / 60;
+        let [year_stem, year_branch, month_stem, month_branch] =
+            gapja::solar_term_pillars(solar_terms::solar_term_at(instant));
+
+        // 자시 from 23:00 already belongs to the next day's hour cycle
+        let hour_cycle_jdn = if hour == 23 { jdn + 1 } else { jdn };
+        let day = match convention {
+            JasiConvention::NextDay => Pillar::from_julian_day_number(hour_cycle_jdn),
+            JasiConvention::CurrentDay => Pillar::from_julian_day_number(jdn),
+        };
+
+        let hour_branch = (hour as usize).div_ceil(2) % KOREAN_GANJI.len();
+        let hour_cycle_stem = Pillar::from_julian_day_number(hour_cycle_jdn).stem_index();
+        let hour_stem = (hour_cycle_stem % 5) * 2 + hour_branch;
+
+        FourPillars {
+            year: Pillar::from_indices(year_stem, year_branch),
+            month: Pillar::from_indices(month_stem, month_branch),
+            day,
+            hour: Pillar::from_indices(hour_stem, hour_branch),
+        }
+    }
+
+    /// Returns the year pillar (연주).
+    pub fn year(&self) -> Pillar {
+        self.year
+    }
+
+    /// Returns the month pillar (월주).
+    pub fn month(&self) -> Pillar {
+        self.month
+    }
+
+    /// Returns the day pillar (일주).
+    pub fn day(&self) -> Pillar {
+        self.day
+    }
+
+    /// Returns the hour pillar (시주).
+    pub fn hour(&self) -> Pillar {
+        self.hour
+    }
+
+    /// Returns the Chinese string of the Four Pillars.
+    /// Format: "[Year]年 [Month]月 [Day]日 [Hour]時" (e.g., "甲辰年 丙寅月 戊戌日 辛酉時").
+    pub fn chinese_string(&self) -> String {
+        self.to_string_in(Locale::Hanja)
+    }
+
+    /// Returns the string of the Four Pillars in a locale, such as
+    /// "Gapjin-nyeon Byeongin-wol Musul-il Sinyu-si" in [`Locale::English`].
+    pub fn to_string_in(&self, locale: Locale) -> String {
+        let mut string = String::new();
+        self.write_in(&mut string, locale)
+            .expect("writing to a String cannot fail");
+
+        string
+    }
+
+    fn write_in(&self, f: &mut impl fmt::Write, locale: Locale) -> fmt::Result {
+        let pillars = [
+            (self.year, PillarUnit::Year),
+            (self.month, PillarUnit::Month),
+            (self.day, PillarUnit::Day),
+            (self.hour, PillarUnit::Hour),
+        ];
+
+        for (i, (pillar, unit)) in pillars.into_iter().enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+            pillar.write_with_unit(f, locale, unit)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for FourPillars {
+    /// Formats the Four Pillars in Korean.
+    /// Format: "[Year]년 [Month]월 [Day]일 [Hour]시" (e.g., "갑진년 병인월 무술일 신유시").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_in(f, Locale::Korean)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FourPillars, JasiConvention};
+    use crate::{KlcError, LunarSolarConverter, SolarDate};
+
+    #[test]
+    fn test_hour_pillars_of_a_day() {
+        // 갑자일 (2022-07-10): 갑자시, 을축시, 병인시 … 을해시, then 병자시 at 23:00
+        let date = SolarDate::new(2022, 7, 10).unwrap();
+        let names: Vec<String> = (0..24)
+            .step_by(2)
+            .chain([1, 23])
+            .map(|hour| FourPillars::new(date, hour, 0).unwrap().hour().to_string())
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "갑자", "을축", "병인", "정묘", "무진", "기사", "경오", "신미", "임신", "계유",
+                "갑술", "을해", "을축", "병자"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_day_pillar_matches_converter() {
+        let mut converter = LunarSolarConverter::new();
+
+        for (year, month, day) in [(1391, 2, 5), (1582, 10, 4), (1582, 10, 15), (2200, 12, 31)] {
+            converter.set_solar_date(year, month, day);
+            let gapja = converter.get_gapja_string();
+            let pillars = FourPillars::new(SolarDate::new(year, month, day).unwrap(), 12, 0);
+
+            assert!(gapja.ends_with(&format!("{}일", pillars.unwrap().day())));
+        }
+    }
+
+    #[test]
+    fn test_jasi_convention() {
+        let date = SolarDate::new(2022, 7, 10).unwrap(); // 갑자일
+        let next_day = FourPillars::with_jasi_convention(date, 23, 0, JasiConvention::NextDay);
+        let current_day =
+            FourPillars::with_jasi_convention(date, 23, 59, JasiConvention::CurrentDay);
+        let early = FourPillars::with_jasi_convention(date, 0, 30, JasiConvention::CurrentDay);
+
+        assert_eq!(next_day.unwrap().day().to_string(), "을축");
+        assert_eq!(current_day.unwrap().day().to_string(), "갑자");
+        assert_eq!(current_day.unwrap().hour().to_string(), "병자");
+        assert_eq!(early.unwrap().day().to_string(), "갑자");
+        assert_eq!(early.unwrap().hour().to_string(), "갑자");
+    }
+
+    #[test]
+    fn test_month_pillar_changes_at_solar_term() {
+        // 경칩 2024 is on 03-05 at 11:23 KST
+        let date = SolarDate::new(2024, 3, 5).unwrap();
+
+        assert_eq!(
+            FourPillars::new(date, 11, 0).unwrap().month().to_string(),
+            "병인"
+        );
+        assert_eq!(
+            FourPillars::new(date, 11, 30).unwrap().month().to_string(),
+            "정묘"
+        );
+    }
+
+    #[test]
+    fn test_year_pillar_after_julian_sohan() {
+        // The Julian 소한 of 1400 falls in December, within 경진년
+        let date = SolarDate::new(1400, 12, 31).unwrap();
+        let pillars = FourPillars::new(date, 12, 0).unwrap();
+
+        assert_eq!(pillars.year().to_string(), "경진");
+        assert_eq!(pillars.month().to_string(), "기축");
+    }
+
+    #[test]
+    fn test_invalid_time() {
+        let date = SolarDate::new(2024, 2, 4).unwrap();
+
+        assert_eq!(
+            FourPillars::new(date, 24, 0),
+            Err(KlcError::InvalidTime {
+                hour: 24,
+                minute: 0
+            })
+        );
+        assert!(FourPillars::new(date, 12, 60).is_err());
+    }
+}
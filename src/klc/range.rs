@@ -0,0 +1,430 @@
+use super::calendar::{Korean, LunisolarCalendar};
+use super::{Gapja, LunarDate, LunarSolarConverter, Pillar, SolarDate, gapja};
+
+/// The step between the dates of a [`DateRange`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeStep {
+    /// One day.
+    #[default]
+    Day,
+    /// One lunar month, counting intercalary months (윤달) as months of their own. The
+    /// lunar day of the start is kept, or the last day of a shorter month.
+    LunarMonth,
+    /// One lunar year. The lunar month and day of the start are kept; an intercalary
+    /// month falls back to the regular month in years without it, and day 30 to the
+    /// last day of a shorter month.
+    LunarYear,
+}
+
+/// A date of a [`DateRange`] in both calendars, with its Gapja (간지).
+///
+/// A pair also converts from a [`SolarDate`] or a [`LunarDate`].
+///
+/// The year and month pillars follow the
+/// [`GapjaConvention::LunarCalendar`](crate::GapjaConvention::LunarCalendar) convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DatePair {
+    /// The solar date.
+    pub solar: SolarDate,
+    /// The lunar date.
+    pub lunar: LunarDate,
+    /// The year pillar (e.g. 갑진).
+    pub year_gapja: Pillar,
+    /// The month pillar.
+    pub month_gapja: Pillar,
+    /// The day pillar.
+    pub day_gapja: Pillar,
+}
+
+/// An iterator over the dates between two bounds, inclusive, in either direction.
+///
+/// Each step is a table lookup from the previous position, so iterating over a range
+/// costs the same per date whatever its length.
+///
+/// # Example
+/// ```
+/// use rs_klc::{DateRange, LunarDate, RangeStep, SolarDate};
+///
+/// // The 1582 Gregorian reform skips from 10-04 to 10-15
+/// let start = SolarDate::new(1582, 10, 3).unwrap();
+/// let end = SolarDate::new(1582, 10, 16).unwrap();
+/// let days: Vec<String> = DateRange::solar(start, end)
+///     .map(|date| date.solar.to_string())
+///     .collect();
+/// assert_eq!(days, ["1582-10-03", "1582-10-04", "1582-10-15", "1582-10-16"]);
+///
+/// // 2023 has 윤2월
+/// let start = LunarDate::new(2023, 2, 30, false).unwrap();
+/// let end = LunarDate::new(2023, 4, 1, false).unwrap();
+/// let months: Vec<String> = DateRange::lunar(start, end)
+///     .with_step(RangeStep::LunarMonth)
+///     .map(|date| date.lunar.to_string())
+///     .collect();
+/// assert_eq!(
+///     months,
+///     ["2023-02-30", "2023-02-29 Intercalation", "2023-03-30"]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateRange {
+    start: LunarDate,
+    end: LunarDate,
+    step: RangeStep,
+    front: Position,
+    back: Position,
+    len: usize,
+}
+
+/// A position in a range: a day count for [`RangeStep::Day`], the lunar year and the
+/// month slot within it for [`RangeStep::LunarMonth`], or the lunar year.
+#[derive(Debug, Clone, Copy)]
+enum Position {
+    Day(u32),
+    Month { year: i32, slot: usize },
+    Year(i32),
+}
+
+impl DateRange {
+    /// Creates a daily range between two solar dates, inclusive.
+    ///
+    /// The range is empty if `end` is before `start`.
+    pub fn solar(start: SolarDate, end: SolarDate) -> Self {
+        DateRange::lunar(start.to_lunar(), end.to_lunar())
+    }
+
+    /// Creates a daily range between two lunar dates, inclusive.
+    ///
+    /// The range is empty if `end` is before `start`.
+    pub fn lunar(start: LunarDate, end: LunarDate) -> Self {
+        DateRange::with_bounds(start, end, RangeStep::Day)
+    }
+
+    /// Sets the step between dates, restarting the range from its bounds.
+    pub fn with_step(self, step: RangeStep) -> Self {
+        DateRange::with_bounds(self.start, self.end, step)
+    }
+
+    /// Returns the step between dates.
+    pub fn step(&self) -> RangeStep {
+        self.step
+    }
+
+    fn with_bounds(start: LunarDate, end: LunarDate, step: RangeStep) -> Self {
+        let (front, back, len) = match step {
+            _ if end < start => (Position::Day(0), Position::Day(0), 0),
+            RangeStep::Day => {
+                let (first, last) = (start.abs_days(), end.abs_days());
+                let len = if last >= first { last - first + 1 } else { 0 };
+
+                (Position::Day(first), Position::Day(last), len as usize)
+            }
+            RangeStep::LunarMonth => {
+                let first =
+                    Korean::tables().month_slot(start.year(), start.month(), start.is_leap_month());
+                let slot =
+                    Korean::tables().month_slot(end.year(), end.month(), end.is_leap_month());
+                let (month, is_leap_month) = Korean::tables().month_by_slot(end.year(), slot);
+                let days = LunarSolarConverter::get_lunar_days(end.year(), month, is_leap_month);
+
+                // The month of the end is left out when the start's day falls after it
+                let (last_year, last) = if start.day().min(days) > end.day() {
+                    match slot.checked_sub(1) {
+                        Some(slot) => (end.year(), slot),
+                        None => (
+                            end.year() - 1,
+                            Korean::tables().month_count(end.year() - 1) - 1,
+                        ),
+                    }
+                } else {
+                    (end.year(), slot)
+                };
+
+                // Months from the start of the first year to the month after the last
+                let months: usize = (start.year()..last_year)
+                    .map(|year| Korean::tables().month_count(year))
+                    .sum();
+                let len = (months + last + 1).saturating_sub(first);
+
+                let back = Position::Month {
+                    year: last_year,
+                    slot: last,
+                };
+                let front = Position::Month {
+                    year: start.year(),
+                    slot: first,
+                };
+
+                (front, back, len)
+            }
+            RangeStep::LunarYear => {
+                let mut last = end.year();
+                let (year, month, is_leap_month, day) = year_date(start, last);
+                if (year, month, is_leap_month, day)
+                    > (end.year(), end.month(), end.is_leap_month(), end.day())
+                {
+                    last -= 1;
+                }
+                let len = (last - start.year() + 1).max(0) as usize;
+
+                (Position::Year(start.year()), Position::Year(last), len)
+            }
+        };
+
+        DateRange {
+            start,
+            end,
+            step,
+            front,
+            back,
+            len,
+        }
+    }
+
+    /// Returns the date at a position.
+    fn date_at(&self, position: Position) -> DatePair {
+        let lunar = match position {
+            Position::Day(abs_days) => LunarDate::from_abs_days(abs_days),
+            Position::Month { year, slot } => {
+                let (month, is_leap_month) = Korean::tables().month_by_slot(year, slot);
+                let days = LunarSolarConverter::get_lunar_days(year, month, is_leap_month);
+
+                LunarDate::new(year, month, self.start.day().min(days), is_leap_month)
+                    .expect("a month of the range has a valid date")
+            }
+            Position::Year(year) => {
+                let (year, month, is_leap_month, day) = year_date(self.start, year);
+
+                LunarDate::new(year, month, day, is_leap_month)
+                    .expect("a year of the range has a valid date")
+            }
+        };
+
+        DatePair::from(lunar)
+    }
+}
+
+impl DatePair {
+    /// Returns the year, month and day pillars as a [`Gapja`].
+    pub fn gapja(&self) -> Gapja {
+        Gapja {
+            year: self.year_gapja,
+            month: self.month_gapja,
+            day: self.day_gapja,
+        }
+    }
+}
+
+impl From<LunarDate> for DatePair {
+    fn from(lunar: LunarDate) -> Self {
+        let solar = SolarDate::from_abs_days(lunar.abs_days());
+        let [year_stem, year_branch, month_stem, month_branch] =
+            gapja::lunar_calendar_pillars(lunar.year(), lunar.month());
+
+        DatePair {
+            solar,
+            lunar,
+            year_gapja: Pillar::from_indices(year_stem, year_branch),
+            month_gapja: Pillar::from_indices(month_stem, month_branch),
+            day_gapja: Pillar::from_julian_day_number(solar.julian_day_number() as i64),
+        }
+    }
+}
+
+impl From<SolarDate> for DatePair {
+    fn from(solar: SolarDate) -> Self {
+        DatePair::from(solar.to_lunar())
+    }
+}
+
+impl Position {
+    fn next(self) -> Self {
+        match self {
+            Position::Day(abs_days) => Position::Day(abs_days + 1),
+            Position::Month { year, slot } if slot + 1 < Korean::tables().month_count(year) => {
+                Position::Month {
+                    year,
+                    slot: slot + 1,
+                }
+            }
+            Position::Month { year, .. } => Position::Month {
+                year: year + 1,
+                slot: 0,
+            },
+            Position::Year(year) => Position::Year(year + 1),
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            Position::Day(abs_days) => Position::Day(abs_days - 1),
+            Position::Month { year, slot: 0 } => Position::Month {
+                year: year - 1,
+                slot: Korean::tables().month_count(year - 1) - 1,
+            },
+            Position::Month { year, slot } => Position::Month {
+                year,
+                slot: slot - 1,
+            },
+            Position::Year(year) => Position::Year(year - 1),
+        }
+    }
+}
+
+/// Returns the date `(year, month, is_leap_month, day)` of the lunar year step of
+/// `start` in a year.
+fn year_date(start: LunarDate, year: i32) -> (i32, u32, bool, u32) {
+    let is_leap_month = start.is_leap_month()
+        && LunarSolarConverter::get_lunar_intercalary_month(year) == Some(start.month());
+    let days = LunarSolarConverter::get_lunar_days(year, start.month(), is_leap_month);
+
+    (year, start.month(), is_leap_month, start.day().min(days))
+}
+
+impl Iterator for DateRange {
+    type Item = DatePair;
+
+    fn next(&mut self) -> Option<DatePair> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let date = self.date_at(self.front);
+        self.len -= 1;
+        if self.len > 0 {
+            self.front = self.front.next();
+        }
+
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<DatePair> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let date = self.date_at(self.back);
+        self.len -= 1;
+        if self.len > 0 {
+            self.back = self.back.previous();
+        }
+
+        Some(date)
+    }
+}
+
+impl ExactSizeIterator for DateRange {}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateRange, RangeStep};
+    use crate::{LunarDate, LunarSolarConverter, SolarDate};
+
+    fn lunar(year: i32, month: u32, day: u32, is_leap_month: bool) -> LunarDate {
+        LunarDate::new(year, month, day, is_leap_month).unwrap()
+    }
+
+    #[test]
+    fn test_daily_range_matches_converter() {
+        let start = SolarDate::new(2023, 1, 1).unwrap();
+        let end = SolarDate::new(2024, 12, 31).unwrap();
+        let range = DateRange::solar(start, end);
+        assert_eq!(range.len(), 731);
+
+        let mut converter = LunarSolarConverter::new();
+        for date in range {
+            converter.set_solar_date(date.solar.year(), date.solar.month(), date.solar.day());
+
+            assert_eq!(date.lunar.to_string(), converter.get_lunar_iso_format());
+            assert_eq!(
+                format!(
+                    "{}년 {}월 {}일",
+                    date.year_gapja, date.month_gapja, date.day_gapja
+                ),
+                converter.get_gapja_string().trim_end_matches(" (윤월)")
+            );
+        }
+    }
+
+    #[test]
+    fn test_double_ended() {
+        let start = SolarDate::new(2024, 2, 8).unwrap();
+        let end = SolarDate::new(2024, 2, 12).unwrap();
+        let mut range = DateRange::solar(start, end);
+
+        assert_eq!(range.next_back().unwrap().solar.to_string(), "2024-02-12");
+        assert_eq!(range.next().unwrap().solar.to_string(), "2024-02-08");
+        assert_eq!(range.len(), 3);
+
+        let rest: Vec<String> = range.rev().map(|date| date.lunar.to_string()).collect();
+        assert_eq!(rest, ["2024-01-02", "2024-01-01", "2023-12-30"]);
+    }
+
+    #[test]
+    fn test_empty_range() {
+        let start = SolarDate::new(2024, 2, 8).unwrap();
+        let end = SolarDate::new(2024, 2, 7).unwrap();
+
+        for step in [RangeStep::Day, RangeStep::LunarMonth, RangeStep::LunarYear] {
+            let mut range = DateRange::solar(start, end).with_step(step);
+
+            assert_eq!(range.len(), 0);
+            assert_eq!(range.next(), None);
+        }
+
+        // The end is in an earlier year, on a later month
+        let range = DateRange::lunar(lunar(2024, 1, 1, false), lunar(2023, 5, 1, false))
+            .with_step(RangeStep::LunarMonth);
+        assert_eq!(range.len(), 0);
+    }
+
+    #[test]
+    fn test_monthly_range() {
+        // The end falls before day 15 of its month, which is left out
+        let range = DateRange::lunar(lunar(2022, 12, 15, false), lunar(2023, 3, 14, false))
+            .with_step(RangeStep::LunarMonth);
+        assert_eq!(range.len(), 4);
+
+        let months: Vec<String> = range.rev().map(|date| date.lunar.to_string()).collect();
+        assert_eq!(
+            months,
+            [
+                "2023-02-15 Intercalation",
+                "2023-02-15",
+                "2023-01-15",
+                "2022-12-15"
+            ]
+        );
+
+        // Leaving out 1월 of the end year steps back to 12월 of the year before
+        let range = DateRange::lunar(lunar(2023, 12, 15, false), lunar(2024, 1, 14, false))
+            .with_step(RangeStep::LunarMonth);
+        let months: Vec<String> = range.rev().map(|date| date.lunar.to_string()).collect();
+        assert_eq!(months, ["2023-12-15"]);
+    }
+
+    #[test]
+    fn test_yearly_range() {
+        // 2월 2025 has 29 days
+        let range = DateRange::lunar(lunar(2023, 2, 30, false), lunar(2025, 2, 29, false))
+            .with_step(RangeStep::LunarYear);
+        let years: Vec<String> = range.map(|date| date.lunar.to_string()).collect();
+        assert_eq!(years, ["2023-02-30", "2024-02-30", "2025-02-29"]);
+
+        // 윤2월 exists in 2023 and 2042 only
+        let range = DateRange::lunar(lunar(2023, 2, 1, true), lunar(2042, 12, 1, false))
+            .with_step(RangeStep::LunarYear);
+        assert_eq!(range.len(), 20);
+
+        let years: Vec<String> = range
+            .filter(|date| date.lunar.is_leap_month())
+            .map(|date| date.solar.to_string())
+            .collect();
+        assert_eq!(years, ["2023-03-22", "2042-03-22"]);
+    }
+}
@@ -0,0 +1,396 @@
+//! Traditional seasonal days (세시풍속).
+//!
+//! Lunar-fixed days fall on the regular (non-leap) month. Days derived from the solar
+//! terms count day stems and branches with the same day Gapja as
+//! [`LunarSolarConverter::get_gapja_string`](crate::LunarSolarConverter::get_gapja_string),
+//! and the day of the solar term itself is counted.
+//!
+//! 납일 follows the Joseon rule by default: the third 미 (未) day from 동지, as 미 is the
+//! tomb (墓) of Wood, the element of the dynasty (木德). This is the day of Korean
+//! almanacs, but it ignores the year. [`NapilRule::YearElement`] takes the tomb of the
+//! element of the year's stem instead.
+
+use super::{
+    EarthlyBranch, Element, HeavenlyStem, KlcError, Locale, LunarDate, LunarSolarConverter,
+    SolarDate, SolarTerm,
+};
+
+const SEASONAL_DAY_MIN_YEAR: u32 = 1392;
+const SEASONAL_DAY_MAX_YEAR: u32 = 2200;
+
+/// Stem of 경 (庚) days, used for 삼복.
+const GYEONG_CHEONGAN: usize = 6;
+
+/// Branch of 미 (未) days, used for 납일 under the Joseon (木德) rule.
+const MI_GANJI: usize = 7;
+
+/// How the 납일 (臘日) after 동지 is chosen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NapilRule {
+    /// The third 미 (未) day, after the Wood element of the Joseon dynasty. This is the
+    /// default and the day of Korean almanacs.
+    #[default]
+    Joseon,
+    /// The third day whose branch is the tomb of the element of the stem of the year of
+    /// 동지: 미 for Wood, 술 (戌) for Fire and Earth, 축 (丑) for Metal and 진 (辰) for
+    /// Water.
+    YearElement,
+}
+
+impl NapilRule {
+    /// Returns the branch of the 납일 that follows 동지 of a solar year.
+    fn branch(self, dongji_year: u32) -> usize {
+        let element = match self {
+            NapilRule::Joseon => return MI_GANJI,
+            NapilRule::YearElement => {
+                HeavenlyStem::from_index((dongji_year as usize + 6) % 10).element()
+            }
+        };
+
+        match element {
+            Element::Wood => MI_GANJI,
+            Element::Fire | Element::Earth => EarthlyBranch::Sul.index(),
+            Element::Metal => EarthlyBranch::Chuk.index(),
+            Element::Water => EarthlyBranch::Jin.index(),
+        }
+    }
+}
+
+/// Lunar-fixed seasonal days as (month, day, Korean name, English name).
+const LUNAR_SEASONAL_DAYS: [(u32, u32, &str, &str); 7] = [
+    (1, 15, "정월대보름", "Jeongwol Daeboreum"),
+    (3, 3, "삼짇날", "Samjinnal"),
+    (5, 5, "단오", "Dano"),
+    (6, 15, "유두", "Yudu"),
+    (7, 7, "칠석", "Chilseok"),
+    (7, 15, "백중", "Baekjung"),
+    (9, 9, "중양절", "Jungyangjeol"),
+];
+
+/// Seasonal day names as (Korean, Hanja, Japanese, Vietnamese).
+const SEASONAL_DAY_NAMES: [(&str, &str, &str, &str); 13] = [
+    ("정월대보름", "上元", "上元", "Tết Nguyên tiêu"),
+    ("삼짇날", "上巳", "上巳", "Tết Thượng tỵ"),
+    ("단오", "端午", "端午", "Tết Đoan ngọ"),
+    ("유두", "流頭", "流頭", "Tết Lưu đầu"),
+    ("칠석", "七夕", "七夕", "Thất tịch"),
+    ("백중", "百中", "百中", "Tết Trung nguyên"),
+    ("중양절", "重陽節", "重陽", "Tết Trùng cửu"),
+    ("한식", "寒食", "寒食", "Tết Hàn thực"),
+    ("초복", "初伏", "初伏", "Sơ phục"),
+    ("중복", "中伏", "中伏", "Trung phục"),
+    ("말복", "末伏", "末伏", "Mạt phục"),
+    ("동지", "冬至", "冬至", "Đông chí"),
+    ("납일", "臘日", "臘日", "Lạp nhật"),
+];
+
+/// How the date of a seasonal day is determined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeasonalDayKind {
+    /// Fixed on a lunar date (e.g. 단오 on 5/5).
+    Lunar,
+    /// Derived from a solar term (e.g. 초복 from 하지 and the 경 days).
+    SolarTerm,
+}
+
+/// A traditional seasonal day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeasonalDay {
+    /// The solar date of the day.
+    pub date: SolarDate,
+    /// The Korean name (e.g. "단오").
+    pub name_ko: &'static str,
+    /// The romanized name (e.g. "Dano").
+    pub name_en: &'static str,
+    /// How the date is determined.
+    pub kind: SeasonalDayKind,
+}
+
+impl SeasonalDay {
+    /// Returns the name of the day in a locale (e.g. "단오", "端午", "Tết Đoan ngọ").
+    pub fn name_in(&self, locale: Locale) -> &'static str {
+        let (_, hanja, japanese, vietnamese) = SEASONAL_DAY_NAMES
+            .iter()
+            .find(|names| names.0 == self.name_ko)
+            .expect("every seasonal day has localized names");
+
+        match locale {
+            Locale::Korean => self.name_ko,
+            Locale::Hanja => hanja,
+            Locale::English => self.name_en,
+            Locale::Japanese => japanese,
+            Locale::Vietnamese => vietnamese,
+        }
+    }
+}
+
+/// Returns the traditional seasonal days of a solar year in chronological order.
+///
+/// * 정월대보름 (1/15), 삼짇날 (3/3), 단오 (5/5), 유두 (6/15), 칠석 (7/7), 백중 (7/15)
+///   and 중양절 (9/9) in the lunar calendar.
+/// * 한식: 105 days after 동지 of the previous year.
+/// * 초복 and 중복: the third and fourth 경 (庚) days from 하지; 말복: the first 경 day
+///   from 입추.
+/// * 동지.
+/// * 납일: the third 미 (未) day from 동지, under [`NapilRule::Joseon`]. It falls in late
+///   December or January, so a year lists the 납일 that falls within it.
+///
+/// # Errors
+/// Returns [`KlcError::OutOfRange`] if the year is outside 1392-2200.
+///
+/// # Example
+/// ```
+/// use rs_klc::seasonal_days;
+///
+/// let days = seasonal_days::seasonal_days(2024).unwrap();
+/// let sambok: Vec<String> = days
+///     .iter()
+///     .filter(|day| day.name_ko.ends_with('복'))
+///     .map(|day| format!("{} {}", day.date, day.name_ko))
+///     .collect();
+///
+/// assert_eq!(sambok, ["2024-07-15 초복", "2024-07-25 중복", "2024-08-14 말복"]);
+/// ```
+pub fn seasonal_days(year: u32) -> Result<Vec<SeasonalDay>, KlcError> {
+    seasonal_days_with_napil_rule(year, NapilRule::default())
+}
+
+/// Returns the traditional seasonal days of a solar year, choosing 납일 by `rule`.
+///
+/// # Errors
+/// Returns [`KlcError::OutOfRange`] if the year is outside 1392-2200.
+///
+/// # Example
+/// ```
+/// use rs_klc::seasonal_days::{NapilRule, seasonal_days_with_napil_rule};
+///
+/// // 동지 2023 is in a 계 (Water) year, whose tomb is 진
+/// let napil = |rule| {
+///     seasonal_days_with_napil_rule(2024, rule)
+///         .unwrap()
+///         .into_iter()
+///         .find(|day| day.name_ko == "납일")
+///         .map(|day| day.date.to_string())
+/// };
+///
+/// assert_eq!(napil(NapilRule::Joseon).unwrap(), "2024-01-20");
+/// assert_eq!(napil(NapilRule::YearElement).unwrap(), "2024-01-17");
+/// ```
+pub fn seasonal_days_with_napil_rule(
+    year: u32,
+    rule: NapilRule,
+) -> Result<Vec<SeasonalDay>, KlcError> {
+    if !(SEASONAL_DAY_MIN_YEAR..=SEASONAL_DAY_MAX_YEAR).contains(&year) {
+        return Err(KlcError::OutOfRange {
+            min: SEASONAL_DAY_MIN_YEAR * 10000 + 101,
+            max: SEASONAL_DAY_MAX_YEAR * 10000 + 1231,
+        });
+    }
+
+    let mut days = Vec::new();
+
+    for (month, day, name_ko, name_en) in LUNAR_SEASONAL_DAYS {
+        days.push(SeasonalDay {
+            date: LunarDate::new(year as i32, month, day, false)?.to_solar(),
+            name_ko,
+            name_en,
+            kind: SeasonalDayKind::Lunar,
+        });
+    }
+
+    let solar_term_day = |date, name_ko, name_en| SeasonalDay {
+        date,
+        name_ko,
+        name_en,
+        kind: SeasonalDayKind::SolarTerm,
+    };
+
+    let previous_dongji = SolarTerm::Dongji.time_in_year(year - 1)?.date()?;
+    let hansik =
+        SolarDate::from_julian_day_number(previous_dongji.julian_day_number() as i64 + 105)?;
+    days.push(solar_term_day(hansik, "한식", "Hansik"));
+
+    let haji = SolarTerm::Haji.time_in_year(year)?.date()?;
+    let gyeong = |[cheongan, _]: [usize; 2]| cheongan == GYEONG_CHEONGAN;
+    days.push(solar_term_day(nth_day(haji, 3, gyeong)?, "초복", "Chobok"));
+    days.push(solar_term_day(nth_day(haji, 4, gyeong)?, "중복", "Jungbok"));
+    let ipchu = SolarTerm::Ipchu.time_in_year(year)?.date()?;
+    days.push(solar_term_day(nth_day(ipchu, 1, gyeong)?, "말복", "Malbok"));
+
+    let dongji = SolarTerm::Dongji.time_in_year(year)?.date()?;
+    days.push(solar_term_day(dongji, "동지", "Dongji"));
+
+    for (dongji_year, dongji) in [(year - 1, previous_dongji), (year, dongji)] {
+        let branch = rule.branch(dongji_year);
+
+        // The 납일 after 동지 2200 is beyond the supported range
+        if let Ok(napil) = nth_day(dongji, 3, |[_, ganji]| ganji == branch)
+            && napil.year() == year
+        {
+            days.push(solar_term_day(napil, "납일", "Napil"));
+        }
+    }
+
+    days.sort_by_key(|day| day.date);
+
+    Ok(days)
+}
+
+/// Returns the `n`-th day from `start` (inclusive) whose day Gapja indices
+/// `[cheongan, ganji]` match.
+fn nth_day(
+    start: SolarDate,
+    n: usize,
+    matches: impl Fn([usize; 2]) -> bool,
+) -> Result<SolarDate, KlcError> {
+    let mut jdn = start.julian_day_number() as i64;
+    let mut count = 0;
+
+    loop {
+        let date = SolarDate::from_julian_day_number(jdn)?;
+        let abs_days =
+            LunarSolarConverter::get_solar_abs_days(date.year() as i32, date.month(), date.day());
+
+        if matches(LunarSolarConverter::get_day_gapja_inx(abs_days)) {
+            count += 1;
+
+            if count == n {
+                return Ok(date);
+            }
+        }
+
+        jdn += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NapilRule, SeasonalDayKind, seasonal_days, seasonal_days_with_napil_rule};
+    use crate::{EarthlyBranch, KlcError, Locale, LunarSolarConverter, Pillar, SolarDate};
+
+    #[test]
+    fn test_seasonal_days_2024() {
+        let days: Vec<String> = seasonal_days(2024)
+            .unwrap()
+            .iter()
+            .map(|day| format!("{} {}", day.date, day.name_ko))
+            .collect();
+
+        assert_eq!(
+            days,
+            [
+                "2024-01-20 납일",
+                "2024-02-24 정월대보름",
+                "2024-04-05 한식",
+                "2024-04-11 삼짇날",
+                "2024-06-10 단오",
+                "2024-07-15 초복",
+                "2024-07-20 유두",
+                "2024-07-25 중복",
+                "2024-08-10 칠석",
+                "2024-08-14 말복",
+                "2024-08-18 백중",
+                "2024-10-11 중양절",
+                "2024-12-21 동지",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sambok_are_gyeong_days() {
+        let mut converter = LunarSolarConverter::new();
+
+        for year in [1600, 1900, 2023, 2050, 2200] {
+            for day in seasonal_days(year).unwrap() {
+                if day.name_ko.ends_with('복') {
+                    let date = day.date;
+                    converter.set_solar_date(date.year(), date.month(), date.day());
+                    let gapja = converter.get_gapja_string();
+                    let day_gapja = gapja.split(' ').nth(2).unwrap();
+
+                    assert!(day_gapja.starts_with('경'), "{} {}", date, gapja);
+                    assert_eq!(day.kind, SeasonalDayKind::SolarTerm);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_napil_rules() {
+        let napil = |year, rule| {
+            seasonal_days_with_napil_rule(year, rule)
+                .unwrap()
+                .into_iter()
+                .find(|day| day.name_ko == "납일")
+                .unwrap()
+                .date
+        };
+        let branch = |date: SolarDate| {
+            Pillar::from_julian_day_number(date.julian_day_number() as i64).branch()
+        };
+
+        for year in [1600, 1900, 2023, 2025, 2050] {
+            assert_eq!(
+                seasonal_days(year),
+                seasonal_days_with_napil_rule(year, NapilRule::Joseon)
+            );
+            assert_eq!(branch(napil(year, NapilRule::Joseon)), EarthlyBranch::Mi);
+        }
+
+        // 동지 of 2024 (갑, Wood), 2026 (병, Fire), 2028 (무, Earth), 2030 (경, Metal)
+        // and 2032 (임, Water)
+        for (year, tomb) in [
+            (2025, EarthlyBranch::Mi),
+            (2027, EarthlyBranch::Sul),
+            (2029, EarthlyBranch::Sul),
+            (2031, EarthlyBranch::Chuk),
+            (2033, EarthlyBranch::Jin),
+        ] {
+            assert_eq!(
+                branch(napil(year, NapilRule::YearElement)),
+                tomb,
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn test_lunar_days_skip_leap_month() {
+        // 2025 has 윤6월; 유두 falls in the regular 6월
+        let yudu = seasonal_days(2025)
+            .unwrap()
+            .into_iter()
+            .find(|day| day.name_ko == "유두")
+            .unwrap();
+
+        assert!(!yudu.date.to_lunar().is_leap_month());
+        assert_eq!(yudu.date.to_lunar().to_string(), "2025-06-15");
+    }
+
+    #[test]
+    fn test_seasonal_days_out_of_range() {
+        assert!(matches!(
+            seasonal_days(1391),
+            Err(KlcError::OutOfRange { .. })
+        ));
+        assert!(seasonal_days(2200).is_ok());
+        assert!(seasonal_days(2201).is_err());
+    }
+
+    #[test]
+    fn test_names_in_every_locale() {
+        let days = seasonal_days(2024).unwrap();
+
+        for day in &days {
+            assert_eq!(day.name_in(Locale::Korean), day.name_ko);
+            assert_eq!(day.name_in(Locale::English), day.name_en);
+        }
+        let names: Vec<&str> = days[..4]
+            .iter()
+            .map(|day| day.name_in(Locale::Hanja))
+            .collect();
+        assert_eq!(names, ["臘日", "上元", "寒食", "上巳"]);
+        assert_eq!(days[4].name_in(Locale::Vietnamese), "Tết Đoan ngọ");
+    }
+}
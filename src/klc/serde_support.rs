@@ -0,0 +1,632 @@
+//! Serialization with [`serde`], enabled by the `serde` feature.
+//!
+//! Dates and pillars serialize as compact strings: `"2023-04-05"` for a [`SolarDate`],
+//! `"2023-02-15"` or `"2023-L02-15"` (intercalary month) for a [`LunarDate`], and the
+//! Korean name `"갑자"` for a [`Pillar`]. [`FourPillars`] serializes as an object of
+//! compact pillars, and [`DayOfWeek`](super::DayOfWeek) as its name (`"Monday"`).
+//!
+//! The default implementations read only the compact form, so they work in formats
+//! that are not self-describing, such as bincode or postcard. A pillar also accepts its
+//! Chinese name (`"甲子"`), and the pillars of a [`FourPillars`] must agree: the hour
+//! stem follows from the day stem.
+//!
+//! Fields annotated with `#[serde(with = "rs_klc::serde_object")]` use a structured
+//! object form instead, such as `{"year":2023,"month":2,"day":15,"is_leap_month":true}`
+//! or `{"stem":0,"branch":0}`. In human-readable formats such as JSON, these fields
+//! accept the compact form as well; other formats read only the object form.
+//!
+//! # Example
+//! ```
+//! use rs_klc::{LunarDate, SolarDate};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Birthday {
+//!     solar: SolarDate,
+//!     #[serde(with = "rs_klc::serde_object")]
+//!     lunar: LunarDate,
+//! }
+//!
+//! let solar = SolarDate::new(2023, 4, 5).unwrap();
+//! let birthday = Birthday { solar, lunar: solar.to_lunar() };
+//! let json = serde_json::to_string(&birthday).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"solar":"2023-04-05","lunar":{"year":2023,"month":2,"day":15,"is_leap_month":true}}"#
+//! );
+//!
+//! let parsed: Birthday = serde_json::from_str(r#"{"solar":"2023-04-05","lunar":"2023-L02-15"}"#).unwrap();
+//! assert_eq!(parsed, birthday);
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{self, DeserializeOwned, Deserializer, MapAccess, Unexpected, Visitor};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    CHINESE_CHEONGAN, CHINESE_GANJI, FourPillars, KOREAN_CHEONGAN, KOREAN_GANJI, KlcError,
+    LunarDate, Pillar, SolarDate,
+};
+
+/// Serializes a value as its structured object form, for use with
+/// `#[serde(with = "rs_klc::serde_object")]`.
+///
+/// Deserialization accepts both the object and the compact string form in human-readable
+/// formats, and only the object form in the others.
+pub mod object {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// A type with a structured object form: [`SolarDate`](crate::SolarDate),
+    /// [`LunarDate`](crate::LunarDate), [`Pillar`](crate::Pillar) and
+    /// [`FourPillars`](crate::FourPillars).
+    ///
+    /// This trait is sealed and cannot be implemented outside this crate.
+    pub trait ObjectForm: Serialize + for<'de> Deserialize<'de> + super::Sealed {}
+
+    /// Serializes `value` as an object.
+    pub fn serialize<T: ObjectForm, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_object(serializer)
+    }
+
+    /// Deserializes a value from its object form, or from its compact string form in a
+    /// human-readable format.
+    pub fn deserialize<'de, T: ObjectForm, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_object(deserializer)
+    }
+}
+
+pub trait Sealed: Sized {
+    fn serialize_object<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_object<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// A type that deserializes from a compact string or a map.
+trait Forms: Sized {
+    const EXPECTING: &'static str;
+
+    type Object: DeserializeOwned;
+
+    /// Parses the compact form, or returns `None` if `s` is not well formed.
+    fn from_compact(s: &str) -> Option<Result<Self, KlcError>>;
+
+    fn from_object(object: Self::Object) -> Result<Self, KlcError>;
+}
+
+struct FormVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Forms> Visitor<'de> for FormVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::from_compact(v)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))?
+            .map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<T, A::Error> {
+        let object = T::Object::deserialize(MapAccessDeserializer::new(map))?;
+
+        T::from_object(object).map_err(de::Error::custom)
+    }
+}
+
+fn deserialize_compact<'de, T: Forms, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_str(FormVisitor(PhantomData))
+}
+
+/// Deserializes either form from a human-readable format, which can tell a string from
+/// a map, and the object form from any other.
+fn deserialize_either<'de, T: Forms, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(FormVisitor(PhantomData))
+    } else {
+        T::from_object(T::Object::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Splits `YYYY-MM-DD` into its fields, keeping any prefix of the month.
+fn split_date(s: &str) -> Option<(&str, &str, &str)> {
+    let mut parts = s.split('-');
+    let fields = (parts.next()?, parts.next()?, parts.next()?);
+
+    parts.next().is_none().then_some(fields)
+}
+
+fn number<T: FromStr>(s: &str) -> Option<T> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SolarObject {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl Forms for SolarDate {
+    const EXPECTING: &'static str = "a solar date as \"YYYY-MM-DD\" or {year, month, day}";
+
+    type Object = SolarObject;
+
+    fn from_compact(s: &str) -> Option<Result<Self, KlcError>> {
+        let (year, month, day) = split_date(s)?;
+
+        Some(SolarDate::new(number(year)?, number(month)?, number(day)?))
+    }
+
+    fn from_object(object: SolarObject) -> Result<Self, KlcError> {
+        SolarDate::new(object.year, object.month, object.day)
+    }
+}
+
+impl Sealed for SolarDate {
+    fn serialize_object<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SolarObject {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_object<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_either(deserializer)
+    }
+}
+
+impl object::ObjectForm for SolarDate {}
+
+impl Serialize for SolarDate {
+    /// Serializes the date as `"YYYY-MM-DD"`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SolarDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_compact(deserializer)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LunarObject {
+    year: i32,
+    month: u32,
+    day: u32,
+    #[serde(default)]
+    is_leap_month: bool,
+}
+
+impl Forms for LunarDate {
+    const EXPECTING: &'static str =
+        "a lunar date as \"YYYY-MM-DD\", \"YYYY-LMM-DD\" or {year, month, day, is_leap_month}";
+
+    type Object = LunarObject;
+
+    fn from_compact(s: &str) -> Option<Result<Self, KlcError>> {
+        let (year, month, day) = split_date(s)?;
+        let (month, is_leap_month) = match month.strip_prefix('L') {
+            Some(month) => (month, true),
+            None => (month, false),
+        };
+
+        Some(LunarDate::new(
+            number(year)?,
+            number(month)?,
+            number(day)?,
+            is_leap_month,
+        ))
+    }
+
+    fn from_object(object: LunarObject) -> Result<Self, KlcError> {
+        LunarDate::new(object.year, object.month, object.day, object.is_leap_month)
+    }
+}
+
+impl Sealed for LunarDate {
+    fn serialize_object<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LunarObject {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            is_leap_month: self.is_leap_month(),
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_object<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_either(deserializer)
+    }
+}
+
+impl object::ObjectForm for LunarDate {}
+
+impl Serialize for LunarDate {
+    /// Serializes the date as `"YYYY-MM-DD"`, or `"YYYY-LMM-DD"` in an intercalary month.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let leap = if self.is_leap_month() { "L" } else { "" };
+
+        serializer.collect_str(&format_args!(
+            "{:04}-{}{:02}-{:02}",
+            self.year(),
+            leap,
+            self.month(),
+            self.day()
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for LunarDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_compact(deserializer)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PillarObject {
+    stem: usize,
+    branch: usize,
+}
+
+/// Rejects a stem and branch that are out of range or never paired in the cycle,
+/// such as 갑축 (a yang stem with a yin branch).
+fn check_pillar(stem: usize, branch: usize) -> Result<Pillar, String> {
+    if stem >= KOREAN_CHEONGAN.len() || branch >= KOREAN_GANJI.len() {
+        return Err(format!("invalid pillar: stem {stem}, branch {branch}"));
+    }
+    if stem % 2 != branch % 2 {
+        return Err(format!(
+            "{}{} is not in the sexagenary cycle",
+            KOREAN_CHEONGAN[stem], KOREAN_GANJI[branch]
+        ));
+    }
+
+    Ok(Pillar::from_indices(stem, branch))
+}
+
+/// Finds the index of a stem or branch by its Korean or Chinese character.
+fn find(c: char, korean: &[char], chinese: &[char]) -> Option<usize> {
+    korean
+        .iter()
+        .position(|&k| k == c)
+        .or_else(|| chinese.iter().position(|&h| h == c))
+}
+
+struct PillarVisitor;
+
+impl<'de> Visitor<'de> for PillarVisitor {
+    type Value = Pillar;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a pillar as \"갑자\", \"甲子\" or {stem, branch}")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Pillar, E> {
+        let mut chars = v.chars();
+        let (Some(stem), Some(branch), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(E::invalid_value(Unexpected::Str(v), &self));
+        };
+        let stem = find(stem, &KOREAN_CHEONGAN, &CHINESE_CHEONGAN);
+        let branch = find(branch, &KOREAN_GANJI, &CHINESE_GANJI);
+
+        match (stem, branch) {
+            (Some(stem), Some(branch)) => check_pillar(stem, branch).map_err(E::custom),
+            _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Pillar, A::Error> {
+        let object = PillarObject::deserialize(MapAccessDeserializer::new(map))?;
+
+        check_pillar(object.stem, object.branch).map_err(de::Error::custom)
+    }
+}
+
+impl Sealed for Pillar {
+    fn serialize_object<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PillarObject {
+            stem: self.stem_index(),
+            branch: self.branch_index(),
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_object<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(PillarVisitor)
+        } else {
+            let object = PillarObject::deserialize(deserializer)?;
+
+            check_pillar(object.stem, object.branch).map_err(de::Error::custom)
+        }
+    }
+}
+
+impl object::ObjectForm for Pillar {}
+
+impl Serialize for Pillar {
+    /// Serializes the pillar as its Korean name (e.g. `"갑자"`).
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pillar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(PillarVisitor)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FourPillarsObject {
+    year: Pillar,
+    month: Pillar,
+    day: Pillar,
+    hour: Pillar,
+}
+
+impl FourPillarsObject {
+    /// Rejects an hour pillar whose stem does not follow from the day stem. The 자시
+    /// stem may also follow from the next day, as 23:00 does under
+    /// [`JasiConvention::CurrentDay`](super::JasiConvention::CurrentDay).
+    fn into_pillars(self) -> Result<FourPillars, String> {
+        let hour_stem = |day_stem: usize| {
+            ((day_stem % 5) * 2 + self.hour.branch_index()) % KOREAN_CHEONGAN.len()
+        };
+        let day_stem = self.day.stem_index();
+        let stem = self.hour.stem_index();
+
+        if stem != hour_stem(day_stem)
+            && !(self.hour.branch_index() == 0 && stem == hour_stem(day_stem + 1))
+        {
+            return Err(format!(
+                "{}시 does not follow the day {}일",
+                self.hour, self.day
+            ));
+        }
+
+        Ok(FourPillars::from_pillars(
+            self.year, self.month, self.day, self.hour,
+        ))
+    }
+}
+
+impl From<&FourPillars> for FourPillarsObject {
+    fn from(pillars: &FourPillars) -> Self {
+        FourPillarsObject {
+            year: pillars.year(),
+            month: pillars.month(),
+            day: pillars.day(),
+            hour: pillars.hour(),
+        }
+    }
+}
+
+/// The pillars in their object form.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FourPillarsObjects {
+    #[serde(with = "object")]
+    year: Pillar,
+    #[serde(with = "object")]
+    month: Pillar,
+    #[serde(with = "object")]
+    day: Pillar,
+    #[serde(with = "object")]
+    hour: Pillar,
+}
+
+impl Sealed for FourPillars {
+    fn serialize_object<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FourPillarsObjects {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            hour: self.hour(),
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_object<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let objects = FourPillarsObjects::deserialize(deserializer)?;
+
+        FourPillarsObject {
+            year: objects.year,
+            month: objects.month,
+            day: objects.day,
+            hour: objects.hour,
+        }
+        .into_pillars()
+        .map_err(de::Error::custom)
+    }
+}
+
+impl object::ObjectForm for FourPillars {}
+
+impl Serialize for FourPillars {
+    /// Serializes the pillars as `{year, month, day, hour}` of Korean names.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FourPillarsObject::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FourPillars {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FourPillarsObject::deserialize(deserializer)?
+            .into_pillars()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+    use crate::{DayOfWeek, FourPillars, JasiConvention, LunarDate, Pillar, SolarDate};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Object<T: super::object::ObjectForm>(#[serde(with = "crate::serde_object")] T);
+
+    fn round_trip<T>(value: T, compact: &str, object: &str)
+    where
+        T: super::object::ObjectForm + Copy + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(serde_json::to_string(&value).unwrap(), compact);
+        assert_eq!(serde_json::to_string(&Object(value)).unwrap(), object);
+
+        assert_eq!(parse::<T>(compact), value);
+        assert_eq!(parse::<Object<T>>(compact), Object(value));
+        assert_eq!(parse::<Object<T>>(object), Object(value));
+        assert!(error::<T>(object).starts_with("invalid type"));
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value);
+        let bytes = bincode::serialize(&Object(value)).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Object<T>>(&bytes).unwrap(),
+            Object(value)
+        );
+    }
+
+    fn parse<T: DeserializeOwned>(json: &str) -> T {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn error<T: DeserializeOwned + std::fmt::Debug>(json: &str) -> String {
+        serde_json::from_str::<T>(json).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_solar_date_round_trip() {
+        round_trip(
+            SolarDate::new(2023, 4, 5).unwrap(),
+            r#""2023-04-05""#,
+            r#"{"year":2023,"month":4,"day":5}"#,
+        );
+        round_trip(
+            SolarDate::new(1582, 10, 4).unwrap(),
+            r#""1582-10-04""#,
+            r#"{"year":1582,"month":10,"day":4}"#,
+        );
+    }
+
+    #[test]
+    fn test_lunar_date_round_trip() {
+        round_trip(
+            LunarDate::new(2023, 2, 15, true).unwrap(),
+            r#""2023-L02-15""#,
+            r#"{"year":2023,"month":2,"day":15,"is_leap_month":true}"#,
+        );
+        round_trip(
+            LunarDate::new(2023, 2, 15, false).unwrap(),
+            r#""2023-02-15""#,
+            r#"{"year":2023,"month":2,"day":15,"is_leap_month":false}"#,
+        );
+        assert_eq!(
+            parse::<Object<LunarDate>>(r#"{"year":2023,"month":2,"day":15}"#),
+            Object(LunarDate::new(2023, 2, 15, false).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_pillar_round_trip() {
+        let pillars = FourPillars::new(SolarDate::new(2024, 2, 4).unwrap(), 18, 0).unwrap();
+
+        round_trip(pillars.day(), r#""무술""#, r#"{"stem":4,"branch":10}"#);
+        assert_eq!(parse::<Pillar>(r#""戊戌""#), pillars.day());
+
+        round_trip(
+            pillars,
+            r#"{"year":"갑진","month":"병인","day":"무술","hour":"신유"}"#,
+            r#"{"year":{"stem":0,"branch":4},"month":{"stem":2,"branch":2},"day":{"stem":4,"branch":10},"hour":{"stem":7,"branch":9}}"#,
+        );
+    }
+
+    #[test]
+    fn test_day_of_week_round_trip() {
+        let json = serde_json::to_string(&DayOfWeek::Monday).unwrap();
+
+        assert_eq!(json, r#""Monday""#);
+        assert_eq!(parse::<DayOfWeek>(&json), DayOfWeek::Monday);
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert!(error::<SolarDate>(r#""2023-4-5x""#).starts_with("invalid value"));
+        assert!(error::<SolarDate>(r#""2023-04-05-01""#).starts_with("invalid value"));
+        assert!(error::<SolarDate>(r#""2023-02-29""#).starts_with("invalid day 29"));
+        assert!(
+            error::<Object<SolarDate>>(r#"{"year":2023,"month":1,"day":4294967295}"#)
+                .starts_with("invalid day 4294967295")
+        );
+        assert!(error::<LunarDate>(r#""2024-L02-01""#).contains("intercalary"));
+        assert!(error::<Object<LunarDate>>(r#"{"year":2023,"month":2}"#).contains("missing field"));
+        assert!(error::<Pillar>(r#""갑축""#).contains("not in the sexagenary cycle"));
+        assert!(error::<Object<Pillar>>(r#"{"stem":10,"branch":0}"#).contains("invalid pillar"));
+        assert!(error::<Pillar>(r#""갑""#).starts_with("invalid value"));
+        assert!(error::<SolarDate>("20230405").starts_with("invalid type"));
+    }
+
+    #[test]
+    fn test_hour_follows_day() {
+        let pillars = |day: &str, hour: &str| {
+            format!(r#"{{"year":"갑진","month":"병인","day":"{day}","hour":"{hour}"}}"#)
+        };
+
+        assert!(
+            error::<FourPillars>(&pillars("무술", "계유"))
+                .contains("계유시 does not follow the day 무술일")
+        );
+        assert!(error::<Object<FourPillars>>(&pillars("무술", "계유")).contains("does not follow"));
+
+        // 23:30 with JasiConvention::CurrentDay pairs 무술일 with the next day's 갑자시
+        let date = SolarDate::new(2024, 2, 4).unwrap();
+        let late = FourPillars::with_jasi_convention(date, 23, 30, JasiConvention::CurrentDay);
+        assert_eq!(
+            parse::<FourPillars>(&pillars("무술", "갑자")),
+            late.unwrap()
+        );
+        let early = FourPillars::new(date, 0, 30).unwrap();
+        assert_eq!(
+            parse::<FourPillars>(&pillars("무술", "임자")).hour(),
+            early.hour()
+        );
+        assert!(error::<FourPillars>(&pillars("무술", "병자")).contains("does not follow"));
+    }
+
+    #[test]
+    fn test_compact_form_in_bincode() {
+        let date = SolarDate::new(2023, 4, 5).unwrap();
+        let bytes = bincode::serialize(&date).unwrap();
+
+        assert_eq!(bytes, bincode::serialize("2023-04-05").unwrap());
+        let error = bincode::deserialize::<SolarDate>(&bincode::serialize("2023-02-29").unwrap());
+        assert!(error.unwrap_err().to_string().starts_with("invalid day 29"));
+    }
+}
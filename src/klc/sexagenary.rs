@@ -0,0 +1,397 @@
+//! The heavenly stems (천간), earthly branches (지지) and their sexagenary cycle (육십갑자).
+
+use std::fmt;
+
+use super::Locale;
+use super::locale::PillarUnit;
+
+/// The ten heavenly stems (천간, 天干), in order starting from 갑 (甲).
+///
+/// `Display` writes the Korean name, or the Hanja name with the alternate flag (`{:#}`).
+///
+/// # Example
+/// ```
+/// use rs_klc::{HeavenlyStem, Locale};
+///
+/// let stem = HeavenlyStem::Gye;
+/// assert_eq!(stem.index(), 9);
+/// assert_eq!(stem.next(), HeavenlyStem::Gap);
+/// assert_eq!(format!("{} {:#}", stem, stem), "계 癸");
+/// assert_eq!(stem.name_in(Locale::Vietnamese), "Quý");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeavenlyStem {
+    /// 갑 (甲)
+    Gap,
+    /// 을 (乙)
+    Eul,
+    /// 병 (丙)
+    Byeong,
+    /// 정 (丁)
+    Jeong,
+    /// 무 (戊)
+    Mu,
+    /// 기 (己)
+    Gi,
+    /// 경 (庚)
+    Gyeong,
+    /// 신 (辛)
+    Sin,
+    /// 임 (壬)
+    Im,
+    /// 계 (癸)
+    Gye,
+}
+
+/// The twelve earthly branches (지지, 地支), in order starting from 자 (子).
+///
+/// `Display` writes the Korean name, or the Hanja name with the alternate flag (`{:#}`).
+///
+/// # Example
+/// ```
+/// use rs_klc::{EarthlyBranch, Locale};
+///
+/// let branch = EarthlyBranch::Myo;
+/// assert_eq!(branch.index(), 3);
+/// assert_eq!(branch.prev(), EarthlyBranch::In);
+/// assert_eq!(format!("{} {:#}", branch, branch), "묘 卯");
+/// assert_eq!(branch.zodiac_name(Locale::Korean), "토끼");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EarthlyBranch {
+    /// 자 (子), the rat
+    Ja,
+    /// 축 (丑), the ox
+    Chuk,
+    /// 인 (寅), the tiger
+    In,
+    /// 묘 (卯), the rabbit
+    Myo,
+    /// 진 (辰), the dragon
+    Jin,
+    /// 사 (巳), the snake
+    Sa,
+    /// 오 (午), the horse
+    O,
+    /// 미 (未), the goat
+    Mi,
+    /// 신 (申), the monkey
+    Sin,
+    /// 유 (酉), the rooster
+    Yu,
+    /// 술 (戌), the dog
+    Sul,
+    /// 해 (亥), the pig
+    Hae,
+}
+
+/// A pair of a heavenly stem and an earthly branch in the sexagenary cycle (육십갑자),
+/// from 갑자 (甲子), the 1st, to 계해 (癸亥), the 60th.
+///
+/// `Display` writes the Korean name, or the Hanja name with the alternate flag (`{:#}`).
+///
+/// # Example
+/// ```
+/// use rs_klc::{EarthlyBranch, HeavenlyStem, Sexagenary};
+///
+/// let imin = Sexagenary::new(HeavenlyStem::Im, EarthlyBranch::In).unwrap();
+/// assert_eq!(imin.cycle_index(), 39);
+/// assert_eq!(Sexagenary::from_cycle_index(39), Some(imin));
+/// assert_eq!(format!("{} {:#}", imin, imin), "임인 壬寅");
+/// assert_eq!(imin.next().to_string(), "계묘");
+///
+/// let gyehae = Sexagenary::from_cycle_index(60).unwrap();
+/// assert_eq!(gyehae.next().to_string(), "갑자");
+///
+/// // 갑축 is not in the cycle: a stem only pairs with branches of the same parity
+/// assert_eq!(Sexagenary::new(HeavenlyStem::Gap, EarthlyBranch::Chuk), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sexagenary {
+    stem: usize,
+    branch: usize,
+}
+
+/// A pillar (주) of the Four Pillars or of the Gapja of a date: a [`Sexagenary`] pair of a
+/// heavenly stem (천간) and an earthly branch (지지).
+///
+/// # Example
+/// ```
+/// use rs_klc::{FourPillars, SolarDate};
+///
+/// let pillars = FourPillars::new(SolarDate::new(2024, 2, 4).unwrap(), 18, 0).unwrap();
+/// let day = pillars.day();
+/// assert_eq!((day.stem_index(), day.branch_index()), (4, 10));
+/// assert_eq!(day.sexagenary_index(), 34);
+/// assert_eq!(day.to_string(), "무술");
+/// assert_eq!(day.chinese_name(), "戊戌");
+/// ```
+pub type Pillar = Sexagenary;
+
+impl HeavenlyStem {
+    /// All heavenly stems, starting from 갑.
+    pub const ALL: [HeavenlyStem; 10] = [
+        HeavenlyStem::Gap,
+        HeavenlyStem::Eul,
+        HeavenlyStem::Byeong,
+        HeavenlyStem::Jeong,
+        HeavenlyStem::Mu,
+        HeavenlyStem::Gi,
+        HeavenlyStem::Gyeong,
+        HeavenlyStem::Sin,
+        HeavenlyStem::Im,
+        HeavenlyStem::Gye,
+    ];
+
+    /// Returns the position of this stem in [`HeavenlyStem::ALL`] (0 for 갑).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the stem at the given position in [`HeavenlyStem::ALL`], wrapping around.
+    pub fn from_index(index: usize) -> HeavenlyStem {
+        Self::ALL[index % Self::ALL.len()]
+    }
+
+    /// Returns the next stem, from 계 back to 갑.
+    pub fn next(self) -> HeavenlyStem {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Returns the previous stem, from 갑 back to 계.
+    pub fn prev(self) -> HeavenlyStem {
+        Self::from_index(self.index() + Self::ALL.len() - 1)
+    }
+
+    /// Returns the name of this stem in a locale (e.g. "갑", "甲", "Gap", "Giáp").
+    pub fn name_in(self, locale: Locale) -> &'static str {
+        locale.stem_name(self.index())
+    }
+}
+
+impl EarthlyBranch {
+    /// All earthly branches, starting from 자.
+    pub const ALL: [EarthlyBranch; 12] = [
+        EarthlyBranch::Ja,
+        EarthlyBranch::Chuk,
+        EarthlyBranch::In,
+        EarthlyBranch::Myo,
+        EarthlyBranch::Jin,
+        EarthlyBranch::Sa,
+        EarthlyBranch::O,
+        EarthlyBranch::Mi,
+        EarthlyBranch::Sin,
+        EarthlyBranch::Yu,
+        EarthlyBranch::Sul,
+        EarthlyBranch::Hae,
+    ];
+
+    /// Returns the position of this branch in [`EarthlyBranch::ALL`] (0 for 자).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the branch at the given position in [`EarthlyBranch::ALL`], wrapping around.
+    pub fn from_index(index: usize) -> EarthlyBranch {
+        Self::ALL[index % Self::ALL.len()]
+    }
+
+    /// Returns the next branch, from 해 back to 자.
+    pub fn next(self) -> EarthlyBranch {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Returns the previous branch, from 자 back to 해.
+    pub fn prev(self) -> EarthlyBranch {
+        Self::from_index(self.index() + Self::ALL.len() - 1)
+    }
+
+    /// Returns the name of this branch in a locale (e.g. "자", "子", "Ja", "Tý").
+    pub fn name_in(self, locale: Locale) -> &'static str {
+        locale.branch_name(self.index())
+    }
+
+    /// Returns the zodiac animal (띠) of this branch in a locale (e.g. "쥐", "Rat").
+    pub fn zodiac_name(self, locale: Locale) -> &'static str {
+        locale.zodiac_name(self.index())
+    }
+}
+
+impl Sexagenary {
+    /// Number of pairs in the cycle.
+    const CYCLE_LEN: usize = 60;
+
+    /// Returns the pair of a stem and a branch, or `None` if they differ in parity and
+    /// never meet in the cycle (e.g. 갑축).
+    pub fn new(stem: HeavenlyStem, branch: EarthlyBranch) -> Option<Self> {
+        (stem.index() % 2 == branch.index() % 2)
+            .then(|| Sexagenary::from_indices(stem.index(), branch.index()))
+    }
+
+    /// Returns the pair at a position in the cycle, from 1 (갑자) to 60 (계해), or `None`
+    /// if `index` is not within 1-60.
+    pub fn from_cycle_index(index: u32) -> Option<Self> {
+        let index = (index as usize).checked_sub(1)?;
+
+        (index < Self::CYCLE_LEN).then(|| Sexagenary::from_indices(index, index))
+    }
+
+    pub(super) fn from_indices(stem: usize, branch: usize) -> Self {
+        Sexagenary {
+            stem: stem % HeavenlyStem::ALL.len(),
+            branch: branch % EarthlyBranch::ALL.len(),
+        }
+    }
+
+    pub(super) fn from_julian_day_number(jdn: i64) -> Self {
+        // JDN 0 is a 계축 (index 49) day
+        let index = (jdn + 49).rem_euclid(60) as usize;
+
+        Sexagenary::from_indices(index, index)
+    }
+
+    /// Returns the heavenly stem (천간).
+    pub fn stem(&self) -> HeavenlyStem {
+        HeavenlyStem::from_index(self.stem)
+    }
+
+    /// Returns the earthly branch (지지).
+    pub fn branch(&self) -> EarthlyBranch {
+        EarthlyBranch::from_index(self.branch)
+    }
+
+    /// Returns the index of the heavenly stem (0 = 갑 甲 … 9 = 계 癸).
+    pub fn stem_index(&self) -> usize {
+        self.stem
+    }
+
+    /// Returns the index of the earthly branch (0 = 자 子 … 11 = 해 亥).
+    pub fn branch_index(&self) -> usize {
+        self.branch
+    }
+
+    /// Returns the position in the sexagenary cycle (0 = 갑자 甲子 … 59 = 계해 癸亥).
+    pub fn sexagenary_index(&self) -> usize {
+        (6 * self.stem + 55 * self.branch) % Self::CYCLE_LEN
+    }
+
+    /// Returns the position in the sexagenary cycle counted from 1 (1 = 갑자 甲子 …
+    /// 60 = 계해 癸亥), as the cycle is traditionally numbered.
+    pub fn cycle_index(&self) -> u32 {
+        self.sexagenary_index() as u32 + 1
+    }
+
+    /// Returns the next pair in the cycle, from 계해 back to 갑자.
+    pub fn next(&self) -> Self {
+        Sexagenary::from_indices(self.stem + 1, self.branch + 1)
+    }
+
+    /// Returns the previous pair in the cycle, from 갑자 back to 계해.
+    pub fn prev(&self) -> Self {
+        Sexagenary::from_indices(
+            self.stem + HeavenlyStem::ALL.len() - 1,
+            self.branch + EarthlyBranch::ALL.len() - 1,
+        )
+    }
+
+    /// Returns the Chinese name of the pillar (e.g. "甲子").
+    pub fn chinese_name(&self) -> String {
+        self.name_in(Locale::Hanja)
+    }
+
+    /// Returns the name of the pillar in a locale (e.g. "갑자", "Gapja", "Giáp Tý").
+    pub fn name_in(&self, locale: Locale) -> String {
+        let mut name = String::new();
+        locale
+            .write_pillar(&mut name, self.stem, self.branch)
+            .expect("writing to a String cannot fail");
+
+        name
+    }
+
+    /// Writes the name of the pillar with its unit (e.g. "갑자년", "Gapja-nyeon").
+    pub(super) fn write_with_unit(
+        &self,
+        f: &mut impl fmt::Write,
+        locale: Locale,
+        unit: PillarUnit,
+    ) -> fmt::Result {
+        locale.write_pillar_with_unit(f, self.stem, self.branch, unit)
+    }
+}
+
+/// Selects the Korean name, or the Hanja name with the alternate flag.
+pub(super) fn display_locale(f: &fmt::Formatter<'_>) -> Locale {
+    if f.alternate() {
+        Locale::Hanja
+    } else {
+        Locale::Korean
+    }
+}
+
+impl fmt::Display for HeavenlyStem {
+    /// Formats the stem with its Korean name (e.g. "갑"), or its Hanja name with `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name_in(display_locale(f)))
+    }
+}
+
+impl fmt::Display for EarthlyBranch {
+    /// Formats the branch with its Korean name (e.g. "자"), or its Hanja name with `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name_in(display_locale(f)))
+    }
+}
+
+impl fmt::Display for Sexagenary {
+    /// Formats the pillar with its Korean name (e.g. "갑자"), or its Hanja name with `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_locale(f).write_pillar(f, self.stem, self.branch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EarthlyBranch, HeavenlyStem, Sexagenary};
+
+    #[test]
+    fn test_cycle_index_round_trip() {
+        for index in 1..=60 {
+            let pair = Sexagenary::from_cycle_index(index).unwrap();
+
+            assert_eq!(pair.cycle_index(), index);
+            assert_eq!(Sexagenary::new(pair.stem(), pair.branch()), Some(pair));
+        }
+
+        assert_eq!(Sexagenary::from_cycle_index(0), None);
+        assert_eq!(Sexagenary::from_cycle_index(61), None);
+    }
+
+    #[test]
+    fn test_next_and_prev() {
+        let mut pair = Sexagenary::from_cycle_index(1).unwrap();
+
+        for index in 2..=61 {
+            let next = pair.next();
+
+            assert_eq!(next.cycle_index(), (index - 1) % 60 + 1);
+            assert_eq!(next.prev(), pair);
+            pair = next;
+        }
+
+        assert_eq!(HeavenlyStem::Gap.prev(), HeavenlyStem::Gye);
+        assert_eq!(EarthlyBranch::Hae.next(), EarthlyBranch::Ja);
+    }
+
+    #[test]
+    fn test_display() {
+        let pair = Sexagenary::new(HeavenlyStem::Gyeong, EarthlyBranch::Sul).unwrap();
+
+        assert_eq!(pair.to_string(), "경술");
+        assert_eq!(format!("{:#}", pair), "庚戌");
+        assert_eq!(format!("{}{:#}", pair.stem(), pair.branch()), "경戌");
+        assert_eq!(pair.cycle_index(), 47);
+    }
+}
@@ -0,0 +1,524 @@
+//! The 24 solar terms (24절기).
+//!
+//! Each term is the instant at which the apparent ecliptic longitude of the sun reaches
+//! a multiple of 15°. Instants are computed astronomically and dated in Korean Standard
+//! Time (UTC+9), the time zone used by the Korean calendar.
+
+use std::fmt;
+
+use super::{KlcError, Locale, SolarDate, astro};
+
+/// Offset of Korean Standard Time (UTC+9) in days.
+pub(crate) const KST_OFFSET_DAYS: f64 = 9.0 / 24.0;
+
+/// Mean motion of the sun in days per degree of longitude.
+const DAYS_PER_DEGREE: f64 = 365.242189 / 360.0;
+
+const SOLAR_TERM_MIN_YEAR: u32 = 1391;
+const SOLAR_TERM_MAX_YEAR: u32 = 2200;
+
+/// Korean, Hanja and English names of the solar terms, starting from 입춘.
+const SOLAR_TERM_NAMES: [(&str, &str, &str); 24] = [
+    ("입춘", "立春", "Start of Spring"),
+    ("우수", "雨水", "Rain Water"),
+    ("경칩", "驚蟄", "Awakening of Insects"),
+    ("춘분", "春分", "Spring Equinox"),
+    ("청명", "淸明", "Pure Brightness"),
+    ("곡우", "穀雨", "Grain Rain"),
+    ("입하", "立夏", "Start of Summer"),
+    ("소만", "小滿", "Grain Buds"),
+    ("망종", "芒種", "Grain in Ear"),
+    ("하지", "夏至", "Summer Solstice"),
+    ("소서", "小暑", "Minor Heat"),
+    ("대서", "大暑", "Major Heat"),
+    ("입추", "立秋", "Start of Autumn"),
+    ("처서", "處暑", "End of Heat"),
+    ("백로", "白露", "White Dew"),
+    ("추분", "秋分", "Autumn Equinox"),
+    ("한로", "寒露", "Cold Dew"),
+    ("상강", "霜降", "Frost's Descent"),
+    ("입동", "立冬", "Start of Winter"),
+    ("소설", "小雪", "Minor Snow"),
+    ("대설", "大雪", "Major Snow"),
+    ("동지", "冬至", "Winter Solstice"),
+    ("소한", "小寒", "Minor Cold"),
+    ("대한", "大寒", "Major Cold"),
+];
+
+/// Japanese names of the solar terms, in the shinjitai forms where they differ.
+const JAPANESE_SOLAR_TERM_NAMES: [&str; 24] = [
+    "立春", "雨水", "啓蟄", "春分", "清明", "穀雨", "立夏", "小満", "芒種", "夏至", "小暑", "大暑",
+    "立秋", "処暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至", "小寒", "大寒",
+];
+
+/// Vietnamese names of the solar terms (tiết khí).
+const VIETNAMESE_SOLAR_TERM_NAMES: [&str; 24] = [
+    "Lập xuân",
+    "Vũ thủy",
+    "Kinh trập",
+    "Xuân phân",
+    "Thanh minh",
+    "Cốc vũ",
+    "Lập hạ",
+    "Tiểu mãn",
+    "Mang chủng",
+    "Hạ chí",
+    "Tiểu thử",
+    "Đại thử",
+    "Lập thu",
+    "Xử thử",
+    "Bạch lộ",
+    "Thu phân",
+    "Hàn lộ",
+    "Sương giáng",
+    "Lập đông",
+    "Tiểu tuyết",
+    "Đại tuyết",
+    "Đông chí",
+    "Tiểu hàn",
+    "Đại hàn",
+];
+
+/// The 24 solar terms (절기), in order starting from 입춘 (Start of Spring).
+///
+/// Terms at even positions (입춘, 경칩, 청명, ...) are the sectional terms (절기, 節氣)
+/// that begin a month in the solar-term calendar; terms at odd positions (우수, 춘분,
+/// 곡우, ...) are the principal terms (중기, 中氣).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolarTerm {
+    /// 입춘 (立春), 315°
+    Ipchun,
+    /// 우수 (雨水), 330°
+    Usu,
+    /// 경칩 (驚蟄), 345°
+    Gyeongchip,
+    /// 춘분 (春分), 0°
+    Chunbun,
+    /// 청명 (淸明), 15°
+    Cheongmyeong,
+    /// 곡우 (穀雨), 30°
+    Gogu,
+    /// 입하 (立夏), 45°
+    Ipha,
+    /// 소만 (小滿), 60°
+    Soman,
+    /// 망종 (芒種), 75°
+    Mangjong,
+    /// 하지 (夏至), 90°
+    Haji,
+    /// 소서 (小暑), 105°
+    Soseo,
+    /// 대서 (大暑), 120°
+    Daeseo,
+    /// 입추 (立秋), 135°
+    Ipchu,
+    /// 처서 (處暑), 150°
+    Cheoseo,
+    /// 백로 (白露), 165°
+    Baengno,
+    /// 추분 (秋分), 180°
+    Chubun,
+    /// 한로 (寒露), 195°
+    Hallo,
+    /// 상강 (霜降), 210°
+    Sanggang,
+    /// 입동 (立冬), 225°
+    Ipdong,
+    /// 소설 (小雪), 240°
+    Soseol,
+    /// 대설 (大雪), 255°
+    Daeseol,
+    /// 동지 (冬至), 270°
+    Dongji,
+    /// 소한 (小寒), 285°
+    Sohan,
+    /// 대한 (大寒), 300°
+    Daehan,
+}
+
+/// The instant of a solar term.
+///
+/// Date and time fields are in Korean Standard Time (UTC+9), rounded to the minute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarTermTime {
+    term: SolarTerm,
+    julian_day: f64,
+}
+
+impl SolarTerm {
+    /// All solar terms, starting from 입춘.
+    pub const ALL: [SolarTerm; 24] = [
+        SolarTerm::Ipchun,
+        SolarTerm::Usu,
+        SolarTerm::Gyeongchip,
+        SolarTerm::Chunbun,
+        SolarTerm::Cheongmyeong,
+        SolarTerm::Gogu,
+        SolarTerm::Ipha,
+        SolarTerm::Soman,
+        SolarTerm::Mangjong,
+        SolarTerm::Haji,
+        SolarTerm::Soseo,
+        SolarTerm::Daeseo,
+        SolarTerm::Ipchu,
+        SolarTerm::Cheoseo,
+        SolarTerm::Baengno,
+        SolarTerm::Chubun,
+        SolarTerm::Hallo,
+        SolarTerm::Sanggang,
+        SolarTerm::Ipdong,
+        SolarTerm::Soseol,
+        SolarTerm::Daeseol,
+        SolarTerm::Dongji,
+        SolarTerm::Sohan,
+        SolarTerm::Daehan,
+    ];
+
+    /// Returns the position of this term in [`SolarTerm::ALL`] (0 for 입춘).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the term at the given position in [`SolarTerm::ALL`], wrapping around.
+    pub fn from_index(index: usize) -> SolarTerm {
+        Self::ALL[index % Self::ALL.len()]
+    }
+
+    /// Returns the term that starts at the given ecliptic longitude of the sun, or the
+    /// most recent term for longitudes that are not a multiple of 15°.
+    pub fn from_longitude(degrees: f64) -> SolarTerm {
+        let offset = astro::normalize_degrees(degrees - 315.0);
+        Self::from_index((offset / 15.0).floor() as usize)
+    }
+
+    /// Returns the apparent ecliptic longitude of the sun (in degrees) that defines this term.
+    pub fn longitude(self) -> u32 {
+        (315 + 15 * self as u32) % 360
+    }
+
+    /// Returns `true` for the sectional terms (절기, 節氣) that begin a solar-term month.
+    pub fn is_sectional(self) -> bool {
+        self.index().is_multiple_of(2)
+    }
+
+    /// Returns `true` for the principal terms (중기, 中氣).
+    pub fn is_principal(self) -> bool {
+        !self.is_sectional()
+    }
+
+    /// Returns the Korean name of this term (e.g. "입춘").
+    pub fn korean_name(self) -> &'static str {
+        SOLAR_TERM_NAMES[self.index()].0
+    }
+
+    /// Returns the Hanja name of this term (e.g. "立春").
+    pub fn hanja_name(self) -> &'static str {
+        SOLAR_TERM_NAMES[self.index()].1
+    }
+
+    /// Returns the English name of this term (e.g. "Start of Spring").
+    pub fn english_name(self) -> &'static str {
+        SOLAR_TERM_NAMES[self.index()].2
+    }
+
+    /// Returns the name of this term in a locale (e.g. "청명", "清明", "Thanh minh").
+    pub fn name_in(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Korean => self.korean_name(),
+            Locale::Hanja => self.hanja_name(),
+            Locale::English => self.english_name(),
+            Locale::Japanese => JAPANESE_SOLAR_TERM_NAMES[self.index()],
+            Locale::Vietnamese => VIETNAMESE_SOLAR_TERM_NAMES[self.index()],
+        }
+    }
+
+    /// Returns the first instant of this term on or after January 1st of a solar year.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the year is outside 1391-2200.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::SolarTerm;
+    ///
+    /// let ipchun = SolarTerm::Ipchun.time_in_year(2024).unwrap();
+    /// assert_eq!((ipchun.month(), ipchun.day()), (2, 4));
+    /// assert_eq!((ipchun.hour(), ipchun.minute()), (17, 27));
+    /// ```
+    pub fn time_in_year(self, year: u32) -> Result<SolarTermTime, KlcError> {
+        let start = year_start_julian_day(year)?;
+        let start_longitude = astro::sun_apparent_longitude(astro::ut_to_tt(start));
+        let distance = astro::normalize_degrees(self.longitude() as f64 - start_longitude);
+
+        Ok(SolarTermTime::find(
+            self,
+            start + distance * DAYS_PER_DEGREE,
+        ))
+    }
+}
+
+impl fmt::Display for SolarTerm {
+    /// Formats the term with its Korean name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.korean_name())
+    }
+}
+
+impl SolarTermTime {
+    fn find(term: SolarTerm, jd_guess: f64) -> Self {
+        SolarTermTime {
+            term,
+            julian_day: astro::find_sun_longitude(term.longitude() as f64, jd_guess),
+        }
+    }
+
+    /// Returns the solar term.
+    pub fn term(&self) -> SolarTerm {
+        self.term
+    }
+
+    /// Returns the instant of the term as a Julian Day in Universal Time.
+    pub fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    /// Returns the instant of the term as seconds since the Unix epoch.
+    pub fn unix_timestamp(&self) -> i64 {
+        ((self.julian_day - 2440587.5) * 86400.0).round() as i64
+    }
+
+    /// Returns the (Julian Day Number, minute of day) of the term in KST.
+    fn kst_parts(&self) -> (i64, u32) {
+        let minutes = ((self.julian_day + KST_OFFSET_DAYS + 0.5) * 1440.0).round() as i64;
+
+        (minutes.div_euclid(1440), minutes.rem_euclid(1440) as u32)
+    }
+
+    /// Returns the solar year of the term in KST.
+    pub fn year(&self) -> u32 {
+        astro::date_from_jdn(self.kst_parts().0).0 as u32
+    }
+
+    /// Returns the solar month of the term in KST.
+    pub fn month(&self) -> u32 {
+        astro::date_from_jdn(self.kst_parts().0).1
+    }
+
+    /// Returns the solar day of the term in KST.
+    pub fn day(&self) -> u32 {
+        astro::date_from_jdn(self.kst_parts().0).2
+    }
+
+    /// Returns the hour (0-23) of the term in KST.
+    pub fn hour(&self) -> u32 {
+        self.kst_parts().1 / 60
+    }
+
+    /// Returns the minute (0-59) of the term in KST.
+    pub fn minute(&self) -> u32 {
+        self.kst_parts().1 % 60
+    }
+
+    /// Returns the Julian Day Number of the day on which the term falls in KST.
+    pub fn julian_day_number(&self) -> i64 {
+        self.kst_parts().0
+    }
+
+    /// Returns the solar date of the term in KST.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the date precedes the supported solar range
+    /// (e.g. 소한 and 대한 of 1391).
+    pub fn date(&self) -> Result<SolarDate, KlcError> {
+        let (year, month, day) = astro::date_from_jdn(self.kst_parts().0);
+
+        SolarDate::new(year as u32, month, day)
+    }
+}
+
+impl fmt::Display for SolarTermTime {
+    /// Formats the term as "[Name] YYYY-MM-DD HH:MM" (e.g. "입춘 2024-02-04 17:27").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (jdn, minute_of_day) = self.kst_parts();
+        let (year, month, day) = astro::date_from_jdn(jdn);
+
+        write!(
+            f,
+            "{} {:04}-{:02}-{:02} {:02}:{:02}",
+            self.term,
+            year,
+            month,
+            day,
+            minute_of_day / 60,
+            minute_of_day % 60
+        )
+    }
+}
+
+/// Returns the Julian Day (UT) of January 1st, 00:00 KST of a solar year.
+fn year_start_julian_day(year: u32) -> Result<f64, KlcError> {
+    if !(SOLAR_TERM_MIN_YEAR..=SOLAR_TERM_MAX_YEAR).contains(&year) {
+        return Err(KlcError::OutOfRange {
+            min: SOLAR_TERM_MIN_YEAR * 10000 + 101,
+            max: SOLAR_TERM_MAX_YEAR * 10000 + 1231,
+        });
+    }
+
+    Ok(astro::jdn_from_date(year as i32, 1, 1) as f64 - 0.5 - KST_OFFSET_DAYS)
+}
+
+/// Returns the solar terms that fall within a solar year (KST), in chronological order.
+///
+/// A year normally contains 24 terms, starting with 소한 in the Gregorian calendar.
+///
+/// # Errors
+/// Returns [`KlcError::OutOfRange`] if the year is outside 1391-2200.
+///
+/// # Example
+/// ```
+/// use rs_klc::solar_terms::{self, SolarTerm};
+///
+/// let terms = solar_terms::solar_terms(2024).unwrap();
+/// assert_eq!(terms.len(), 24);
+/// assert_eq!(terms[0].term(), SolarTerm::Sohan);
+/// assert_eq!(terms[23].term(), SolarTerm::Dongji);
+/// assert_eq!((terms[23].month(), terms[23].day()), (12, 21));
+/// ```
+pub fn solar_terms(year: u32) -> Result<Vec<SolarTermTime>, KlcError> {
+    let start = year_start_julian_day(year)?;
+    let end = astro::jdn_from_date(year as i32 + 1, 1, 1) as f64 - 0.5 - KST_OFFSET_DAYS;
+    let start_longitude = astro::sun_apparent_longitude(astro::ut_to_tt(start));
+
+    let mut terms = Vec::with_capacity(25);
+    let mut term = SolarTerm::from_index(SolarTerm::from_longitude(start_longitude).index() + 1);
+    let distance = astro::normalize_degrees(term.longitude() as f64 - start_longitude);
+    let mut time = SolarTermTime::find(term, start + distance * DAYS_PER_DEGREE);
+
+    while time.julian_day < end {
+        terms.push(time);
+        term = SolarTerm::from_index(term.index() + 1);
+        time = SolarTermTime::find(term, time.julian_day + 15.0 * DAYS_PER_DEGREE);
+    }
+
+    Ok(terms)
+}
+
+impl SolarDate {
+    /// Returns the most recent solar term that began on or before this day (KST).
+    ///
+    /// Compare the term's [`date`](SolarTermTime::date) with this date to tell whether
+    /// the term falls on this day.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::{SolarDate, SolarTerm};
+    ///
+    /// let day = SolarDate::new(2024, 2, 10).unwrap();
+    /// assert_eq!(day.solar_term().term(), SolarTerm::Ipchun);
+    ///
+    /// let ipchun = SolarDate::new(2024, 2, 4).unwrap();
+    /// assert_eq!(ipchun.solar_term().date(), Ok(ipchun));
+    /// ```
+    pub fn solar_term(&self) -> SolarTermTime {
+        // End of this day in KST, expressed in UT
+        solar_term_at(self.julian_day_number() as f64 + 0.5 - KST_OFFSET_DAYS)
+    }
+}
+
+/// Returns the most recent solar term that began at or before an instant (Julian Day, UT).
+pub(crate) fn solar_term_at(julian_day: f64) -> SolarTermTime {
+    let longitude = astro::sun_apparent_longitude(astro::ut_to_tt(julian_day));
+    let term = SolarTerm::from_longitude(longitude);
+    let distance = astro::normalize_degrees(longitude - term.longitude() as f64);
+
+    SolarTermTime::find(term, julian_day - distance * DAYS_PER_DEGREE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SolarTerm, solar_terms};
+    use crate::{KlcError, Locale, SolarDate};
+
+    #[test]
+    fn test_solar_term_names() {
+        assert_eq!(SolarTerm::Ipchun.korean_name(), "입춘");
+        assert_eq!(SolarTerm::Dongji.hanja_name(), "冬至");
+        assert_eq!(SolarTerm::Chunbun.english_name(), "Spring Equinox");
+        assert_eq!(SolarTerm::Cheongmyeong.name_in(Locale::Japanese), "清明");
+        assert_eq!(SolarTerm::Soseol.name_in(Locale::Vietnamese), "Tiểu tuyết");
+        assert_eq!(SolarTerm::Chunbun.longitude(), 0);
+        assert_eq!(SolarTerm::Daehan.longitude(), 300);
+        assert!(SolarTerm::Gyeongchip.is_sectional());
+        assert!(SolarTerm::Usu.is_principal());
+    }
+
+    #[test]
+    fn test_from_longitude() {
+        assert_eq!(SolarTerm::from_longitude(315.0), SolarTerm::Ipchun);
+        assert_eq!(SolarTerm::from_longitude(0.0), SolarTerm::Chunbun);
+        assert_eq!(SolarTerm::from_longitude(359.9), SolarTerm::Gyeongchip);
+        assert_eq!(SolarTerm::from_longitude(-45.0), SolarTerm::Ipchun);
+    }
+
+    #[test]
+    fn test_known_solar_term_times() {
+        for (term, year, want) in [
+            (SolarTerm::Ipchun, 2024, "입춘 2024-02-04 17:27"),
+            (SolarTerm::Haji, 2024, "하지 2024-06-21 05:51"),
+            (SolarTerm::Dongji, 2023, "동지 2023-12-22 12:27"),
+            (SolarTerm::Chunbun, 2000, "춘분 2000-03-20 16:35"),
+        ] {
+            assert_eq!(term.time_in_year(year).unwrap().to_string(), want);
+        }
+    }
+
+    #[test]
+    fn test_solar_terms_in_year() {
+        let terms = solar_terms(2022).unwrap();
+
+        assert_eq!(terms.len(), 24);
+        assert_eq!(terms[0].term(), SolarTerm::Sohan);
+        assert_eq!(terms[2].term(), SolarTerm::Ipchun);
+        assert!(
+            terms
+                .windows(2)
+                .all(|w| w[0].julian_day() < w[1].julian_day())
+        );
+        assert!(terms.iter().all(|t| t.year() == 2022));
+    }
+
+    #[test]
+    fn test_solar_terms_julian_calendar() {
+        // Before the Gregorian reform the calendar drifts, so 소한 falls in late December.
+        let terms = solar_terms(1500).unwrap();
+
+        assert_eq!(terms.len(), 24);
+        assert_eq!(terms[0].term(), SolarTerm::Daehan);
+        assert_eq!(terms[23].term(), SolarTerm::Sohan);
+    }
+
+    #[test]
+    fn test_solar_terms_out_of_range() {
+        assert_eq!(
+            solar_terms(2201).unwrap_err(),
+            KlcError::OutOfRange {
+                min: 13910101,
+                max: 22001231
+            }
+        );
+        assert!(solar_terms(1391).unwrap()[0].date().is_err());
+    }
+
+    #[test]
+    fn test_solar_date_solar_term() {
+        let before = SolarDate::new(2024, 2, 3).unwrap();
+        let on = SolarDate::new(2024, 2, 4).unwrap();
+        let new_year = SolarDate::new(2024, 1, 1).unwrap();
+
+        assert_eq!(before.solar_term().term(), SolarTerm::Daehan);
+        assert_eq!(on.solar_term().term(), SolarTerm::Ipchun);
+        assert_eq!(on.solar_term().date(), Ok(on));
+        assert_eq!(new_year.solar_term().term(), SolarTerm::Dongji);
+        assert_eq!(new_year.solar_term().year(), 2023);
+    }
+}
@@ -0,0 +1,574 @@
+//! Cumulative day tables of the lunisolar calendars.
+//!
+//! All offsets are counted in days from the start of the Korean base year (1391), so a
+//! conversion is a table lookup plus a binary search instead of a loop over years, and
+//! the same day count gives the same day Gapja in every calendar.
+//!
+//! The tables of the embedded lunar data are evaluated at compile time. Lunar years past
+//! the end of the embedded data are computed astronomically one at a time, the first
+//! time each is needed, and their tables continue the embedded ones.
+//!
+//! Every calendar stops at [`MAX_YEAR`]. The day counts are `u32` offsets from 1391 and
+//! the solar tables are sized at compile time, and the astronomical series lose accuracy
+//! centuries away from the present, so the range is a deliberate limit rather than a
+//! missing fallback. Dates before the Korean base year are not computed either.
+
+use std::sync::OnceLock;
+
+use super::{
+    KOREAN_LUNAR_BASE_YEAR, KOREAN_LUNAR_DATA, KOREAN_LUNAR_MAX_VALUE, KOREAN_LUNAR_MIN_VALUE,
+    KOREAN_SOLAR_MAX_VALUE, KOREAN_SOLAR_MIN_VALUE, KlcError, LunarSolarConverter, astro,
+    calendar::{CHINESE_LUNAR_DATA, Chinese, Korean, LunisolarCalendar},
+    lunation,
+};
+
+/// Last lunar and solar year supported by every calendar.
+pub(super) const MAX_YEAR: i32 = 2200;
+
+/// Julian Day Number of the day before the first day of the Korean base year.
+const BASE_JDN: i64 = 2229155;
+
+/// Number of lunar years computed after the embedded data, 2051 to 2200 in both
+/// calendars.
+const COMPUTED_YEAR_COUNT: usize =
+    (MAX_YEAR - KOREAN_LUNAR_BASE_YEAR + 1) as usize - KOREAN_LUNAR_DATA.len();
+
+/// Number of solar years in the solar tables.
+const SOLAR_YEAR_COUNT: usize = (MAX_YEAR - KOREAN_LUNAR_BASE_YEAR + 1) as usize;
+
+/// Days before January 1st of each solar year. The extra last entry is the start
+/// of the year after the table.
+pub(super) static SOLAR_YEAR_START_DAYS: [u32; SOLAR_YEAR_COUNT + 1] =
+    build_solar_year_start_days();
+
+/// Days from January 1st to the start of each month for a common year, a leap year
+/// and 1582 (which lost 10 days in October). The last entry is the length of the year.
+pub(super) static SOLAR_MONTH_START_DAYS: [[u16; 13]; 3] = [
+    build_solar_month_start_days(1583),
+    build_solar_month_start_days(1584),
+    build_solar_month_start_days(1582),
+];
+
+/// Days before the first day of each Korean lunar year. The extra last entry is the
+/// number of days covered by the whole table.
+static KOREAN_LUNAR_YEAR_START_DAYS: [u32; KOREAN_LUNAR_DATA.len() + 1] =
+    build_lunar_year_start_days(&KOREAN_LUNAR_DATA, 0);
+
+/// Days from the start of a Korean lunar year to the start of each month, in calendar
+/// order (the intercalary month follows its regular month). Entries past the last month
+/// hold the length of the year.
+static KOREAN_LUNAR_MONTH_START_DAYS: [[u16; 14]; KOREAN_LUNAR_DATA.len()] =
+    build_lunar_month_start_days(&KOREAN_LUNAR_DATA);
+
+pub(super) static KOREAN_TABLES: CalendarTables = CalendarTables::new::<Korean>(
+    &KOREAN_LUNAR_YEAR_START_DAYS,
+    &KOREAN_LUNAR_MONTH_START_DAYS,
+    KOREAN_LUNAR_MIN_VALUE,
+    KOREAN_LUNAR_MAX_VALUE,
+    KOREAN_SOLAR_MIN_VALUE,
+);
+
+/// First day of the Chinese table, lunar 1901-01-01.
+const CHINESE_SOLAR_MIN_VALUE: u32 = 19010219;
+const CHINESE_LUNAR_MIN_VALUE: u32 = 19010101;
+const CHINESE_LUNAR_MAX_VALUE: u32 = 22001125;
+
+static CHINESE_LUNAR_YEAR_START_DAYS: [u32; CHINESE_LUNAR_DATA.len() + 1] =
+    build_lunar_year_start_days(
+        &CHINESE_LUNAR_DATA,
+        (astro::jdn_from_date(1901, 2, 19) - BASE_JDN - 1) as u32,
+    );
+
+static CHINESE_LUNAR_MONTH_START_DAYS: [[u16; 14]; CHINESE_LUNAR_DATA.len()] =
+    build_lunar_month_start_days(&CHINESE_LUNAR_DATA);
+
+pub(super) static CHINESE_TABLES: CalendarTables = CalendarTables::new::<Chinese>(
+    &CHINESE_LUNAR_YEAR_START_DAYS,
+    &CHINESE_LUNAR_MONTH_START_DAYS,
+    CHINESE_LUNAR_MIN_VALUE,
+    CHINESE_LUNAR_MAX_VALUE,
+    CHINESE_SOLAR_MIN_VALUE,
+);
+
+/// The lunar data and day tables of a calendar, and its supported range.
+///
+/// Only constructed for the calendars of this crate.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct CalendarTables {
+    base_year: i32,
+    utc_offset_days: f64,
+    lunar_data: &'static [u32],
+    lunar_year_start_days: &'static [u32],
+    lunar_month_start_days: &'static [[u16; 14]],
+    lunar_min_value: u32,
+    lunar_max_value: u32,
+    solar_min_value: u32,
+    computed: [OnceLock<ComputedYear>; COMPUTED_YEAR_COUNT],
+}
+
+/// Lunar data and day tables of a computed year, laid out like the embedded ones.
+#[derive(Debug)]
+struct ComputedYear {
+    lunar_data: u32,
+    year_start_days: u32,
+    month_start_days: [u16; 14],
+}
+
+impl CalendarTables {
+    const fn new<C: LunisolarCalendar>(
+        lunar_year_start_days: &'static [u32],
+        lunar_month_start_days: &'static [[u16; 14]],
+        lunar_min_value: u32,
+        lunar_max_value: u32,
+        solar_min_value: u32,
+    ) -> Self {
+        assert!(
+            (MAX_YEAR - C::BASE_YEAR + 1) as usize - C::LUNAR_DATA.len() == COMPUTED_YEAR_COUNT
+        );
+
+        CalendarTables {
+            base_year: C::BASE_YEAR,
+            utc_offset_days: C::UTC_OFFSET_MINUTES as f64 / 1440.0,
+            lunar_data: C::LUNAR_DATA,
+            lunar_year_start_days,
+            lunar_month_start_days,
+            lunar_min_value,
+            lunar_max_value,
+            solar_min_value,
+            computed: [const { OnceLock::new() }; COMPUTED_YEAR_COUNT],
+        }
+    }
+
+    /// Returns the supported range as `(lunar min, lunar max, solar min, solar max)`
+    /// `YYYYMMDD` values.
+    pub(super) fn range(&self) -> (u32, u32, u32, u32) {
+        (
+            self.lunar_min_value,
+            self.lunar_max_value,
+            self.solar_min_value,
+            KOREAN_SOLAR_MAX_VALUE,
+        )
+    }
+
+    /// Returns the number of supported lunar years, embedded and computed.
+    fn year_count(&self) -> usize {
+        (MAX_YEAR - self.base_year + 1) as usize
+    }
+
+    /// Returns the lunar year at `index` past the embedded data, computing it the first
+    /// time it is needed. A year starts on its own new moon rather than where the previous
+    /// year ends, so a lookup never computes the years before it.
+    fn computed_year(&self, index: usize) -> &ComputedYear {
+        self.computed[index].get_or_init(|| {
+            let year = self.base_year + (self.lunar_data.len() + index) as i32;
+            let (first_day, lunar_data) = lunation::lunar_year(year, self.utc_offset_days);
+
+            let mut month_start_days = [[0; 14]];
+            fill_lunar_month_start_days(&[lunar_data], &mut month_start_days);
+
+            ComputedYear {
+                lunar_data,
+                year_start_days: (first_day - BASE_JDN - 1) as u32,
+                month_start_days: month_start_days[0],
+            }
+        })
+    }
+
+    /// Returns the lunar data of a year, or 0 outside the supported range.
+    pub(super) fn lunar_data(&self, year: i32) -> u32 {
+        if year < self.base_year || (year - self.base_year) as usize >= self.year_count() {
+            return 0;
+        }
+
+        let index = (year - self.base_year) as usize;
+        if index < self.lunar_data.len() {
+            self.lunar_data[index]
+        } else {
+            self.computed_year(index - self.lunar_data.len()).lunar_data
+        }
+    }
+
+    /// Returns the intercalary month of a year, if it has one.
+    pub(super) fn intercalation_month(&self, year: i32) -> Option<u32> {
+        match LunarSolarConverter::get_lunar_intercalation_month(self.lunar_data(year)) {
+            0 => None,
+            month => Some(month),
+        }
+    }
+
+    /// Returns the number of days of a lunar month.
+    pub(super) fn lunar_days(&self, year: i32, month: u32, is_intercalation: bool) -> u32 {
+        if year < self.base_year {
+            return 0;
+        }
+        LunarSolarConverter::get_lunar_month_days(self.lunar_data(year), month, is_intercalation)
+    }
+
+    /// Returns the days before the first day of the lunar year at `index`, which may be
+    /// the year count for the end of the supported range.
+    fn lunar_year_start_days(&self, index: usize) -> u32 {
+        if index <= self.lunar_data.len() {
+            self.lunar_year_start_days[index]
+        } else if index < self.year_count() {
+            self.computed_year(index - self.lunar_data.len())
+                .year_start_days
+        } else {
+            let last = self.computed_year(COMPUTED_YEAR_COUNT - 1);
+            last.year_start_days + last.month_start_days[13] as u32
+        }
+    }
+
+    /// Returns the month start days of the lunar year at `index`.
+    fn lunar_month_start_days(&self, index: usize) -> &[u16; 14] {
+        if index < self.lunar_data.len() {
+            &self.lunar_month_start_days[index]
+        } else {
+            &self
+                .computed_year(index - self.lunar_data.len())
+                .month_start_days
+        }
+    }
+
+    /// Returns the position of a month within its lunar year, counting the intercalary
+    /// month as its own slot right after the regular month with the same number.
+    fn lunar_month_slot(lunar_data: u32, month: u32, is_intercalation: bool) -> usize {
+        let intercalation_month = LunarSolarConverter::get_lunar_intercalation_month(lunar_data);
+        let month = month.min(13);
+        let mut slot = month as usize - 1;
+
+        if intercalation_month > 0
+            && (month > intercalation_month || (is_intercalation && month == intercalation_month))
+        {
+            slot += 1;
+        }
+
+        slot
+    }
+
+    /// Inverse of `lunar_month_slot`: returns the month and whether it is intercalary.
+    fn lunar_month_by_slot(lunar_data: u32, slot: usize) -> (u32, bool) {
+        let intercalation_month =
+            LunarSolarConverter::get_lunar_intercalation_month(lunar_data) as usize;
+
+        if intercalation_month == 0 || slot < intercalation_month {
+            (slot as u32 + 1, false)
+        } else if slot == intercalation_month {
+            (slot as u32, true)
+        } else {
+            (slot as u32, false)
+        }
+    }
+
+    /// Returns the number of months, 12 or 13, of a lunar year.
+    pub(super) fn month_count(&self, year: i32) -> usize {
+        12 + self.intercalation_month(year).is_some() as usize
+    }
+
+    /// Returns the slot of a month within its lunar year.
+    pub(super) fn month_slot(&self, year: i32, month: u32, is_intercalation: bool) -> usize {
+        Self::lunar_month_slot(self.lunar_data(year), month, is_intercalation)
+    }
+
+    /// Returns the month at a slot of a lunar year and whether it is intercalary.
+    pub(super) fn month_by_slot(&self, year: i32, slot: usize) -> (u32, bool) {
+        Self::lunar_month_by_slot(self.lunar_data(year), slot)
+    }
+
+    /// Returns the 1-based day count since the Korean base date (lunar 1391-01-01) of a
+    /// lunar date, or 0 if the year precedes the calendar.
+    pub(super) fn lunar_abs_days(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        is_intercalation: bool,
+    ) -> u32 {
+        if year < self.base_year {
+            return 0;
+        }
+
+        let index = ((year - self.base_year) as usize).min(self.year_count());
+        let mut days = self.lunar_year_start_days(index) + day;
+
+        if month > 0 && index < self.year_count() {
+            let slot = Self::lunar_month_slot(self.lunar_data(year), month, is_intercalation);
+            days += self.lunar_month_start_days(index)[slot] as u32;
+        }
+
+        days
+    }
+
+    /// Returns the lunar date `(year, month, day, is_intercalation)` of a 1-based day
+    /// count since the Korean base date.
+    pub(super) fn lunar_date(&self, abs_days: u32) -> (i32, u32, u32, bool) {
+        let embedded_days = self.lunar_year_start_days[self.lunar_data.len()];
+        let index = if abs_days <= embedded_days {
+            self.lunar_year_start_days
+                .partition_point(|&start| start < abs_days)
+                - 1
+        } else {
+            // A lunar year is at most 385 days, so the estimate from the mean length of a
+            // year is off by at most one year either way.
+            let years = ((abs_days - embedded_days - 1) as f64 / 365.2422) as usize;
+            let mut index = (self.lunar_data.len() + years).min(self.year_count() - 1);
+
+            while self.lunar_year_start_days(index) >= abs_days {
+                index -= 1;
+            }
+            while index + 1 < self.year_count() && self.lunar_year_start_days(index + 1) < abs_days
+            {
+                index += 1;
+            }
+
+            index
+        };
+        let year = self.base_year + index as i32;
+
+        let day_of_year = abs_days - self.lunar_year_start_days(index);
+        let month_starts = self.lunar_month_start_days(index);
+        let slot = month_starts.partition_point(|&start| (start as u32) < day_of_year) - 1;
+        let (month, is_intercalation) = Self::lunar_month_by_slot(self.lunar_data(year), slot);
+
+        (
+            year,
+            month,
+            day_of_year - month_starts[slot] as u32,
+            is_intercalation,
+        )
+    }
+
+    /// Checks that a lunar or solar date exists and is within the supported range.
+    pub(super) fn try_check_valid_date(
+        &self,
+        is_lunar: bool,
+        is_intercalation: bool,
+        year: u32,
+        month: u32,
+        day: u32,
+    ) -> Result<(), KlcError> {
+        if month == 0 || month > 12 {
+            return Err(KlcError::InvalidMonth(month));
+        }
+
+        // No month has 99 days, so clamping the day keeps it from spilling into the month
+        // (or overflowing) while the range check below still sees an invalid day.
+        let date_value = year
+            .saturating_mul(10000)
+            .saturating_add(month * 100 + day.min(99));
+        let (lunar_min, lunar_max, solar_min, solar_max) = self.range();
+        let (min, max) = if is_lunar {
+            (lunar_min, lunar_max)
+        } else {
+            (solar_min, solar_max)
+        };
+
+        if date_value < min || date_value > max {
+            return Err(KlcError::OutOfRange { min, max });
+        }
+
+        let mut day_limit;
+
+        if is_lunar {
+            let intercalation_month = self.intercalation_month(year as i32);
+            if is_intercalation && intercalation_month != Some(month) {
+                return Err(KlcError::InvalidLeapMonth {
+                    year: year as i32,
+                    month,
+                    leap_month: intercalation_month,
+                });
+            }
+            day_limit = self.lunar_days(year as i32, month, is_intercalation);
+        } else {
+            day_limit = LunarSolarConverter::get_solar_days(year as i32, month);
+        }
+
+        //1582. 10. 5 ~ 1582. 10. 14 is not enable
+        if !is_lunar && year == 1582 && month == 10 {
+            if day > 4 && day < 15 {
+                return Err(KlcError::GregorianGap { day });
+            } else {
+                day_limit += 10;
+            }
+        }
+
+        if day == 0 || day > day_limit {
+            return Err(KlcError::InvalidDay {
+                day,
+                days_in_month: day_limit,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+const fn build_lunar_year_start_days<const N: usize>(lunar_data: &[u32], start: u32) -> [u32; N] {
+    let mut table = [0; N];
+    table[0] = start;
+    fill_lunar_year_start_days(lunar_data, &mut table);
+
+    table
+}
+
+/// Fills the entries after the first, which holds the days before the first year.
+const fn fill_lunar_year_start_days(lunar_data: &[u32], table: &mut [u32]) {
+    let mut index = 0;
+
+    while index < lunar_data.len() {
+        table[index + 1] = table[index] + LunarSolarConverter::shift_lunar_days(lunar_data[index]);
+        index += 1;
+    }
+}
+
+const fn build_lunar_month_start_days<const N: usize>(lunar_data: &[u32]) -> [[u16; 14]; N] {
+    let mut table = [[0; 14]; N];
+    fill_lunar_month_start_days(lunar_data, &mut table);
+
+    table
+}
+
+const fn fill_lunar_month_start_days(lunar_data: &[u32], table: &mut [[u16; 14]]) {
+    let mut index = 0;
+
+    while index < lunar_data.len() {
+        let data = lunar_data[index];
+        let intercalation_month = LunarSolarConverter::get_lunar_intercalation_month(data);
+        let mut days = 0;
+        let mut slot = 0;
+        let mut month = 1;
+
+        while month <= 12 {
+            days += LunarSolarConverter::get_lunar_month_days(data, month, false);
+            slot += 1;
+            table[index][slot] = days as u16;
+
+            if month == intercalation_month {
+                days += LunarSolarConverter::get_lunar_month_days(data, month, true);
+                slot += 1;
+                table[index][slot] = days as u16;
+            }
+
+            month += 1;
+        }
+
+        while slot < 13 {
+            slot += 1;
+            table[index][slot] = days as u16;
+        }
+
+        index += 1;
+    }
+}
+
+const fn build_solar_year_start_days() -> [u32; SOLAR_YEAR_COUNT + 1] {
+    let mut table = [0; SOLAR_YEAR_COUNT + 1];
+    let mut index = 0;
+
+    while index < SOLAR_YEAR_COUNT {
+        let year = KOREAN_LUNAR_BASE_YEAR + index as i32;
+        table[index + 1] = table[index] + LunarSolarConverter::shift_solar_days(year);
+        index += 1;
+    }
+
+    table
+}
+
+const fn build_solar_month_start_days(year: i32) -> [u16; 13] {
+    let mut table = [0; 13];
+    let mut month = 1;
+
+    while month <= 12 {
+        table[month as usize] =
+            table[month as usize - 1] + LunarSolarConverter::get_solar_days(year, month) as u16;
+        month += 1;
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CHINESE_TABLES, CalendarTables, KOREAN_LUNAR_MONTH_START_DAYS,
+        KOREAN_LUNAR_YEAR_START_DAYS, KOREAN_TABLES, SOLAR_MONTH_START_DAYS, SOLAR_YEAR_START_DAYS,
+    };
+    use crate::LunarSolarConverter;
+    use crate::klc::{
+        KOREAN_LUNAR_MAX_VALUE, KOREAN_LUNAR_MIN_VALUE, KOREAN_SOLAR_MIN_VALUE, calendar::Korean,
+    };
+
+    #[test]
+    fn test_lunar_year_start_days() {
+        assert_eq!(KOREAN_LUNAR_YEAR_START_DAYS[0], 0);
+        assert_eq!(KOREAN_LUNAR_YEAR_START_DAYS[1], 354); // 1391 has 354 days
+        assert_eq!(KOREAN_LUNAR_YEAR_START_DAYS[2], 354 + 384); // 1392 has 윤12월
+    }
+
+    #[test]
+    fn test_lunar_month_start_days_with_intercalation() {
+        // 2023 has 윤2월 right after the regular 2월
+        let months = KOREAN_LUNAR_MONTH_START_DAYS[2023 - 1391];
+
+        assert_eq!(months[2] - months[1], 30); // 2월
+        assert_eq!(months[3] - months[2], 29); // 윤2월
+        assert_eq!(months[13], 384);
+    }
+
+    #[test]
+    fn test_solar_tables() {
+        assert_eq!(SOLAR_YEAR_START_DAYS[1], 365);
+        assert_eq!(SOLAR_MONTH_START_DAYS[0][12], 365);
+        assert_eq!(SOLAR_MONTH_START_DAYS[1][12], 366);
+        assert_eq!(SOLAR_MONTH_START_DAYS[2][12], 355);
+    }
+
+    #[test]
+    fn test_calendars_share_day_count() {
+        // 2023-01-22 is lunar 2023-01-01 in both calendars
+        let abs_days = LunarSolarConverter::get_solar_abs_days(2023, 1, 22);
+
+        assert_eq!(KOREAN_TABLES.lunar_abs_days(2023, 1, 1, false), abs_days);
+        assert_eq!(CHINESE_TABLES.lunar_abs_days(2023, 1, 1, false), abs_days);
+        assert_eq!(CHINESE_TABLES.lunar_date(abs_days), (2023, 1, 1, false));
+    }
+
+    #[test]
+    fn test_computed_years_continue_the_table() {
+        for tables in [&KOREAN_TABLES, &CHINESE_TABLES] {
+            let first = tables.lunar_data.len();
+
+            assert_eq!(
+                tables.lunar_year_start_days(first + 1) - tables.lunar_year_start_days(first),
+                LunarSolarConverter::shift_lunar_days(tables.lunar_data(2051))
+            );
+            for index in first + 1..tables.year_count() {
+                let year = tables.base_year + index as i32;
+                assert_eq!(
+                    tables.lunar_year_start_days(index + 1) - tables.lunar_year_start_days(index),
+                    LunarSolarConverter::shift_lunar_days(tables.lunar_data(year)),
+                    "{}",
+                    year
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_computes_only_the_years_it_needs() {
+        let tables = CalendarTables::new::<Korean>(
+            &KOREAN_LUNAR_YEAR_START_DAYS,
+            &KOREAN_LUNAR_MONTH_START_DAYS,
+            KOREAN_LUNAR_MIN_VALUE,
+            KOREAN_LUNAR_MAX_VALUE,
+            KOREAN_SOLAR_MIN_VALUE,
+        );
+
+        let abs_days = KOREAN_TABLES.lunar_abs_days(2150, 7, 1, false);
+        assert_eq!(tables.lunar_date(abs_days), (2150, 7, 1, false));
+        assert_eq!(tables.lunar_abs_days(2150, 7, 1, false), abs_days);
+
+        let computed = tables.computed.iter().filter(|year| year.get().is_some());
+        assert!(computed.count() <= 3);
+    }
+}
@@ -0,0 +1,94 @@
+//! Conversions with [`time::Date`], enabled by the `time` feature.
+//!
+//! `time::Date` counts Julian days as an `i32`, which covers the whole supported range.
+//! See [`SolarDate::julian_day_number`] for dates before 1582-10-15.
+//!
+//! # Example
+//! ```
+//! use rs_klc::{LunarDate, SolarDate};
+//! use time::{Date, Month};
+//!
+//! let date = Date::from_calendar_date(2023, Month::April, 5).unwrap();
+//! let lunar = LunarDate::try_from(date).unwrap();
+//! assert_eq!(lunar.to_string(), "2023-02-15 Intercalation");
+//! assert_eq!(Date::from(lunar), date);
+//! ```
+
+use time::Date;
+
+use super::{KlcError, LunarDate, SolarDate};
+
+impl TryFrom<Date> for SolarDate {
+    type Error = KlcError;
+
+    fn try_from(date: Date) -> Result<Self, KlcError> {
+        SolarDate::from_julian_day_number(date.to_julian_day() as i64)
+    }
+}
+
+impl From<SolarDate> for Date {
+    fn from(date: SolarDate) -> Self {
+        Date::from_julian_day(date.julian_day_number() as i32)
+            .expect("every supported date is within the range of time::Date")
+    }
+}
+
+impl TryFrom<Date> for LunarDate {
+    type Error = KlcError;
+
+    fn try_from(date: Date) -> Result<Self, KlcError> {
+        Ok(SolarDate::try_from(date)?.to_lunar())
+    }
+}
+
+impl From<LunarDate> for Date {
+    fn from(date: LunarDate) -> Self {
+        Date::from(date.to_solar())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use crate::{KlcError, SolarDate};
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_julian_dates() {
+        assert_eq!(
+            SolarDate::try_from(date(1582, Month::October, 14)),
+            Ok(SolarDate::new(1582, 10, 4).unwrap())
+        );
+        assert_eq!(
+            Date::from(SolarDate::new(1582, 10, 15).unwrap()),
+            date(1582, Month::October, 15)
+        );
+    }
+
+    #[test]
+    fn test_julian_day_cast() {
+        let first = SolarDate::new(1391, 2, 5).unwrap();
+        let last = SolarDate::new(2200, 12, 31).unwrap();
+
+        assert_eq!(Date::from(first).to_julian_day(), 2229156);
+        assert_eq!(Date::from(last), date(2200, Month::December, 31));
+        assert_eq!(SolarDate::try_from(Date::from(last)), Ok(last));
+
+        // time::Date::MIN has a negative Julian day
+        assert!(Date::MIN.to_julian_day() < 0);
+        assert!(SolarDate::try_from(Date::MIN).is_err());
+        assert!(SolarDate::try_from(Date::MAX).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert!(matches!(
+            SolarDate::try_from(date(2201, Month::January, 1)),
+            Err(KlcError::OutOfRange { .. })
+        ));
+    }
+}
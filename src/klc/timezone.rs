@@ -0,0 +1,339 @@
+use std::fmt;
+
+use super::{
+    FourPillars, JasiConvention, KOREAN_SOLAR_MAX_VALUE, KOREAN_SOLAR_MIN_VALUE, KlcError,
+    LunarDate, SolarDate, astro,
+};
+
+/// Julian Day of the Unix epoch (1970-01-01 00:00 UTC).
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2440587.5;
+
+/// Julian Day Number of 1970-01-01.
+const UNIX_EPOCH_JDN: i64 = 2440588;
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+/// Longitude of Seoul in degrees east, used for true solar time.
+const SEOUL_LONGITUDE: f64 = 126.978;
+
+/// Offset of local mean time in Seoul (UTC+8:27:52), used before 1908-04-01.
+const SEOUL_LMT_OFFSET: i32 = 30472;
+
+/// Historical UTC offsets of Korea (Asia/Seoul) as `(start, offset, is_dst)`, where
+/// `start` is the Unix timestamp at which the offset (in seconds) takes effect.
+const KOREAN_UTC_OFFSETS: [(i64, i32, bool); 28] = [
+    (-1948782472, 30600, false), // 1908-04-01 00:00 LMT, UTC+8:30
+    (-1830414600, 32400, false), // 1912-01-01 UTC+9
+    (-681210000, 36000, true),   // 1948-06-01 summer time
+    (-672228000, 32400, false),  // 1948-09-13
+    (-654771600, 36000, true),   // 1949-04-03
+    (-640864800, 32400, false),  // 1949-09-11
+    (-623408400, 36000, true),   // 1950-04-01
+    (-609415200, 32400, false),  // 1950-09-10
+    (-588848400, 36000, true),   // 1951-05-06
+    (-577965600, 32400, false),  // 1951-09-09
+    (-498128400, 30600, false),  // 1954-03-21 UTC+8:30
+    (-462702600, 34200, true),   // 1955-05-05
+    (-451733400, 30600, false),  // 1955-09-09
+    (-429784200, 34200, true),   // 1956-05-20
+    (-418296600, 30600, false),  // 1956-09-30
+    (-399544200, 34200, true),   // 1957-05-05
+    (-387451800, 30600, false),  // 1957-09-22
+    (-368094600, 34200, true),   // 1958-05-04
+    (-356002200, 30600, false),  // 1958-09-21
+    (-336645000, 34200, true),   // 1959-05-03
+    (-324552600, 30600, false),  // 1959-09-20
+    (-305195400, 34200, true),   // 1960-05-01
+    (-293103000, 30600, false),  // 1960-09-18
+    (-264933000, 32400, false),  // 1961-08-10 UTC+9
+    (547578000, 36000, true),    // 1987-05-10 summer time
+    (560883600, 32400, false),   // 1987-10-11
+    (579027600, 36000, true),    // 1988-05-08
+    (592333200, 32400, false),   // 1988-10-09
+];
+
+/// A date and time in Korea at a given instant.
+///
+/// By default the time is the civil time in force at the instant, taken from an embedded
+/// table of historical Korean offsets: local mean time before 1908-04-01, UTC+8:30 in
+/// 1908-1911 and 1954-1961, UTC+9 otherwise, plus summer time in 1948-1951, 1955-1960 and
+/// 1987-1988. [`to_true_solar_time`](Self::to_true_solar_time) converts it to the apparent
+/// solar time of Seoul.
+///
+/// # Example
+/// ```
+/// use rs_klc::KoreanDateTime;
+///
+/// // 1988-07-01 00:00 UTC, during summer time
+/// let time = KoreanDateTime::from_unix_timestamp(583718400).unwrap();
+/// assert_eq!(time.to_string(), "1988-07-01 10:00:00+10:00");
+/// assert!(time.is_daylight_saving());
+/// assert_eq!(time.to_lunar().to_string(), "1988-05-18");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KoreanDateTime {
+    timestamp: i64,
+    date: SolarDate,
+    second_of_day: u32,
+    utc_offset: i32,
+    is_dst: bool,
+}
+
+impl KoreanDateTime {
+    /// Creates the civil date and time in Korea at a Unix timestamp (seconds since
+    /// 1970-01-01 00:00 UTC).
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the local date is outside the supported range.
+    pub fn from_unix_timestamp(timestamp: i64) -> Result<Self, KlcError> {
+        let (utc_offset, is_dst) = Self::korean_utc_offset(timestamp);
+
+        Self::with_offset(timestamp, utc_offset, is_dst)
+    }
+
+    /// Returns the UTC offset in seconds and whether summer time applies in Korea at a
+    /// Unix timestamp.
+    fn korean_utc_offset(timestamp: i64) -> (i32, bool) {
+        let index = KOREAN_UTC_OFFSETS.partition_point(|&(start, _, _)| start <= timestamp);
+
+        match index {
+            0 => (SEOUL_LMT_OFFSET, false),
+            _ => {
+                let (_, offset, is_dst) = KOREAN_UTC_OFFSETS[index - 1];
+                (offset, is_dst)
+            }
+        }
+    }
+
+    fn with_offset(timestamp: i64, utc_offset: i32, is_dst: bool) -> Result<Self, KlcError> {
+        let local = timestamp.saturating_add(utc_offset as i64);
+        let jdn = UNIX_EPOCH_JDN + local.div_euclid(SECONDS_PER_DAY);
+
+        // Keep far away instants from overflowing the calendar computation
+        if !(0..=5_000_000).contains(&jdn) {
+            return Err(KlcError::OutOfRange {
+                min: KOREAN_SOLAR_MIN_VALUE,
+                max: KOREAN_SOLAR_MAX_VALUE,
+            });
+        }
+
+        let (year, month, day) = astro::date_from_jdn(jdn);
+
+        Ok(KoreanDateTime {
+            timestamp,
+            date: SolarDate::new(year as u32, month, day)?,
+            second_of_day: local.rem_euclid(SECONDS_PER_DAY) as u32,
+            utc_offset,
+            is_dst,
+        })
+    }
+
+    /// Converts the time to the apparent (true) solar time of Seoul (126.978°E).
+    ///
+    /// The offset from UTC becomes the longitude of Seoul in time (8:27:55) plus the
+    /// equation of time, so the hour follows the position of the sun, as used for the
+    /// hour pillar (시주) in 사주명리.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the solar date is outside the supported range.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::KoreanDateTime;
+    ///
+    /// // 2024-02-04 12:00 KST; the sun crosses the meridian of Seoul at about 12:46
+    /// let time = KoreanDateTime::from_unix_timestamp(1707015600).unwrap();
+    /// let solar = time.to_true_solar_time().unwrap();
+    /// assert_eq!((solar.hour(), solar.minute()), (11, 14));
+    /// ```
+    pub fn to_true_solar_time(&self) -> Result<Self, KlcError> {
+        let jde = astro::ut_to_tt(self.julian_day());
+        let offset_minutes = SEOUL_LONGITUDE * 4.0 + astro::equation_of_time_minutes(jde);
+
+        Self::with_offset(
+            self.timestamp,
+            (offset_minutes * 60.0).round() as i32,
+            false,
+        )
+    }
+
+    /// Returns the instant as seconds since the Unix epoch.
+    pub fn unix_timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Returns the local solar date.
+    pub fn date(&self) -> SolarDate {
+        self.date
+    }
+
+    /// Returns the hour (0-23) of the local time.
+    pub fn hour(&self) -> u32 {
+        self.second_of_day / 3600
+    }
+
+    /// Returns the minute (0-59) of the local time.
+    pub fn minute(&self) -> u32 {
+        self.second_of_day / 60 % 60
+    }
+
+    /// Returns the second (0-59) of the local time.
+    pub fn second(&self) -> u32 {
+        self.second_of_day % 60
+    }
+
+    /// Returns the offset of the local time from UTC in seconds.
+    pub fn utc_offset_seconds(&self) -> i32 {
+        self.utc_offset
+    }
+
+    /// Returns `true` if summer time (daylight saving time) was in force.
+    pub fn is_daylight_saving(&self) -> bool {
+        self.is_dst
+    }
+
+    /// Converts the local date to the corresponding Korean Lunar date.
+    pub fn to_lunar(&self) -> LunarDate {
+        self.date.to_lunar()
+    }
+
+    /// Computes the Four Pillars of the local date and time.
+    ///
+    /// The year and month pillars change at the exact instant of 입춘 and each sectional
+    /// term, whatever the offset of the local time.
+    pub fn four_pillars(&self, convention: JasiConvention) -> FourPillars {
+        FourPillars::from_local_time(
+            self.date.julian_day_number() as i64,
+            self.second_of_day / 60,
+            self.julian_day(),
+            convention,
+        )
+    }
+
+    /// Returns the instant as a Julian Day (UT).
+    fn julian_day(&self) -> f64 {
+        UNIX_EPOCH_JULIAN_DAY + self.timestamp as f64 / SECONDS_PER_DAY as f64
+    }
+}
+
+impl fmt::Display for KoreanDateTime {
+    /// Formats the time as "YYYY-MM-DD HH:MM:SS+HH:MM" (the offset is truncated to the minute).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.utc_offset < 0 { '-' } else { '+' };
+        let offset_minutes = self.utc_offset.unsigned_abs() / 60;
+
+        write!(
+            f,
+            "{} {:02}:{:02}:{:02}{}{:02}:{:02}",
+            self.date,
+            self.hour(),
+            self.minute(),
+            self.second(),
+            sign,
+            offset_minutes / 60,
+            offset_minutes % 60
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KoreanDateTime;
+    use crate::{JasiConvention, KlcError};
+
+    #[test]
+    fn test_korean_utc_offsets() {
+        for (timestamp, offset, is_dst) in [
+            (-2208988800, 30472, false), // 1900-01-01, local mean time
+            (-1900000000, 30600, false), // 1909-10-17
+            (-1000000000, 32400, false), // 1938-04-24
+            (-670000000, 32400, false),  // 1948-10-08
+            (-660000000, 32400, false),  // 1949-02-01
+            (-650000000, 36000, true),   // 1949-05-28
+            (-400000000, 30600, false),  // 1957-04-29
+            (-390000000, 34200, true),   // 1957-08-23
+            (0, 32400, false),           // 1970-01-01
+            (550000000, 36000, true),    // 1987-06-06
+            (1700000000, 32400, false),  // 2023-11-15
+        ] {
+            let time = KoreanDateTime::from_unix_timestamp(timestamp).unwrap();
+
+            assert_eq!(time.utc_offset_seconds(), offset, "{}", timestamp);
+            assert_eq!(time.is_daylight_saving(), is_dst, "{}", timestamp);
+        }
+    }
+
+    #[test]
+    fn test_summer_time_transition() {
+        // 1987-05-10 02:00 KST became 03:00 KDT
+        let before = KoreanDateTime::from_unix_timestamp(547578000 - 1).unwrap();
+        let after = KoreanDateTime::from_unix_timestamp(547578000).unwrap();
+
+        assert_eq!(before.to_string(), "1987-05-10 01:59:59+09:00");
+        assert_eq!(after.to_string(), "1987-05-10 03:00:00+10:00");
+    }
+
+    #[test]
+    fn test_standard_time_transition() {
+        // 1908-04-01 00:00 local mean time (UTC+8:27:52) became 00:02:08 UTC+8:30
+        let before = KoreanDateTime::from_unix_timestamp(-1948782472 - 1).unwrap();
+        let after = KoreanDateTime::from_unix_timestamp(-1948782472).unwrap();
+
+        assert_eq!(before.utc_offset_seconds(), 30472);
+        assert_eq!(before.to_string(), "1908-03-31 23:59:59+08:27");
+        assert_eq!(after.utc_offset_seconds(), 30600);
+        assert_eq!(after.to_string(), "1908-04-01 00:02:08+08:30");
+    }
+
+    #[test]
+    fn test_date_changes_with_offset() {
+        // 1955-05-04 15:30 UTC is midnight of 1955-05-05 with summer time (UTC+9:30)
+        let time = KoreanDateTime::from_unix_timestamp(-462702600).unwrap();
+
+        assert_eq!(time.to_string(), "1955-05-05 01:00:00+09:30");
+        assert_eq!(time.date().to_string(), "1955-05-05");
+    }
+
+    #[test]
+    fn test_true_solar_time() {
+        // 2024-11-03 12:00 KST: equation of time is about +16.4 minutes
+        let time = KoreanDateTime::from_unix_timestamp(1730602800).unwrap();
+        let solar = time.to_true_solar_time().unwrap();
+
+        assert_eq!((solar.hour(), solar.minute()), (11, 44));
+        assert!(!solar.is_daylight_saving());
+        assert_eq!(solar.unix_timestamp(), time.unix_timestamp());
+    }
+
+    #[test]
+    fn test_four_pillars_uses_instant_for_solar_terms() {
+        // 입춘 2024 is at 2024-02-04 17:27 KST (08:27 UTC)
+        let before = KoreanDateTime::from_unix_timestamp(1707035100).unwrap(); // 17:25 KST
+        let after = KoreanDateTime::from_unix_timestamp(1707035400).unwrap(); // 17:30 KST
+
+        assert_eq!(
+            before.four_pillars(JasiConvention::NextDay).to_string(),
+            "계묘년 을축월 무술일 신유시"
+        );
+        assert_eq!(
+            after.four_pillars(JasiConvention::NextDay).to_string(),
+            "갑진년 병인월 무술일 신유시"
+        );
+
+        // In true solar time 17:30 KST is 16:44, 신시 of the new year
+        let solar = after.to_true_solar_time().unwrap();
+        assert_eq!(
+            solar.four_pillars(JasiConvention::NextDay).to_string(),
+            "갑진년 병인월 무술일 경신시"
+        );
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert!(matches!(
+            KoreanDateTime::from_unix_timestamp(7289654400), // 2201-01-01
+            Err(KlcError::OutOfRange { .. })
+        ));
+        assert!(KoreanDateTime::from_unix_timestamp(i64::MIN / 4).is_err());
+    }
+}
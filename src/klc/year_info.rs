@@ -0,0 +1,221 @@
+use std::ops::RangeInclusive;
+
+use super::{KOREAN_LUNAR_BASE_YEAR, KlcError, LunarDate, LunarSolarConverter, Pillar, SolarDate};
+
+/// Last lunar year whose months all fall within the supported solar range.
+const LUNAR_YEAR_INFO_MAX_YEAR: i32 = 2199;
+
+/// A month of a lunar year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunarMonthInfo {
+    /// The month number (1-12).
+    pub month: u32,
+    /// `true` if the month is an intercalary month (윤달).
+    pub is_leap_month: bool,
+    /// The number of days, 29 (작은달) or 30 (큰달).
+    pub days: u32,
+    /// The solar date of the first day of the month.
+    pub start: SolarDate,
+}
+
+/// The months of a Korean lunar year, in order, with the intercalary month (윤달)
+/// right after the regular month with the same number.
+///
+/// # Example
+/// ```
+/// use rs_klc::LunarYearInfo;
+///
+/// let year = LunarYearInfo::new(2023).unwrap();
+/// assert_eq!(year.new_year().to_string(), "2023-01-22");
+/// assert_eq!(year.days(), 384);
+/// assert_eq!(year.leap_month(), Some(2));
+/// assert_eq!(year.gapja().to_string(), "계묘");
+///
+/// let leap = year.months()[2];
+/// assert_eq!((leap.month, leap.is_leap_month, leap.days), (2, true, 29));
+/// assert_eq!(leap.start.to_string(), "2023-03-22");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LunarYearInfo {
+    year: i32,
+    months: Vec<LunarMonthInfo>,
+}
+
+/// An iterator over the [`LunarYearInfo`] of consecutive lunar years.
+///
+/// Created by [`LunarYearInfo::all`].
+#[derive(Debug, Clone)]
+pub struct LunarYears {
+    years: RangeInclusive<i32>,
+}
+
+impl LunarYearInfo {
+    /// Returns the months of a lunar year.
+    ///
+    /// # Errors
+    /// Returns [`KlcError::OutOfRange`] if the year is outside 1391-2199. Lunar 2200 is
+    /// only supported up to 11/25.
+    pub fn new(year: i32) -> Result<Self, KlcError> {
+        if !(KOREAN_LUNAR_BASE_YEAR..=LUNAR_YEAR_INFO_MAX_YEAR).contains(&year) {
+            return Err(KlcError::OutOfRange {
+                min: KOREAN_LUNAR_BASE_YEAR as u32 * 10000 + 101,
+                max: LUNAR_YEAR_INFO_MAX_YEAR as u32 * 10000 + 1230,
+            });
+        }
+
+        let leap_month = LunarSolarConverter::get_lunar_intercalary_month(year);
+        let mut months = Vec::with_capacity(13);
+
+        for month in 1..=12 {
+            for is_leap_month in [false, true] {
+                if is_leap_month && leap_month != Some(month) {
+                    continue;
+                }
+
+                months.push(LunarMonthInfo {
+                    month,
+                    is_leap_month,
+                    days: LunarSolarConverter::get_lunar_days(year, month, is_leap_month),
+                    start: LunarDate::new(year, month, 1, is_leap_month)?.to_solar(),
+                });
+            }
+        }
+
+        Ok(LunarYearInfo { year, months })
+    }
+
+    /// Returns an iterator over every lunar year with complete months, 1391-2199.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_klc::LunarYearInfo;
+    ///
+    /// let longest = LunarYearInfo::all().map(|year| year.days()).max();
+    /// assert_eq!(longest, Some(385));
+    /// ```
+    pub fn all() -> LunarYears {
+        LunarYears {
+            years: KOREAN_LUNAR_BASE_YEAR..=LUNAR_YEAR_INFO_MAX_YEAR,
+        }
+    }
+
+    /// Returns the lunar year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the solar date of lunar new year (설날, 1/1).
+    pub fn new_year(&self) -> SolarDate {
+        self.months[0].start
+    }
+
+    /// Returns the number of days in the year, 353-385.
+    pub fn days(&self) -> u32 {
+        self.months.iter().map(|month| month.days).sum()
+    }
+
+    /// Returns the months in order, 12 or 13 of them.
+    pub fn months(&self) -> &[LunarMonthInfo] {
+        &self.months
+    }
+
+    /// Returns the intercalary month (윤달), if the year has one.
+    pub fn leap_month(&self) -> Option<u32> {
+        self.months
+            .iter()
+            .find(|month| month.is_leap_month)
+            .map(|month| month.month)
+    }
+
+    /// Returns the Gapja (간지) of the year, e.g. 갑진 for 2024.
+    pub fn gapja(&self) -> Pillar {
+        // 4 CE was a 갑자 year
+        let index = (self.year - 4).rem_euclid(60) as usize;
+
+        Pillar::from_indices(index, index)
+    }
+}
+
+impl Iterator for LunarYears {
+    type Item = LunarYearInfo;
+
+    fn next(&mut self) -> Option<LunarYearInfo> {
+        self.years
+            .next()
+            .map(|year| LunarYearInfo::new(year).expect("the years are within the range"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.years.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for LunarYears {
+    fn next_back(&mut self) -> Option<LunarYearInfo> {
+        self.years
+            .next_back()
+            .map(|year| LunarYearInfo::new(year).expect("the years are within the range"))
+    }
+}
+
+impl ExactSizeIterator for LunarYears {}
+
+#[cfg(test)]
+mod tests {
+    use super::LunarYearInfo;
+    use crate::KlcError;
+
+    #[test]
+    fn test_year_without_leap_month() {
+        let year = LunarYearInfo::new(2024).unwrap();
+
+        assert_eq!(year.new_year().to_string(), "2024-02-10");
+        assert_eq!(year.months().len(), 12);
+        assert_eq!(year.leap_month(), None);
+        assert_eq!(year.days(), 354);
+        assert_eq!(year.gapja().to_string(), "갑진");
+        assert_eq!(year.gapja().chinese_name(), "甲辰");
+    }
+
+    #[test]
+    fn test_months_are_contiguous() {
+        for year in [1582, 2033, 2051] {
+            let info = LunarYearInfo::new(year).unwrap();
+            let next = LunarYearInfo::new(year + 1).unwrap();
+            let ends = info
+                .months()
+                .iter()
+                .map(|month| month.start.julian_day_number() + month.days);
+            let starts = info.months()[1..]
+                .iter()
+                .chain([&next.months()[0]])
+                .map(|month| month.start.julian_day_number());
+
+            assert!(ends.eq(starts), "{}", year);
+        }
+    }
+
+    #[test]
+    fn test_all_years() {
+        let years = LunarYearInfo::all();
+        assert_eq!(years.len(), 809);
+
+        for year in years {
+            assert!((353..=385).contains(&year.days()), "{}", year.year());
+        }
+
+        assert_eq!(LunarYearInfo::all().next_back().unwrap().year(), 2199);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(
+            LunarYearInfo::new(2200),
+            Err(KlcError::OutOfRange {
+                min: 13910101,
+                max: 21991230
+            })
+        );
+        assert!(LunarYearInfo::new(1390).is_err());
+    }
+}
@@ -44,4 +44,20 @@
 pub mod klc;
 
 // Re-export the main struct and enum for easier access
-pub use klc::{DayOfWeek, LunarSolarConverter};
+pub use klc::{
+    DateField, DatePair, DateParser, DatePattern, DateRange, DayOfWeek, DayOverflowPolicy,
+    EarthlyBranch, Element, ElementDistribution, ElementRelation, FourPillars, Gapja,
+    GapjaConvention, HeavenlyStem, JasiConvention, KlcError, KoreanDateTime, LeapMonthPolicy,
+    Locale, LunarAnniversary, LunarDate, LunarMonthInfo, LunarSolarConverter, LunarYearInfo,
+    LunarYears, MissingDayPolicy, MissingLeapMonthPolicy, Nayin, Observance, Occurrences,
+    ParseError, ParseErrorKind, ParsedDate, Pillar, RangeStep, Sexagenary, SolarDate, SolarTerm,
+    SolarTermTime, YinYang,
+};
+pub use klc::{calendar, holidays, seasonal_days, solar_terms};
+
+#[cfg(feature = "chrono")]
+pub use klc::NaiveDateExt;
+#[cfg(feature = "jiff")]
+pub use klc::ZonedExt;
+#[cfg(feature = "serde")]
+pub use klc::serde_object;
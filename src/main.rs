@@ -1,6 +1,4 @@
-mod klc;
-
-use klc::LunarSolarConverter;
+use rs_klc::LunarSolarConverter;
 
 fn main() {
     let mut converter = LunarSolarConverter::new();